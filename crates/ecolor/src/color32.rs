@@ -1,4 +1,4 @@
-use crate::{Rgba, fast_round, linear_f32_from_linear_u8};
+use crate::{Hsva, Rgba, fast_round, linear_f32_from_linear_u8};
 
 /// This format is used for space-efficient color representation (32 bits).
 ///
@@ -232,6 +232,26 @@ impl Color32 {
         self.0[3]
     }
 
+    /// From hue, saturation, value and alpha, all in the range 0-1.
+    ///
+    /// Shorthand for `Color32::from(Hsva::new(h, s, v, a))`, see [`Hsva`].
+    /// The value is in linear space, and the alpha is _not_ premultiplied.
+    #[inline]
+    pub fn from_hsva(h: f32, s: f32, v: f32, a: f32) -> Self {
+        Hsva::new(h, s, v, a).into()
+    }
+
+    /// To hue, saturation, value and alpha, all in the range 0-1.
+    ///
+    /// Shorthand for converting to an [`Hsva`].
+    /// For grays (including black and white) the hue is undefined, and `0.0` is returned.
+    /// For additive colors the alpha is negative.
+    #[inline]
+    pub fn to_hsva(self) -> (f32, f32, f32, f32) {
+        let Hsva { h, s, v, a } = self.into();
+        (h, s, v, a)
+    }
+
     /// Returns an opaque version of self
     #[inline]
     pub fn to_opaque(self) -> Self {
@@ -353,7 +373,22 @@ impl Color32 {
     }
 
     /// Lerp this color towards `other` by `t` in gamma space.
+    #[deprecated = "Renamed to `lerp_gamma`"]
     pub fn lerp_to_gamma(&self, other: Self, t: f32) -> Self {
+        self.lerp_gamma(other, t)
+    }
+
+    /// Lerp this color towards `other` by `t` in gamma (sRGB) space,
+    /// so that `0.0 => self, 1.0 => other`.
+    ///
+    /// This interpolates the bytes directly, which is fast and often perceptually more even,
+    /// e.g. for gradients that should look linear to the eye.
+    /// Use [`Self::lerp_linear`] for physically correct mixing of light,
+    /// e.g. when blending between two bright colors, which otherwise gives a dark, muddy midpoint.
+    ///
+    /// Interpolation is done on the premultiplied values,
+    /// so fading towards [`Self::TRANSPARENT`] keeps the hue of `self`.
+    pub fn lerp_gamma(self, other: Self, t: f32) -> Self {
         use emath::lerp;
 
         Self::from_rgba_premultiplied(
@@ -364,6 +399,19 @@ impl Color32 {
         )
     }
 
+    /// Lerp this color towards `other` by `t` in linear space,
+    /// so that `0.0 => self, 1.0 => other`.
+    ///
+    /// This converts both colors to [`Rgba`], interpolates, and converts back.
+    /// This is the physically correct way of mixing two colors,
+    /// and avoids the dark, muddy midpoints you get from [`Self::lerp_gamma`].
+    ///
+    /// Interpolation is done on the premultiplied values,
+    /// so fading towards [`Self::TRANSPARENT`] keeps the hue of `self`.
+    pub fn lerp_linear(self, other: Self, t: f32) -> Self {
+        emath::lerp(Rgba::from(self)..=Rgba::from(other), t).into()
+    }
+
     /// Blend two colors in gamma space, so that `self` is behind the argument.
     pub fn blend(self, on_top: Self) -> Self {
         self.gamma_multiply_u8(255 - on_top.a()) + on_top
@@ -432,6 +480,61 @@ mod test {
         .into_iter()
     }
 
+    #[test]
+    fn test_color32_lerp() {
+        for [r, g, b, a] in test_rgba() {
+            let color = Color32::from_rgba_premultiplied(r, g, b, a);
+            let other = Color32::from_rgb(200, 50, 10);
+            assert_eq!(color.lerp_gamma(other, 0.0), color);
+            assert_eq!(color.lerp_gamma(other, 1.0), other);
+            assert_eq!(other.lerp_linear(color, 0.0), other);
+        }
+
+        // Linear space gives a brighter midpoint than gamma space:
+        let linear = Color32::RED.lerp_linear(Color32::GREEN, 0.5);
+        let gamma = Color32::RED.lerp_gamma(Color32::GREEN, 0.5);
+        assert_eq!(gamma, Color32::from_rgb(128, 128, 0));
+        assert!(
+            linear.r() > gamma.r() && linear.g() > gamma.g(),
+            "{linear:?}"
+        );
+
+        // Fading out to transparent keeps the color, since we work on premultiplied values:
+        for lerp in [Color32::lerp_gamma, Color32::lerp_linear] {
+            let faded = lerp(Color32::RED, Color32::TRANSPARENT, 0.5);
+            assert_eq!(faded, Color32::from_rgba_premultiplied(128, 0, 0, 128));
+            assert_eq!(faded.to_opaque(), Color32::RED);
+        }
+    }
+
+    #[test]
+    fn test_color32_hsva() {
+        for color in [Color32::WHITE, Color32::BLACK, Color32::GRAY] {
+            let (h, s, _v, a) = color.to_hsva();
+            assert_eq!((h, s, a), (0.0, 0.0, 1.0), "{color:?}");
+        }
+
+        let (h, s, v, a) = Color32::BLUE.to_hsva();
+        assert!((h - 2.0 / 3.0).abs() < 1e-6, "{h}");
+        assert_eq!((s, v, a), (1.0, 1.0, 1.0));
+
+        for [r, g, b, a] in test_rgba() {
+            if a != 0 && r.max(g).max(b) > a {
+                continue; // Not a valid premultiplied color
+            }
+            let color = Color32::from_rgba_premultiplied(r, g, b, a);
+            let (h, s, v, a) = color.to_hsva();
+            let roundtripped = Color32::from_hsva(h, s, v, a);
+            for i in 0..4 {
+                assert!(
+                    color[i].abs_diff(roundtripped[i]) <= 1,
+                    "{color:?} -> {:?} -> {roundtripped:?}",
+                    (h, s, v, a)
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_color32_additive() {
         let opaque = Color32::from_rgb(40, 50, 60);
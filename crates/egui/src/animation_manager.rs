@@ -1,12 +1,13 @@
 use crate::{
-    Id, IdMap, InputState,
-    emath::{NumExt as _, remap_clamp},
+    Color32, Id, IdMap, InputState,
+    emath::{NumExt as _, lerp, remap_clamp},
 };
 
 #[derive(Clone, Default)]
 pub(crate) struct AnimationManager {
     bools: IdMap<BoolAnim>,
     values: IdMap<ValueAnim>,
+    colors: IdMap<ColorAnim>,
 }
 
 #[derive(Clone, Debug)]
@@ -25,6 +26,16 @@ struct ValueAnim {
     toggle_time: f64,
 }
 
+#[derive(Clone, Debug)]
+struct ColorAnim {
+    from_color: Color32,
+
+    to_color: Color32,
+
+    /// when did `color` last change?
+    toggle_time: f64,
+}
+
 impl AnimationManager {
     /// See [`crate::Context::animate_bool`] for documentation
     pub fn animate_bool(
@@ -65,12 +76,14 @@ impl AnimationManager {
         }
     }
 
+    /// See [`crate::Context::animate_value_with_time_and_easing`] for documentation
     pub fn animate_value(
         &mut self,
         input: &InputState,
         animation_time: f32,
         id: Id,
         value: f32,
+        easing: fn(f32) -> f32,
     ) -> f32 {
         match self.values.get_mut(&id) {
             None => {
@@ -89,11 +102,12 @@ impl AnimationManager {
                 // On the frame we toggle we don't want to return the old value,
                 // so we extrapolate forwards by half a frame:
                 let time_since_toggle = time_since_toggle + input.predicted_dt / 2.0;
-                let current_value = remap_clamp(
-                    time_since_toggle,
-                    0.0..=animation_time,
-                    anim.from_value..=anim.to_value,
-                );
+                let t = remap_clamp(time_since_toggle, 0.0..=animation_time, 0.0..=1.0);
+                let current_value = if t < 1.0 {
+                    lerp(anim.from_value..=anim.to_value, easing(t))
+                } else {
+                    anim.to_value
+                };
                 if anim.to_value != value {
                     anim.from_value = current_value; //start new animation from current position of playing animation
                     anim.to_value = value;
@@ -107,4 +121,48 @@ impl AnimationManager {
             }
         }
     }
+
+    /// See [`crate::Context::animate_color`] for documentation
+    pub fn animate_color(
+        &mut self,
+        input: &InputState,
+        animation_time: f32,
+        id: Id,
+        color: Color32,
+    ) -> Color32 {
+        match self.colors.get_mut(&id) {
+            None => {
+                self.colors.insert(
+                    id,
+                    ColorAnim {
+                        from_color: color,
+                        to_color: color,
+                        toggle_time: -f64::INFINITY, // long time ago
+                    },
+                );
+                color
+            }
+            Some(anim) => {
+                // Same as for `animate_value`:
+                let time_since_toggle =
+                    (input.time - anim.toggle_time) as f32 + input.predicted_dt / 2.0;
+                let t = remap_clamp(time_since_toggle, 0.0..=animation_time, 0.0..=1.0);
+                let current_color = if t < 1.0 {
+                    anim.from_color.lerp_linear(anim.to_color, t)
+                } else {
+                    anim.to_color
+                };
+                if anim.to_color != color {
+                    anim.from_color = current_color; //start new animation from current color of playing animation
+                    anim.to_color = color;
+                    anim.toggle_time = input.time;
+                }
+                if animation_time == 0.0 {
+                    anim.from_color = color;
+                    anim.to_color = color;
+                }
+                current_color
+            }
+        }
+    }
 }
@@ -183,6 +183,14 @@ impl Area {
         LayerId::new(self.order, self.id)
     }
 
+    /// The [`Id`] of the drag handle of the area with the given id.
+    ///
+    /// Dragging a widget with this id moves the area, if it is movable.
+    /// See [`Ui::window_drag_handle`].
+    pub fn drag_handle_id(area_id: Id) -> Id {
+        area_id.with("drag_handle")
+    }
+
     /// If false, no content responds to click
     /// and widgets will be shown grayed out.
     /// You won't be able to move the window.
@@ -291,6 +299,12 @@ impl Area {
 
     /// Constrain the movement of the window to the given rectangle.
     ///
+    /// This replaces the default of [`Context::screen_rect`], and is useful for keeping
+    /// floating areas inside a sub-region of the screen, e.g. a canvas panel.
+    /// The area is clamped to the rectangle every frame, so dragging it past an edge
+    /// makes it stop at that edge, and dragging it back moves it right away.
+    /// The area is also not painted outside the rectangle.
+    ///
     /// For instance: `.constrain_to(ctx.screen_rect())`.
     #[inline]
     pub fn constrain_to(mut self, constrain_rect: Rect) -> Self {
@@ -525,13 +539,24 @@ impl Area {
                 true,
             );
 
-            if movable && move_response.dragged() {
+            // Check the drag handle (if any) to prevent frame-delay:
+            let handle_dragged = ctx
+                .read_response(Self::drag_handle_id(id))
+                .filter(|handle| handle.dragged());
+
+            if movable {
                 if let Some(pivot_pos) = &mut state.pivot_pos {
-                    *pivot_pos += move_response.drag_delta();
+                    if move_response.dragged() {
+                        *pivot_pos += move_response.drag_delta();
+                    }
+                    if let Some(handle) = &handle_dragged {
+                        *pivot_pos += handle.drag_delta();
+                    }
                 }
             }
 
             if (move_response.dragged() || move_response.clicked())
+                || handle_dragged.is_some()
                 || pointer_pressed_on_area(ctx, layer_id)
                 || !ctx.memory(|m| m.areas().visible_last_frame(&layer_id))
             {
@@ -437,6 +437,9 @@ impl CollapsingHeader {
     /// Calling `.open(Some(false))` will make the collapsing header close this frame (or stay closed).
     ///
     /// Calling `.open(None)` has no effect (default).
+    ///
+    /// Opening or closing this way is animated just like a click would be.
+    /// Use [`CollapsingResponse::is_animating`] to find out when the animation is done.
     #[inline]
     pub fn open(mut self, open: Option<bool>) -> Self {
         self.open = open;
@@ -717,4 +720,29 @@ impl<R> CollapsingResponse<R> {
     pub fn fully_open(&self) -> bool {
         self.openness >= 1.0
     }
+
+    /// 0.0 if fully closed, 1.0 if fully open, and something in-between while animating.
+    #[inline]
+    pub fn openness(&self) -> f32 {
+        self.openness
+    }
+
+    /// Is the [`CollapsingHeader`] currently animating between open and closed?
+    ///
+    /// Useful e.g. for deferring layout work until after a [`CollapsingHeader::open`] has finished animating.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let collapse_all = false;
+    /// let response = egui::CollapsingHeader::new("Details")
+    ///     .open(collapse_all.then_some(false))
+    ///     .show(ui, |ui| ui.label("Body"));
+    /// if !response.is_animating() {
+    ///     // The header has settled, so its size won't change next frame.
+    /// }
+    /// # });
+    /// ```
+    pub fn is_animating(&self) -> bool {
+        !self.fully_closed() && !self.fully_open()
+    }
 }
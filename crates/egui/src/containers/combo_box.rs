@@ -1,9 +1,10 @@
 use epaint::Shape;
 
 use crate::{
-    Align2, Context, Id, InnerResponse, NumExt as _, Painter, Popup, PopupCloseBehavior, Rect,
-    Response, ScrollArea, Sense, Stroke, TextStyle, TextWrapMode, Ui, UiBuilder, Vec2, WidgetInfo,
-    WidgetText, WidgetType, epaint, style::StyleModifier, style::WidgetVisuals, vec2,
+    Align, Align2, Context, Event, Id, InnerResponse, Key, NumExt as _, Painter, Popup,
+    PopupCloseBehavior, Rect, Response, ScrollArea, Sense, Stroke, TextEdit, TextStyle,
+    TextWrapMode, Ui, UiBuilder, Vec2, WidgetInfo, WidgetText, WidgetType, epaint,
+    style::StyleModifier, style::WidgetVisuals, vec2,
 };
 
 #[expect(unused_imports)] // Documentation
@@ -12,6 +13,59 @@ use crate::style::Spacing;
 /// A function that paints the [`ComboBox`] icon
 pub type IconPainter = Box<dyn FnOnce(&Ui, Rect, &WidgetVisuals, bool)>;
 
+/// Characters typed within this many seconds of each other are combined
+/// into one type-ahead search in [`ComboBox::show_index`].
+const TYPE_AHEAD_TIMEOUT: f64 = 1.0;
+
+/// The type-ahead state of an open [`ComboBox::show_index`] popup.
+#[derive(Clone, Debug, Default)]
+struct TypeAhead {
+    /// What the user has typed so far.
+    text: String,
+
+    /// When the user last typed something, in [`crate::InputState::time`].
+    last_input_time: f64,
+
+    /// The option we last jumped to.
+    highlighted: Option<usize>,
+}
+
+impl TypeAhead {
+    /// Add typed characters, starting over if the user paused for longer than [`TYPE_AHEAD_TIMEOUT`].
+    fn push(&mut self, typed: &str, time: f64) {
+        if TYPE_AHEAD_TIMEOUT < time - self.last_input_time {
+            self.text.clear();
+        }
+        self.text.push_str(typed);
+        self.last_input_time = time;
+    }
+
+    /// Find the first option starting with the typed text (ignoring case), searching from `current` and wrapping around.
+    ///
+    /// Typing the same character repeatedly cycles through the options starting with that character.
+    fn find(&self, current: usize, len: usize, label: impl Fn(usize) -> String) -> Option<usize> {
+        let text = self.text.to_lowercase();
+        let first = text.chars().next()?;
+        let (prefix, start) = if text.chars().all(|c| c == first) {
+            (first.to_string(), current + 1)
+        } else {
+            (text, current)
+        };
+        (0..len)
+            .map(|offset| (start + offset) % len)
+            .find(|&i| label(i).to_lowercase().starts_with(&prefix))
+    }
+}
+
+/// The state of an open [`ComboBox::show_index_with_search`] popup.
+#[derive(Clone, Debug, Default)]
+struct ComboBoxSearch {
+    query: String,
+
+    /// The widest the popup has been, so it doesn't shrink while filtering.
+    min_width: f32,
+}
+
 /// A drop-down selection menu with a descriptive label.
 ///
 /// ```
@@ -213,13 +267,15 @@ impl ComboBox {
         ui: &mut Ui,
         menu_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<Option<R>> {
-        self.show_ui_dyn(ui, Box::new(menu_contents))
+        self.show_ui_dyn(ui, Box::new(menu_contents), true)
     }
 
+    /// If `in_scroll_area` is `false`, `menu_contents` is responsible for adding its own [`ScrollArea`].
     fn show_ui_dyn<'c, R>(
         self,
         ui: &mut Ui,
         menu_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+        in_scroll_area: bool,
     ) -> InnerResponse<Option<R>> {
         let Self {
             id_salt,
@@ -241,6 +297,7 @@ impl ComboBox {
                 button_id,
                 selected_text,
                 menu_contents,
+                in_scroll_area,
                 icon,
                 wrap_mode,
                 close_behavior,
@@ -263,6 +320,8 @@ impl ComboBox {
 
     /// Show a list of items with the given selected index.
     ///
+    /// While the popup is open, typing jumps to the first item starting with the typed text,
+    /// like in a native combo box.
     ///
     /// ```
     /// # #[derive(Debug, PartialEq)]
@@ -286,21 +345,166 @@ impl ComboBox {
         len: usize,
         get: impl Fn(usize) -> Text,
     ) -> Response {
+        let type_ahead_id =
+            Self::widget_to_popup_id(ui.make_persistent_id(self.id_salt)).with("type_ahead");
         let slf = self.selected_text(get(*selected));
 
         let mut changed = false;
 
-        let mut response = slf
-            .show_ui(ui, |ui| {
-                for i in 0..len {
+        let inner_response = slf.show_ui(ui, |ui| {
+            let (typed, time) = ui.input(|i| {
+                let typed: String = i
+                    .events
+                    .iter()
+                    .filter_map(|event| match event {
+                        Event::Text(text) => Some(text.as_str()),
+                        _ => None,
+                    })
+                    .collect();
+                (typed, i.time)
+            });
+
+            let mut jump_to = None;
+            if !typed.is_empty() {
+                let mut type_ahead: TypeAhead =
+                    ui.data(|d| d.get_temp(type_ahead_id)).unwrap_or_default();
+                type_ahead.push(&typed, time);
+                let current = type_ahead.highlighted.unwrap_or(*selected);
+                jump_to = type_ahead.find(current, len, |i| {
+                    let text: WidgetText = get(i).into();
+                    text.text().to_owned()
+                });
+                if jump_to.is_some() {
+                    type_ahead.highlighted = jump_to;
+                }
+                ui.data_mut(|d| d.insert_temp(type_ahead_id, type_ahead));
+            }
+
+            for i in 0..len {
+                let response = ui.selectable_label(i == *selected, get(i));
+                if jump_to == Some(i) {
+                    response.request_focus();
+                    response.scroll_to_me(Some(Align::Center));
+                }
+                if response.clicked() {
+                    *selected = i;
+                    changed = true;
+                }
+            }
+        });
+
+        if inner_response.inner.is_none() {
+            // Start over the next time the popup is opened:
+            ui.data_mut(|d| d.remove::<TypeAhead>(type_ahead_id));
+        }
+
+        let mut response = inner_response.response;
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Like [`Self::show_index`], but with a search field at the top of the popup.
+    ///
+    /// Only the items containing the search text (ignoring case) are shown.
+    /// Pressing enter in the search field selects the first match,
+    /// and tab moves the keyboard focus to the matching items.
+    ///
+    /// This is useful for combo boxes with a lot of items.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let countries = ["Austria", "Belgium", "Croatia", "Denmark", "Estonia"];
+    /// let mut selected = 0;
+    /// egui::ComboBox::from_label("Country").show_index_with_search(
+    ///     ui,
+    ///     &mut selected,
+    ///     countries.len(),
+    ///     |i| countries[i]
+    /// );
+    /// # });
+    /// ```
+    pub fn show_index_with_search<Text: Into<WidgetText>>(
+        self,
+        ui: &mut Ui,
+        selected: &mut usize,
+        len: usize,
+        get: impl Fn(usize) -> Text,
+    ) -> Response {
+        let search_id =
+            Self::widget_to_popup_id(ui.make_persistent_id(self.id_salt)).with("search");
+        let height = self.height.unwrap_or_else(|| ui.spacing().combo_height);
+        // Clicking the search field should not close the popup:
+        let close_behavior = self
+            .close_behavior
+            .unwrap_or(PopupCloseBehavior::CloseOnClickOutside);
+        let slf = self
+            .selected_text(get(*selected))
+            .close_behavior(close_behavior);
+
+        let mut changed = false;
+
+        let menu_contents = |ui: &mut Ui| {
+            let state: Option<ComboBoxSearch> = ui.data(|d| d.get_temp(search_id));
+            let just_opened = state.is_none();
+            let mut state = state.unwrap_or_default();
+
+            // Don't shrink while filtering, so the popup doesn't jump around:
+            ui.set_min_width(state.min_width);
+
+            let search_response = ui.add(
+                TextEdit::singleline(&mut state.query)
+                    .hint_text("Search…")
+                    .desired_width(ui.available_width()),
+            );
+            if just_opened {
+                search_response.request_focus();
+            }
+
+            let query = state.query.to_lowercase();
+            let matches: Vec<usize> = (0..len)
+                .filter(|&i| {
+                    let text: WidgetText = get(i).into();
+                    text.text().to_lowercase().contains(&query)
+                })
+                .collect();
+
+            if search_response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+                if let Some(&first) = matches.first() {
+                    *selected = first;
+                    changed = true;
+                    ui.close();
+                }
+            }
+
+            ui.separator();
+
+            ScrollArea::vertical().max_height(height).show(ui, |ui| {
+                for &i in &matches {
                     if ui.selectable_label(i == *selected, get(i)).clicked() {
                         *selected = i;
                         changed = true;
+                        ui.close();
                     }
                 }
-            })
-            .response;
+                if matches.is_empty() {
+                    ui.weak("No matches");
+                }
+            });
+
+            state.min_width = state.min_width.max(ui.min_rect().width());
+            ui.data_mut(|d| d.insert_temp(search_id, state));
+        };
 
+        let inner_response = slf.show_ui_dyn(ui, Box::new(menu_contents), false);
+
+        if inner_response.inner.is_none() {
+            // Start over the next time the popup is opened:
+            ui.data_mut(|d| d.remove::<ComboBoxSearch>(search_id));
+        }
+
+        let mut response = inner_response.response;
         if changed {
             response.mark_changed();
         }
@@ -324,6 +528,7 @@ fn combo_box_dyn<'c, R>(
     button_id: Id,
     selected_text: WidgetText,
     menu_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+    in_scroll_area: bool,
     icon: Option<IconPainter>,
     wrap_mode: Option<TextWrapMode>,
     close_behavior: Option<PopupCloseBehavior>,
@@ -402,18 +607,23 @@ fn combo_box_dyn<'c, R>(
         .show(|ui| {
             ui.set_min_width(ui.available_width());
 
-            ScrollArea::vertical()
-                .max_height(height)
-                .show(ui, |ui| {
-                    // Often the button is very narrow, which means this popup
-                    // is also very narrow. Having wrapping on would therefore
-                    // result in labels that wrap very early.
-                    // Instead, we turn it off by default so that the labels
-                    // expand the width of the menu.
-                    ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
-                    menu_contents(ui)
-                })
-                .inner
+            // Often the button is very narrow, which means this popup
+            // is also very narrow. Having wrapping on would therefore
+            // result in labels that wrap very early.
+            // Instead, we turn it off by default so that the labels
+            // expand the width of the menu.
+            if in_scroll_area {
+                ScrollArea::vertical()
+                    .max_height(height)
+                    .show(ui, |ui| {
+                        ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                        menu_contents(ui)
+                    })
+                    .inner
+            } else {
+                ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                menu_contents(ui)
+            }
         })
         .map(|r| r.inner);
 
@@ -10,8 +10,9 @@
 
 use crate::style::StyleModifier;
 use crate::{
-    Button, Color32, Context, Frame, Id, InnerResponse, IntoAtoms, Layout, Popup,
-    PopupCloseBehavior, Response, Style, Ui, UiBuilder, UiKind, UiStack, UiStackInfo, Widget as _,
+    Button, Color32, Context, FocusDirection, Frame, Id, InnerResponse, IntoAtoms, Key, Layout,
+    Popup, PopupCloseBehavior, Response, Style, Ui, UiBuilder, UiKind, UiStack, UiStackInfo,
+    Widget as _,
 };
 use emath::{Align, RectAlign, Vec2, vec2};
 use epaint::Stroke;
@@ -313,6 +314,9 @@ impl<'a> MenuButton<'a> {
 }
 
 /// A submenu button that shows a [`SubMenu`] if a [`Button`] is hovered.
+///
+/// The submenu can also be opened with the right arrow key when the button has keyboard focus,
+/// and closed again with the left arrow key.
 pub struct SubMenuButton<'a> {
     pub button: Button<'a>,
     pub sub_menu: SubMenu,
@@ -445,9 +449,19 @@ impl SubMenu {
         // But since we check if no other menu is open, nothing should be able to cover the button
         let is_hovered = hover_pos.is_some_and(|pos| button_rect.contains(pos));
 
+        // Like in native menus, the right arrow key opens the submenu and focuses its first item:
+        let focus_first_item_id = id.with("focus_first_item");
+        let opened_with_key =
+            button_response.has_focus() && ui.input(|i| i.key_pressed(Key::ArrowRight));
+        if opened_with_key {
+            ui.data_mut(|data| data.insert_temp(focus_first_item_id, true));
+            // Don't let the arrow key move the focus somewhere else:
+            ui.memory_mut(|mem| mem.move_focus(FocusDirection::None));
+        }
+
         // The clicked handler is there for accessibility (keyboard navigation)
-        let should_open =
-            ui.is_enabled() && (button_response.clicked() || (is_hovered && !is_any_open));
+        let should_open = ui.is_enabled()
+            && (button_response.clicked() || opened_with_key || (is_hovered && !is_any_open));
         if should_open {
             set_open = Some(true);
             is_open = true;
@@ -483,7 +497,30 @@ impl SubMenu {
                 if button_response.clicked() || button_response.is_pointer_button_down_on() {
                     ui.ctx().move_to_top(ui.layer_id());
                 }
-                content(ui)
+                let num_widgets_before = ui
+                    .ctx()
+                    .pass_state(|fs| fs.widgets.get_layer(ui.layer_id()).count());
+                let inner = content(ui);
+
+                // Wait for the sizing pass to be over, so the items can actually be focused:
+                if !ui.is_sizing_pass()
+                    && ui
+                        .data_mut(|data| data.remove_temp::<bool>(focus_first_item_id))
+                        .unwrap_or(false)
+                {
+                    let first_item = ui.ctx().pass_state(|fs| {
+                        fs.widgets
+                            .get_layer(ui.layer_id())
+                            .skip(num_widgets_before)
+                            .find(|w| w.enabled && w.sense.is_focusable())
+                            .map(|w| w.id)
+                    });
+                    if let Some(first_item) = first_item {
+                        ui.memory_mut(|mem| mem.request_focus(first_item));
+                    }
+                }
+
+                inner
             });
 
         if let Some(popup_response) = &popup_response {
@@ -547,6 +584,24 @@ impl SubMenu {
                 set_open = Some(false);
             }
 
+            // The left arrow key closes the submenu and moves the focus back to its button,
+            // unless the focused widget uses the arrow keys itself (like a `TextEdit`):
+            let focused_layer = ui
+                .memory(|mem| mem.focused())
+                .and_then(|focused| ui.ctx().pass_state(|fs| fs.widgets.get(focused).copied()))
+                .map(|w| w.layer_id);
+            let focused_uses_arrows = ui.memory(|mem| mem.focus_lock_filter().horizontal_arrows);
+            if focused_layer == Some(popup_response.response.layer_id)
+                && !focused_uses_arrows
+                && ui.input(|i| i.key_pressed(Key::ArrowLeft))
+            {
+                set_open = Some(false);
+                ui.memory_mut(|mem| {
+                    mem.request_focus(button_response.id);
+                    mem.move_focus(FocusDirection::None);
+                });
+            }
+
             if ui.will_parent_close() {
                 ui.data_mut(|data| data.remove_by_type::<MenuState>());
             }
@@ -18,8 +18,8 @@
 use emath::GuiRounding as _;
 
 use crate::{
-    Align, Context, CursorIcon, Frame, Id, InnerResponse, LayerId, Layout, NumExt as _, Rangef,
-    Rect, Sense, Stroke, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, lerp, vec2,
+    Align, Context, CursorIcon, CursorIconPriority, Frame, Id, InnerResponse, LayerId, Layout,
+    NumExt as _, Rangef, Rect, Sense, Stroke, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, lerp, vec2,
 };
 
 fn animate_expansion(ctx: &Context, id: Id, is_expanded: bool) -> f32 {
@@ -337,7 +337,8 @@ impl SidePanel {
                     Side::Right => CursorIcon::ResizeEast,
                 }
             };
-            ui.ctx().set_cursor_icon(cursor_icon);
+            ui.ctx()
+                .set_cursor_icon_with_priority(cursor_icon, CursorIconPriority::RESIZE);
         }
 
         PanelState { rect }.store(ui.ctx(), id);
@@ -834,7 +835,8 @@ impl TopBottomPanel {
                     TopBottomSide::Bottom => CursorIcon::ResizeSouth,
                 }
             };
-            ui.ctx().set_cursor_icon(cursor_icon);
+            ui.ctx()
+                .set_cursor_icon_with_priority(cursor_icon, CursorIconPriority::RESIZE);
         }
 
         PanelState { rect }.store(ui.ctx(), id);
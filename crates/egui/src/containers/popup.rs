@@ -170,6 +170,7 @@ pub struct Popup<'a> {
     anchor: PopupAnchor,
     rect_align: RectAlign,
     alternative_aligns: Option<&'a [RectAlign]>,
+    prefer_flip: bool,
     layer_id: LayerId,
     open_kind: OpenKind<'a>,
     close_behavior: PopupCloseBehavior,
@@ -201,6 +202,7 @@ impl<'a> Popup<'a> {
             layer_id,
             rect_align: RectAlign::BOTTOM_START,
             alternative_aligns: None,
+            prefer_flip: false,
             gap: 0.0,
             width: None,
             sense: Sense::click(),
@@ -291,6 +293,22 @@ impl<'a> Popup<'a> {
         self
     }
 
+    /// If the popup doesn't fit in any of the positions, pick the one with the most room.
+    ///
+    /// By default, a popup that doesn't fit anywhere uses the position set with [`Self::align`],
+    /// and is then clamped to the screen, which can make it cover its anchor.
+    /// With this set, it instead flips to the side (down, up, left or right) where
+    /// most of it is visible, like native menus do.
+    ///
+    /// See also [`RectAlign::find_roomiest_align`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn prefer_flip(mut self, prefer_flip: bool) -> Self {
+        self.prefer_flip = prefer_flip;
+        self
+    }
+
     /// Force the popup to be open or closed.
     #[inline]
     pub fn open(mut self, open: bool) -> Self {
@@ -471,26 +489,39 @@ impl<'a> Popup<'a> {
             return self.rect_align;
         };
 
-        RectAlign::find_best_align(
-            #[expect(clippy::iter_on_empty_collections)]
-            once(self.rect_align).chain(
-                self.alternative_aligns
-                    // Need the empty slice so the iters have the same type so we can unwrap_or
-                    .map(|a| a.iter().copied().chain([].iter().copied()))
-                    .unwrap_or(
-                        self.rect_align
-                            .symmetries()
-                            .iter()
-                            .copied()
-                            .chain(RectAlign::MENU_ALIGNS.iter().copied()),
-                    ),
-            ),
-            self.ctx.screen_rect(),
-            anchor_rect,
-            self.gap,
-            expected_popup_size,
-        )
-        .unwrap_or_default()
+        let symmetries = self.rect_align.symmetries();
+        #[expect(clippy::iter_on_empty_collections)]
+        let aligns = once(self.rect_align).chain(
+            self.alternative_aligns
+                // Need the empty slice so the iters have the same type so we can unwrap_or
+                .map(|a| a.iter().copied().chain([].iter().copied()))
+                .unwrap_or(
+                    symmetries
+                        .iter()
+                        .copied()
+                        .chain(RectAlign::MENU_ALIGNS.iter().copied()),
+                ),
+        );
+        let screen_rect = self.ctx.screen_rect();
+
+        let best_align = if self.prefer_flip {
+            RectAlign::find_roomiest_align(
+                aligns,
+                screen_rect,
+                anchor_rect,
+                self.gap,
+                expected_popup_size,
+            )
+        } else {
+            RectAlign::find_best_align(
+                aligns,
+                screen_rect,
+                anchor_rect,
+                self.gap,
+                expected_popup_size,
+            )
+        };
+        best_align.unwrap_or_default()
     }
 
     /// Show the popup.
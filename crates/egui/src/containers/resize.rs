@@ -1,6 +1,6 @@
 use crate::{
-    Align2, Color32, Context, CursorIcon, Id, NumExt as _, Rect, Response, Sense, Shape, Ui,
-    UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b, pos2, vec2,
+    Align2, Color32, Context, CursorIcon, CursorIconPriority, Id, NumExt as _, Rect, Response,
+    Sense, Shape, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b, pos2, vec2,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -215,6 +215,15 @@ impl Resize {
         let mut state = State::load(ui.ctx(), id).unwrap_or_else(|| {
             ui.ctx().request_repaint(); // counter frame delay
 
+            #[cfg(feature = "log")]
+            if self.default_size.x > self.max_size.x || self.default_size.y > self.max_size.y {
+                log::debug!(
+                    "Resize default_size {:?} is larger than max_size {:?}; clamping it",
+                    self.default_size,
+                    self.max_size
+                );
+            }
+
             let default_size = self
                 .default_size
                 .at_least(self.min_size)
@@ -322,7 +331,9 @@ impl Resize {
                 // We show how large we are,
                 // so we must follow the contents:
 
-                state.desired_size[d] = state.desired_size[d].max(state.last_content_size[d]);
+                state.desired_size[d] = state.desired_size[d]
+                    .max(state.last_content_size[d])
+                    .at_most(self.max_size[d]);
 
                 // We are as large as we look
                 size[d] = state.desired_size[d];
@@ -364,7 +375,10 @@ impl Resize {
             paint_resize_corner(ui, &corner_response);
 
             if corner_response.hovered() || corner_response.dragged() {
-                ui.ctx().set_cursor_icon(CursorIcon::ResizeNwSe);
+                ui.ctx().set_cursor_icon_with_priority(
+                    CursorIcon::ResizeNwSe,
+                    CursorIconPriority::RESIZE,
+                );
             }
         }
 
@@ -273,9 +273,9 @@ impl AddAssign for ScrollSource {
 ///
 /// ## See also
 /// If you want to allow zooming, use [`crate::Scene`].
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 #[must_use = "You should call .show()"]
-pub struct ScrollArea {
+pub struct ScrollArea<'a> {
     /// Do we have horizontal/vertical scrolling enabled?
     direction_enabled: Vec2b,
 
@@ -287,10 +287,12 @@ pub struct ScrollArea {
     id_salt: Option<Id>,
     offset_x: Option<f32>,
     offset_y: Option<f32>,
+    scroll_to_fraction: Option<Vec2>,
     on_hover_cursor: Option<CursorIcon>,
     on_drag_cursor: Option<CursorIcon>,
     scroll_source: ScrollSource,
     wheel_scroll_multiplier: Vec2,
+    shift_to_horizontal: bool,
 
     /// If true for vertical or horizontal the scroll wheel will stick to the
     /// end position until user manually changes position. It will become true
@@ -299,9 +301,20 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    on_scroll: Option<OnScroll<'a>>,
+}
+
+/// Callback for [`ScrollArea::on_scroll`].
+struct OnScroll<'a>(Box<dyn FnMut(Vec2, Vec2) + 'a>);
+
+impl std::fmt::Debug for OnScroll<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnScroll").finish_non_exhaustive()
+    }
 }
 
-impl ScrollArea {
+impl<'a> ScrollArea<'a> {
     /// Create a horizontal scroll area.
     #[inline]
     pub fn horizontal() -> Self {
@@ -340,12 +353,15 @@ impl ScrollArea {
             id_salt: None,
             offset_x: None,
             offset_y: None,
+            scroll_to_fraction: None,
             on_hover_cursor: None,
             on_drag_cursor: None,
             scroll_source: ScrollSource::default(),
             wheel_scroll_multiplier: Vec2::splat(1.0),
+            shift_to_horizontal: true,
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            on_scroll: None,
         }
     }
 
@@ -466,6 +482,24 @@ impl ScrollArea {
         self
     }
 
+    /// Scroll to a fraction of the scrollable range, for each axis.
+    ///
+    /// `0.0` means the start (top/left), and `1.0` means the end is fully visible (bottom/right).
+    /// Values outside of `0.0..=1.0` are clamped.
+    ///
+    /// The offset is computed once the size of the content is known,
+    /// so the result will be visible from the next frame on.
+    ///
+    /// Scrolling to `1.0` on an axis with [`Self::stick_to_bottom`] / [`Self::stick_to_right`]
+    /// will make the scroll area stick to the end, and anything less will unstick it.
+    ///
+    /// See also: [`Self::scroll_offset`].
+    #[inline]
+    pub fn scroll_to_fraction(mut self, fraction: Vec2) -> Self {
+        self.scroll_to_fraction = Some(fraction);
+        self
+    }
+
     /// Set the cursor used when the mouse pointer is hovering over the [`ScrollArea`].
     ///
     /// Only applies if [`Self::scroll_source()`] has set [`ScrollSource::drag`] to `true`.
@@ -565,6 +599,20 @@ impl ScrollArea {
         self
     }
 
+    /// Should holding down [`crate::InputOptions::horizontal_scroll_modifier`] (shift by default)
+    /// turn vertical mouse wheel scrolling into horizontal scrolling?
+    ///
+    /// If `false`, the mouse wheel will scroll this area vertically even while shift is held.
+    /// Note that some platforms (e.g. Mac) already report shift+wheel as horizontal scroll events,
+    /// and those will then also be treated as vertical scrolling.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn shift_to_horizontal(mut self, shift_to_horizontal: bool) -> Self {
+        self.shift_to_horizontal = shift_to_horizontal;
+        self
+    }
+
     /// For each axis, should the containing area shrink if the content is small?
     ///
     /// * If `true`, egui will add blank space outside the scroll area.
@@ -586,6 +634,37 @@ impl ScrollArea {
         self
     }
 
+    /// Called with the new scroll offset and the content size whenever the scroll offset changes.
+    ///
+    /// This is called at most once per frame, at the end of [`Self::show`],
+    /// and only if the offset differs from the one of the previous frame.
+    /// It is called for any change in offset: mouse wheel, dragging, the scroll bars,
+    /// `scroll_to_*` animations, and [`Self::stick_to_bottom`] / [`Self::stick_to_right`]
+    /// keeping up with content that grows.
+    ///
+    /// This can be used to e.g. load more items when the user scrolls close to the end:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut near_the_end = false;
+    /// egui::ScrollArea::vertical()
+    ///     .on_scroll(|offset, content_size| {
+    ///         near_the_end = content_size.y - offset.y < 500.0;
+    ///     })
+    ///     .show(ui, |ui| {
+    ///         ui.label("Lots of items");
+    ///     });
+    /// if near_the_end {
+    ///     // Load more items…
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn on_scroll(mut self, on_scroll: impl FnMut(Vec2, Vec2) + 'a) -> Self {
+        self.on_scroll = Some(OnScroll(Box::new(on_scroll)));
+        self
+    }
+
     /// Is any scrolling enabled?
     pub(crate) fn is_any_scroll_enabled(&self) -> bool {
         self.direction_enabled[0] || self.direction_enabled[1]
@@ -616,7 +695,7 @@ impl ScrollArea {
     }
 }
 
-struct Prepared {
+struct Prepared<'a> {
     id: Id,
     state: State,
 
@@ -653,17 +732,23 @@ struct Prepared {
 
     scroll_source: ScrollSource,
     wheel_scroll_multiplier: Vec2,
+    shift_to_horizontal: bool,
     stick_to_end: Vec2b,
+    scroll_to_fraction: Option<Vec2>,
 
     /// If there was a scroll target before the [`ScrollArea`] was added this frame, it's
     /// not for us to handle so we save it and restore it after this [`ScrollArea`] is done.
     saved_scroll_target: [Option<pass_state::ScrollTarget>; 2],
 
     animated: bool,
+
+    /// The offset at the end of the previous frame.
+    previous_offset: Vec2,
+    on_scroll: Option<OnScroll<'a>>,
 }
 
-impl ScrollArea {
-    fn begin(self, ui: &mut Ui) -> Prepared {
+impl<'a> ScrollArea<'a> {
+    fn begin(self, ui: &mut Ui) -> Prepared<'a> {
         let Self {
             direction_enabled,
             auto_shrink,
@@ -674,12 +759,15 @@ impl ScrollArea {
             id_salt,
             offset_x,
             offset_y,
+            scroll_to_fraction,
             on_hover_cursor,
             on_drag_cursor,
             scroll_source,
             wheel_scroll_multiplier,
+            shift_to_horizontal,
             stick_to_end,
             animated,
+            on_scroll,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -692,6 +780,7 @@ impl ScrollArea {
             "ScrollArea",
         );
         let mut state = State::load(&ctx, id).unwrap_or_default();
+        let previous_offset = state.offset;
 
         state.offset.x = offset_x.unwrap_or(state.offset.x);
         state.offset.y = offset_y.unwrap_or(state.offset.y);
@@ -886,9 +975,13 @@ impl ScrollArea {
             viewport,
             scroll_source,
             wheel_scroll_multiplier,
+            shift_to_horizontal,
             stick_to_end,
+            scroll_to_fraction,
             saved_scroll_target,
             animated,
+            previous_offset,
+            on_scroll,
         }
     }
 
@@ -984,7 +1077,7 @@ impl ScrollArea {
     }
 }
 
-impl Prepared {
+impl Prepared<'_> {
     /// Returns content size and state
     fn end(self, ui: &mut Ui) -> (Vec2, State) {
         let Self {
@@ -1001,9 +1094,13 @@ impl Prepared {
             viewport: _,
             scroll_source,
             wheel_scroll_multiplier,
+            shift_to_horizontal,
             stick_to_end,
+            scroll_to_fraction,
             saved_scroll_target,
             animated,
+            previous_offset,
+            on_scroll,
         } = self;
 
         let content_size = content_ui.min_size();
@@ -1122,14 +1219,25 @@ impl Prepared {
         if scroll_source.mouse_wheel && ui.is_enabled() && is_hovering_outer_rect {
             let always_scroll_enabled_direction = ui.style().always_scroll_the_only_direction
                 && direction_enabled[0] != direction_enabled[1];
+
+            // The input has already turned shift+wheel into horizontal scrolling.
+            // If we don't want that, we swap it back:
+            let swap_axes = !shift_to_horizontal && {
+                let options = ui.ctx().options(|o| o.input_options);
+                let modifiers = ui.input(|i| i.modifiers);
+                modifiers.matches_any(options.horizontal_scroll_modifier)
+                    && !modifiers.matches_any(options.vertical_scroll_modifier)
+            };
+
             for d in 0..2 {
                 if direction_enabled[d] {
+                    let source_d = if swap_axes { 1 - d } else { d };
                     let scroll_delta = ui.ctx().input(|input| {
                         if always_scroll_enabled_direction {
                             // no bidirectional scrolling; allow horizontal scrolling without pressing shift
                             input.smooth_scroll_delta[0] + input.smooth_scroll_delta[1]
                         } else {
-                            input.smooth_scroll_delta[d]
+                            input.smooth_scroll_delta[source_d]
                         }
                     });
                     let scroll_delta = scroll_delta * wheel_scroll_multiplier[d];
@@ -1146,7 +1254,7 @@ impl Prepared {
                                 input.smooth_scroll_delta[0] = 0.0;
                                 input.smooth_scroll_delta[1] = 0.0;
                             } else {
-                                input.smooth_scroll_delta[d] = 0.0;
+                                input.smooth_scroll_delta[source_d] = 0.0;
                             }
                         });
 
@@ -1157,6 +1265,22 @@ impl Prepared {
             }
         }
 
+        if let Some(fraction) = scroll_to_fraction {
+            for d in 0..2 {
+                if direction_enabled[d] {
+                    let fraction = fraction[d].clamp(0.0, 1.0);
+                    let target_offset = fraction * max_offset[d].at_least(0.0);
+                    if state.offset[d] != target_offset {
+                        state.offset[d] = target_offset;
+                        ui.ctx().request_repaint();
+                    }
+                    state.vel[d] = 0.0;
+                    state.offset_target[d] = None;
+                    state.scroll_stuck_to_end[d] = fraction == 1.0;
+                }
+            }
+        }
+
         let show_scroll_this_frame = match scroll_bar_visibility {
             ScrollBarVisibility::AlwaysHidden => Vec2b::FALSE,
             ScrollBarVisibility::VisibleWhenNeeded => content_is_too_large,
@@ -1445,6 +1569,12 @@ impl Prepared {
 
         state.store(ui.ctx(), id);
 
+        if let Some(OnScroll(mut on_scroll)) = on_scroll {
+            if state.offset != previous_offset {
+                on_scroll(state.offset, content_size);
+            }
+        }
+
         (content_size, state)
     }
 }
@@ -5,6 +5,13 @@ use crate::{
 };
 use emath::Vec2;
 
+/// A popup that is shown when hovering a widget.
+///
+/// Usually created via [`Response::on_hover_ui`] and friends.
+///
+/// If the tooltip contains interactive widgets (e.g. links, buttons or selectable text),
+/// it stays open while the pointer is over it, and while the pointer is moving towards it,
+/// so that the user can interact with its contents.
 pub struct Tooltip<'a> {
     pub popup: Popup<'a>,
 
@@ -215,6 +222,17 @@ impl Tooltip<'_> {
     /// Argument `allow_interactive_tooltip` controls whether mouse can interact with tooltip that
     /// contains interactive widgets
     pub fn should_show_tooltip(response: &Response, allow_interactive_tooltip: bool) -> bool {
+        let tooltip_delay = response.ctx.style().interaction.tooltip_delay;
+        Self::should_show_tooltip_after(response, allow_interactive_tooltip, tooltip_delay)
+    }
+
+    /// Like [`Self::should_show_tooltip`], but with a custom delay (in seconds)
+    /// instead of [`crate::style::Interaction::tooltip_delay`].
+    pub fn should_show_tooltip_after(
+        response: &Response,
+        allow_interactive_tooltip: bool,
+        tooltip_delay: f32,
+    ) -> bool {
         if response.ctx.memory(|mem| mem.everything_is_visible()) {
             return true;
         }
@@ -231,7 +249,6 @@ impl Tooltip<'_> {
 
         let style = response.ctx.style();
 
-        let tooltip_delay = style.interaction.tooltip_delay;
         let tooltip_grace_time = style.interaction.tooltip_grace_time;
 
         let (
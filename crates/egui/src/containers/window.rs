@@ -37,12 +37,14 @@ pub struct Window<'open> {
     title: WidgetText,
     open: Option<&'open mut bool>,
     area: Area,
+    anchor_to_window: Option<(Id, Align2, Vec2)>,
     frame: Option<Frame>,
     resize: Resize,
-    scroll: ScrollArea,
+    scroll: ScrollArea<'static>,
     collapsible: bool,
     default_open: bool,
     with_title_bar: bool,
+    drag_bounds_from_title: bool,
     fade_out: bool,
 }
 
@@ -57,6 +59,7 @@ impl<'open> Window<'open> {
             title,
             open: None,
             area,
+            anchor_to_window: None,
             frame: None,
             resize: Resize::default()
                 .with_stroke(false)
@@ -66,6 +69,7 @@ impl<'open> Window<'open> {
             collapsible: true,
             default_open: true,
             with_title_bar: true,
+            drag_bounds_from_title: true,
             fade_out: true,
         }
     }
@@ -199,6 +203,9 @@ impl<'open> Window<'open> {
     }
 
     /// Set maximum size of the window, equivalent to calling both `max_width` and `max_height`.
+    ///
+    /// This is enforced both when the user resizes the window and when the contents grows,
+    /// and a [`Self::default_size`] larger than this is clamped.
     #[inline]
     pub fn max_size(mut self, max_size: impl Into<Vec2>) -> Self {
         self.resize = self.resize.max_size(max_size);
@@ -277,6 +284,46 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Anchor this window to another window.
+    ///
+    /// The [`Self::pivot`] of this window is put at the `align` point of the other window's rectangle,
+    /// plus the given offset.
+    /// For instance, `.anchor_to_window(other_id, Align2::RIGHT_TOP, [8.0, 0.0])`
+    /// puts this window just to the right of the other window.
+    ///
+    /// `window_id` is the [`Id`] of the other window, i.e. its title, or what you passed to [`Self::id`].
+    ///
+    /// The position follows the other window when it moves or resizes.
+    /// Its rectangle is read from memory, so show the other window first to avoid a frame of delay.
+    ///
+    /// If the other window has not been shown yet, or is collapsed,
+    /// this window is positioned as normal (see [`Self::default_pos`]).
+    ///
+    /// While anchored, the window is not movable.
+    #[inline]
+    pub fn anchor_to_window(
+        mut self,
+        window_id: Id,
+        align: Align2,
+        offset: impl Into<Vec2>,
+    ) -> Self {
+        self.anchor_to_window = Some((window_id, align, offset.into()));
+        self
+    }
+
+    /// Can the window be moved by dragging its title bar (or anywhere else in the window)?
+    ///
+    /// If `false`, the window can only be moved using a drag handle added with [`Ui::window_drag_handle`].
+    /// This is useful if you have interactive widgets in the title bar or body of the window,
+    /// since dragging on something that only senses clicks (like a button) normally moves the window.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn drag_bounds_from_title(mut self, drag_bounds_from_title: bool) -> Self {
+        self.drag_bounds_from_title = drag_bounds_from_title;
+        self
+    }
+
     /// Set initial collapsed state of the window
     #[inline]
     pub fn default_open(mut self, default_open: bool) -> Self {
@@ -430,13 +477,15 @@ impl Window<'_> {
         let Window {
             title,
             mut open,
-            area,
+            mut area,
+            anchor_to_window,
             frame,
             resize,
             scroll,
             collapsible,
             default_open,
             with_title_bar,
+            drag_bounds_from_title,
             fade_out,
         } = self;
 
@@ -455,6 +504,22 @@ impl Window<'_> {
             return None;
         }
 
+        if !drag_bounds_from_title {
+            area = area.sense(Sense::click()); // still allow clicks to bring to front
+        }
+
+        if let Some((window_id, align, offset)) = anchor_to_window {
+            let is_collapsed = CollapsingState::load(ctx, window_id.with("collapsing"))
+                .is_some_and(|state| !state.is_open());
+            if !is_collapsed {
+                if let Some(window_rect) = ctx.memory(|mem| mem.area_rect(window_id)) {
+                    area = area
+                        .current_pos(align.pos_in_rect(&window_rect) + offset)
+                        .movable(false);
+                }
+            }
+        }
+
         let area_id = area.id;
         let area_layer_id = area.layer();
         let resize_id = area_id.with("resize");
@@ -523,6 +588,7 @@ impl Window<'_> {
                 resize_interaction,
                 ctx,
                 margins,
+                resize.min_size..=resize.max_size,
                 area_layer_id,
                 &mut area,
                 resize_id,
@@ -801,13 +867,19 @@ impl ResizeInteraction {
 
         // TODO(emilk): use one-sided cursors for when we reached the min/max size.
         if (left && top) || (right && bottom) {
-            ctx.set_cursor_icon(CursorIcon::ResizeNwSe);
+            ctx.set_cursor_icon_with_priority(CursorIcon::ResizeNwSe, CursorIconPriority::RESIZE);
         } else if (right && top) || (left && bottom) {
-            ctx.set_cursor_icon(CursorIcon::ResizeNeSw);
+            ctx.set_cursor_icon_with_priority(CursorIcon::ResizeNeSw, CursorIconPriority::RESIZE);
         } else if left || right {
-            ctx.set_cursor_icon(CursorIcon::ResizeHorizontal);
+            ctx.set_cursor_icon_with_priority(
+                CursorIcon::ResizeHorizontal,
+                CursorIconPriority::RESIZE,
+            );
         } else if bottom || top {
-            ctx.set_cursor_icon(CursorIcon::ResizeVertical);
+            ctx.set_cursor_icon_with_priority(
+                CursorIcon::ResizeVertical,
+                CursorIconPriority::RESIZE,
+            );
         }
     }
 
@@ -824,6 +896,7 @@ fn resize_response(
     resize_interaction: ResizeInteraction,
     ctx: &Context,
     margins: Vec2,
+    inner_size_range: std::ops::RangeInclusive<Vec2>,
     area_layer_id: LayerId,
     area: &mut area::Prepared,
     resize_id: Id,
@@ -832,6 +905,25 @@ fn resize_response(
         return;
     };
 
+    {
+        // Respect the min/max size, keeping the side opposite to the dragged one in place.
+        // Otherwise dragging the left/top side past the limit would move the window.
+        let size = new_rect
+            .size()
+            .at_least(*inner_size_range.start() + margins)
+            .at_most(*inner_size_range.end() + margins);
+        if resize_interaction.left.drag {
+            new_rect.min.x = new_rect.max.x - size.x;
+        } else if resize_interaction.right.drag {
+            new_rect.max.x = new_rect.min.x + size.x;
+        }
+        if resize_interaction.top.drag {
+            new_rect.min.y = new_rect.max.y - size.y;
+        } else if resize_interaction.bottom.drag {
+            new_rect.max.y = new_rect.min.y + size.y;
+        }
+    }
+
     if area.constrain() {
         new_rect = Context::constrain_window_rect_to_area(new_rect, area.constrain_rect());
     }
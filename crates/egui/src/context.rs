@@ -4,7 +4,7 @@ use std::{borrow::Cow, cell::RefCell, panic::Location, sync::Arc, time::Duration
 
 use emath::GuiRounding as _;
 use epaint::{
-    ClippedPrimitive, ClippedShape, Color32, ImageData, Pos2, Rect, StrokeKind,
+    ClippedPrimitive, ClippedShape, Color32, ImageData, Pos2, Rect, Shape, StrokeKind,
     TessellationOptions, TextureId, Vec2,
     emath::{self, TSTransform},
     mutex::RwLock,
@@ -15,12 +15,12 @@ use epaint::{
 };
 
 use crate::{
-    Align2, CursorIcon, DeferredViewportUiCallback, FontDefinitions, Grid, Id, ImmediateViewport,
-    ImmediateViewportRendererCallback, Key, KeyboardShortcut, Label, LayerId, Memory,
-    ModifierNames, Modifiers, NumExt as _, Order, Painter, Plugin, RawInput, Response, RichText,
-    ScrollArea, Sense, Style, TextStyle, TextureHandle, TextureOptions, Ui, ViewportBuilder,
-    ViewportCommand, ViewportId, ViewportIdMap, ViewportIdPair, ViewportIdSet, ViewportOutput,
-    Widget as _, WidgetRect, WidgetText,
+    Align2, CursorIcon, CursorIconPriority, DeferredViewportUiCallback, FontDefinitions, Grid, Id,
+    ImmediateViewport, ImmediateViewportRendererCallback, Key, KeyboardShortcut, Label, LayerId,
+    Memory, ModifierNames, Modifiers, NumExt as _, Order, Painter, Plugin, RawInput, Response,
+    RichText, ScrollArea, Sense, Style, TextStyle, TextureHandle, TextureOptions, Ui,
+    ViewportBuilder, ViewportCommand, ViewportId, ViewportIdMap, ViewportIdPair, ViewportIdSet,
+    ViewportOutput, Widget as _, WidgetRect, WidgetText,
     animation_manager::AnimationManager,
     containers::{self, area::AreaState},
     data::output::PlatformOutput,
@@ -365,6 +365,44 @@ impl ViewportRepaintInfo {
 
 // ----------------------------------------------------------------------------
 
+/// An ongoing [`Context::animate_zoom_to`].
+#[derive(Clone, Copy, Debug)]
+struct ZoomAnimation {
+    from: f32,
+    to: f32,
+    start_time: f64,
+
+    /// In seconds.
+    duration: f32,
+}
+
+impl ZoomAnimation {
+    /// Zoom levels at which the fonts are re-rasterized during the animation.
+    const STEP: f32 = 0.25;
+
+    fn is_done(&self, time: f64) -> bool {
+        self.start_time + f64::from(self.duration) <= time
+    }
+
+    /// The zoom factor the ui should look like it has at this time.
+    fn visual_zoom_factor(&self, time: f64) -> f32 {
+        let t = ((time - self.start_time) as f32 / self.duration).clamp(0.0, 1.0);
+        emath::lerp(self.from..=self.to, emath::easing::cubic_out(t))
+    }
+
+    /// The zoom factor to actually lay out and rasterize the ui with at this time.
+    fn logical_zoom_factor(&self, time: f64) -> f32 {
+        if self.is_done(time) {
+            self.to
+        } else {
+            let snapped = (self.visual_zoom_factor(time) / Self::STEP).round() * Self::STEP;
+            snapped.clamp(self.from.min(self.to), self.from.max(self.to))
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Default)]
 struct ContextImpl {
     fonts: Option<Fonts>,
@@ -386,6 +424,13 @@ struct ContextImpl {
     /// Set during the pass, becomes active at the start of the next pass.
     new_zoom_factor: Option<f32>,
 
+    /// Set by [`Context::animate_zoom_to`].
+    zoom_animation: Option<ZoomAnimation>,
+
+    /// How much to scale the painted shapes this pass, to bridge the gap
+    /// between the visual and logical zoom factor of a [`ZoomAnimation`].
+    zoom_visual_scale: Option<f32>,
+
     os: OperatingSystem,
 
     /// How deeply nested are we?
@@ -427,6 +472,20 @@ impl ContextImpl {
         let viewport = self.viewports.entry(viewport_id).or_default();
 
         if is_outermost_viewport {
+            self.zoom_visual_scale = None;
+            if let Some(animation) = self.zoom_animation {
+                let time = new_raw_input.time.unwrap_or(viewport.input.time);
+                let logical = animation.logical_zoom_factor(time);
+                if logical != self.memory.options.zoom_factor {
+                    self.new_zoom_factor = Some(logical);
+                }
+                if animation.is_done(time) {
+                    self.zoom_animation = None;
+                } else {
+                    self.zoom_visual_scale = Some(animation.visual_zoom_factor(time) / logical);
+                }
+            }
+
             if let Some(new_zoom_factor) = self.new_zoom_factor.take() {
                 let ratio = self.memory.options.zoom_factor / new_zoom_factor;
                 self.memory.options.zoom_factor = new_zoom_factor;
@@ -533,10 +592,11 @@ impl ContextImpl {
         let input = &self.viewport().input;
         let max_texture_side = input.max_texture_side;
 
+        let mut definitions_changed = false;
+
         if let Some(font_definitions) = self.memory.new_font_definitions.take() {
-            // New font definition loaded, so we need to reload all fonts.
-            self.fonts = None;
             self.font_definitions = font_definitions;
+            definitions_changed = true;
             #[cfg(feature = "log")]
             log::trace!("Loading new font definitions");
         }
@@ -544,7 +604,7 @@ impl ContextImpl {
         if !self.memory.add_fonts.is_empty() {
             let fonts = self.memory.add_fonts.drain(..);
             for font in fonts {
-                self.fonts = None; // recreate all the fonts
+                definitions_changed = true;
                 for family in font.families {
                     let fam = self
                         .font_definitions
@@ -565,6 +625,13 @@ impl ContextImpl {
             log::trace!("Adding new fonts");
         }
 
+        if definitions_changed {
+            if let Some(fonts) = &mut self.fonts {
+                // Only load the fonts that changed, keeping the rest (and their glyphs in the atlas):
+                fonts.set_definitions(self.font_definitions.clone());
+            }
+        }
+
         let text_alpha_from_coverage = self.memory.options.style().visuals.text_alpha_from_coverage;
 
         let mut is_new = false;
@@ -948,6 +1015,46 @@ impl Context {
         self.write(move |ctx| reader(&ctx.viewport().graphics))
     }
 
+    /// The text of every galley painted so far this pass, in the current viewport.
+    ///
+    /// This includes labels, button texts, headings, the contents of text edits, etc.,
+    /// back-to-front and with duplicates removed.
+    /// It works by looking at the painted shapes, so it works without `accesskit`.
+    ///
+    /// This is mostly useful for tests.
+    /// Call it at the end of the pass, after all the ui code has run,
+    /// e.g. from a [`Self::on_end_pass`] callback.
+    pub fn all_text(&self) -> Vec<String> {
+        fn collect<'a>(shape: &'a Shape, texts: &mut Vec<&'a str>) {
+            match shape {
+                Shape::Text(text_shape) => texts.push(text_shape.galley.text()),
+                Shape::Vec(shapes) => {
+                    for shape in shapes {
+                        collect(shape, texts);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.write(|ctx| {
+            let area_order = ctx.memory.areas().order().to_vec();
+            let mut texts = vec![];
+            for list in ctx.viewport().graphics.ordered(&area_order) {
+                for clipped_shape in list.all_entries() {
+                    collect(&clipped_shape.shape, &mut texts);
+                }
+            }
+
+            let mut seen = crate::ahash::HashSet::default();
+            texts
+                .into_iter()
+                .filter(|text| !text.is_empty() && seen.insert(*text))
+                .map(ToOwned::to_owned)
+                .collect()
+        })
+    }
+
     /// Read-only access to [`PlatformOutput`].
     ///
     /// This is what egui outputs each pass and frame.
@@ -1477,15 +1584,43 @@ impl Context {
         self.write(|ctx| ctx.os = os);
     }
 
-    /// Set the cursor icon.
+    /// Set the cursor icon, with [`CursorIconPriority::DEFAULT`].
     ///
-    /// Equivalent to:
+    /// This is ignored if a cursor icon with a higher priority was already requested this pass,
+    /// e.g. by a resize handle.
+    /// To ignore priorities, write to [`PlatformOutput::cursor_icon`] directly:
     /// ```
     /// # let ctx = egui::Context::default();
     /// ctx.output_mut(|o| o.cursor_icon = egui::CursorIcon::PointingHand);
     /// ```
     pub fn set_cursor_icon(&self, cursor_icon: CursorIcon) {
-        self.output_mut(|o| o.cursor_icon = cursor_icon);
+        self.set_cursor_icon_with_priority(cursor_icon, CursorIconPriority::DEFAULT);
+    }
+
+    /// Request a cursor icon with the given priority.
+    ///
+    /// The highest priority request of this pass wins.
+    /// For equal priorities, the last request wins.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// use egui::{CursorIcon, CursorIconPriority};
+    /// ctx.set_cursor_icon_with_priority(CursorIcon::ResizeHorizontal, CursorIconPriority::RESIZE);
+    /// ctx.set_cursor_icon(CursorIcon::PointingHand); // ignored: lower priority
+    /// assert_eq!(ctx.output(|o| o.cursor_icon), CursorIcon::ResizeHorizontal);
+    /// ```
+    pub fn set_cursor_icon_with_priority(
+        &self,
+        cursor_icon: CursorIcon,
+        priority: CursorIconPriority,
+    ) {
+        self.write(|ctx| {
+            let viewport = ctx.viewport();
+            if viewport.this_pass.cursor_icon_priority <= priority {
+                viewport.this_pass.cursor_icon_priority = priority;
+                viewport.output.cursor_icon = cursor_icon;
+            }
+        });
     }
 
     /// Add a command to [`PlatformOutput::commands`],
@@ -1738,7 +1873,10 @@ impl Context {
     /// So, it's not that we are requesting repaint within X duration. We are rather timing out
     /// during app idle time where we are not receiving any new input events.
     ///
-    /// This repaints the specified viewport.
+    /// This repaints the specified viewport, and only that one:
+    /// each viewport keeps its own schedule, so this will not wake up any other viewport.
+    /// The integration is only notified (via [`Self::set_request_repaint_callback`])
+    /// when this brings that viewport's repaint forward.
     #[track_caller]
     pub fn request_repaint_after_for(&self, duration: Duration, id: ViewportId) {
         let cause = RepaintCause::new();
@@ -1929,6 +2067,11 @@ impl Context {
     ///
     /// The new fonts will become active at the start of the next pass.
     /// This will overwrite the existing fonts.
+    ///
+    /// Fonts that are unchanged keep their rasterized glyphs,
+    /// so switching to definitions that e.g. only add a font for another language is cheap:
+    /// the cost is loading the new fonts, and rasterizing each of their glyphs the first time it is shown.
+    /// Changing the data or tweak of a font reloads that font.
     pub fn set_fonts(&self, font_definitions: FontDefinitions) {
         profiling::function_scope!();
 
@@ -1954,7 +2097,7 @@ impl Context {
     /// but you can call this to install additional fonts that support e.g. korean characters.
     ///
     /// The new font will become active at the start of the next pass.
-    /// This will keep the existing fonts.
+    /// This will keep the existing fonts, and their rasterized glyphs.
     pub fn add_font(&self, new_font: FontInsert) {
         profiling::function_scope!();
 
@@ -2047,6 +2190,29 @@ impl Context {
         });
     }
 
+    /// Register a named set of widget visuals in both the dark and light mode [`Style`]s.
+    ///
+    /// Widgets can then opt into it by name, e.g. with [`crate::Button::class`].
+    /// Registering a class with an existing name replaces it.
+    ///
+    /// If you want different visuals for dark and light mode,
+    /// insert into [`Style::classes`] using [`Self::style_mut_of`] instead.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut danger = ui.visuals().widgets.clone();
+    /// danger.inactive.weak_bg_fill = egui::Color32::DARK_RED;
+    /// danger.hovered.weak_bg_fill = egui::Color32::RED;
+    /// ui.ctx().register_style_class("danger", danger);
+    /// # });
+    /// ```
+    pub fn register_style_class(&self, name: impl Into<String>, widgets: crate::style::Widgets) {
+        let name = name.into();
+        self.all_styles_mut(|style| {
+            style.classes.insert(name.clone(), widgets.clone());
+        });
+    }
+
     /// The [`Style`] used by all subsequent windows, panels etc.
     pub fn style_of(&self, theme: Theme) -> Arc<Style> {
         self.options(|opt| match theme {
@@ -2163,10 +2329,14 @@ impl Context {
     ///
     /// It is better to call this than modifying
     /// [`Options::zoom_factor`].
+    ///
+    /// This cancels any ongoing [`Self::animate_zoom_to`].
+    /// See also [`Self::animate_zoom_to`] for a smooth transition.
     #[inline(always)]
     pub fn set_zoom_factor(&self, zoom_factor: f32) {
         let cause = RepaintCause::new();
         self.write(|ctx| {
+            ctx.zoom_animation = None;
             if ctx.memory.options.zoom_factor != zoom_factor {
                 ctx.new_zoom_factor = Some(zoom_factor);
                 #[expect(clippy::iter_over_hash_type)]
@@ -2177,6 +2347,61 @@ impl Context {
         });
     }
 
+    /// Smoothly change the [`Self::zoom_factor`] to `zoom_factor` over `duration` seconds.
+    ///
+    /// Changing the zoom factor means re-laying out the ui and re-rasterizing all fonts,
+    /// which is too expensive to do every frame.
+    /// So during the animation there is a distinction between two zoom factors:
+    ///
+    /// * The _logical_ zoom factor, which is what the ui is laid out with, what fonts are
+    ///   rasterized for, and what [`Self::zoom_factor`] returns.
+    ///   It only changes in steps of `0.25` while animating, and then to `zoom_factor` at the end.
+    /// * The _visual_ zoom factor, which is what the ui looks like.
+    ///   This changes smoothly, by scaling all painted shapes from the top-left corner of the screen
+    ///   to bridge the gap to the logical zoom factor.
+    ///
+    /// The tradeoff is that while animating, text may look a bit blurry (from the scaling),
+    /// the ui may briefly be cut off or not fill the whole screen,
+    /// and the pointer won't line up exactly with what is painted.
+    /// This is usually fine for a short animation, e.g. for an accessibility zoom control.
+    ///
+    /// Calling this during an ongoing animation starts a new animation from the current visual zoom factor.
+    /// Calling [`Self::set_zoom_factor`] cancels the animation.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// ctx.animate_zoom_to(2.0, 0.3);
+    /// ```
+    pub fn animate_zoom_to(&self, zoom_factor: f32, duration: f32) {
+        if duration <= 0.0 {
+            self.set_zoom_factor(zoom_factor);
+            return;
+        }
+
+        let time = self.input(|i| i.time);
+        let cause = RepaintCause::new();
+        self.write(|ctx| {
+            let from = ctx.zoom_animation.map_or_else(
+                || {
+                    ctx.new_zoom_factor
+                        .unwrap_or(ctx.memory.options.zoom_factor)
+                },
+                |animation| animation.visual_zoom_factor(time),
+            );
+            ctx.new_zoom_factor = None;
+            ctx.zoom_animation = Some(ZoomAnimation {
+                from,
+                to: zoom_factor,
+                start_time: time,
+                duration,
+            });
+            #[expect(clippy::iter_over_hash_type)]
+            for viewport_id in ctx.all_viewport_ids() {
+                ctx.request_repaint(viewport_id, cause.clone());
+            }
+        });
+    }
+
     /// Allocate a texture.
     ///
     /// This is for advanced users.
@@ -2496,11 +2721,18 @@ impl ContextImpl {
             }
         }
 
-        let shapes = viewport
+        let mut shapes = viewport
             .graphics
             .drain(self.memory.areas().order(), &self.memory.to_global);
 
-        let mut repaint_needed = false;
+        if let Some(scale) = self.zoom_visual_scale {
+            let transform = TSTransform::from_scaling(scale);
+            for clipped_shape in &mut shapes {
+                clipped_shape.transform(transform);
+            }
+        }
+
+        let mut repaint_needed = self.zoom_animation.is_some();
 
         if self.memory.options.repaint_on_widget_change {
             profiling::scope!("compare-widget-rects");
@@ -2809,6 +3041,8 @@ impl Context {
     ///
     /// This will also affect input.
     /// The direction of the given transform is "into the global coordinate system".
+    /// Pointer positions are mapped into the layer using the inverse of this transform,
+    /// which is exact since [`TSTransform`] only supports uniform scaling and translation.
     ///
     /// This is a sticky setting, remembered from one frame to the next.
     ///
@@ -2828,7 +3062,7 @@ impl Context {
 
     /// Return how to transform the graphics of the given layer into the global coordinate system.
     ///
-    /// Set this with [`Self::layer_transform_to_global`].
+    /// Set this with [`Self::set_transform_layer`].
     pub fn layer_transform_to_global(&self, layer_id: LayerId) -> Option<TSTransform> {
         self.memory(|m| m.to_global.get(&layer_id).copied())
     }
@@ -2979,8 +3213,14 @@ impl Context {
     /// Use e.g. [`emath::easing::quadratic_out`]
     /// for a responsive start and a slow end.
     ///
+    /// The easing is applied to the linear progress at read time, so toggling `target_value`
+    /// mid-animation simply reverses the progress from where it is.
+    ///
     /// The easing function flips when `target_value` is `false`,
-    /// so that when going back towards 0.0, we get
+    /// so that when going back towards 0.0, we get the same curve as going towards 1.0, mirrored.
+    /// For easing functions that are not symmetric around `(0.5, 0.5)` (like [`emath::easing::cubic_out`]),
+    /// this means the value can jump a bit when toggling mid-animation.
+    /// Symmetric ones (like [`emath::easing::smoothstep`]) are always continuous.
     #[track_caller] // To track repaint cause
     pub fn animate_bool_with_time_and_easing(
         &self,
@@ -3016,12 +3256,34 @@ impl Context {
     /// When it is called with a new value, it linearly interpolates to it in the given time.
     #[track_caller] // To track repaint cause
     pub fn animate_value_with_time(&self, id: Id, target_value: f32, animation_time: f32) -> f32 {
+        self.animate_value_with_time_and_easing(
+            id,
+            target_value,
+            animation_time,
+            emath::easing::linear,
+        )
+    }
+
+    /// Like [`Self::animate_value_with_time`] but allows you to control the easing function.
+    ///
+    /// Use e.g. [`emath::easing::smoothstep`] for a smooth start and end.
+    ///
+    /// If the target value changes mid-animation, a new animation starts from the current value.
+    #[track_caller] // To track repaint cause
+    pub fn animate_value_with_time_and_easing(
+        &self,
+        id: Id,
+        target_value: f32,
+        animation_time: f32,
+        easing: fn(f32) -> f32,
+    ) -> f32 {
         let animated_value = self.write(|ctx| {
             ctx.animation_manager.animate_value(
                 &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
                 animation_time,
                 id,
                 target_value,
+                easing,
             )
         });
         let animation_in_progress = animated_value != target_value;
@@ -3032,6 +3294,45 @@ impl Context {
         animated_value
     }
 
+    /// Smoothly animate a [`Color32`].
+    ///
+    /// At the first call the color is stored, keyed by `id`.
+    /// When it is called with a new color, it interpolates to it in linear space over `animation_time` seconds.
+    /// If the target color changes mid-animation, a new animation starts from the current color.
+    ///
+    /// The color is remembered until [`Self::clear_animations`] is called,
+    /// so use a stable [`Id`] for each animated color.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.label("Hover me");
+    /// let target = if response.hovered() {
+    ///     egui::Color32::RED
+    /// } else {
+    ///     ui.visuals().text_color()
+    /// };
+    /// let color = ui.ctx().animate_color(response.id.with("color"), target, 0.2);
+    /// ui.painter().rect_filled(response.rect, 0.0, color.gamma_multiply(0.2));
+    /// # });
+    /// ```
+    #[track_caller] // To track repaint cause
+    pub fn animate_color(&self, id: Id, target: Color32, animation_time: f32) -> Color32 {
+        let animated_color = self.write(|ctx| {
+            ctx.animation_manager.animate_color(
+                &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
+                animation_time,
+                id,
+                target,
+            )
+        });
+        let animation_in_progress = animated_color != target;
+        if animation_in_progress {
+            self.request_repaint();
+        }
+
+        animated_color
+    }
+
     /// Clear memory of any animations.
     pub fn clear_animations(&self) {
         self.write(|ctx| ctx.animation_manager = Default::default());
@@ -4194,4 +4495,158 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_animate_bool_with_easing() {
+        use crate::Id;
+        use emath::easing::smoothstep;
+
+        let ctx = Context::default();
+        let id = Id::new("anim");
+        let animate = |target_value| {
+            let mut value = f32::NAN;
+            ctx.run(Default::default(), |ctx| {
+                value = ctx.animate_bool_with_time_and_easing(id, target_value, 0.5, smoothstep);
+            });
+            value
+        };
+
+        assert_eq!(animate(false), 0.0, "The first call should not animate");
+
+        // Each frame is 1/60 s, so half the animation takes 15 frames:
+        let mut prev = 0.0;
+        for frame in 1..=15 {
+            let value = animate(true);
+            assert!(prev < value, "Should move towards 1.0");
+            if frame < 15 {
+                assert!(value < frame as f32 / 30.0, "Should be eased in");
+            }
+            prev = value;
+        }
+        assert!((prev - 0.5).abs() < 1e-3);
+
+        // Toggling mid-animation reverses smoothly:
+        let value = animate(false);
+        assert!(value < prev && prev - value < 0.1, "Should not jump");
+
+        for _ in 0..30 {
+            animate(false);
+        }
+        assert_eq!(animate(false), 0.0);
+    }
+
+    #[test]
+    fn test_animate_color() {
+        use crate::{Color32, Id};
+
+        let ctx = Context::default();
+        let id = Id::new("color");
+        let animate = |target| {
+            let mut color = Color32::PLACEHOLDER;
+            ctx.run(Default::default(), |ctx| {
+                color = ctx.animate_color(id, target, 0.5);
+            });
+            color
+        };
+
+        assert_eq!(
+            animate(Color32::BLACK),
+            Color32::BLACK,
+            "No animation at first"
+        );
+
+        // Halfway to white (each frame is 1/60 s):
+        let mut halfway = Color32::BLACK;
+        for _ in 0..15 {
+            halfway = animate(Color32::WHITE);
+        }
+        assert_eq!(halfway.r(), halfway.g());
+        assert!(
+            150 < halfway.r() && halfway.r() < 220,
+            "Should be interpolated in linear space, got {halfway:?}"
+        );
+
+        // Re-target mid-animation, starting from the current color:
+        let retargeted = animate(Color32::RED);
+        assert!(halfway.g().abs_diff(retargeted.g()) < 20, "Should not jump");
+
+        for _ in 0..30 {
+            animate(Color32::RED);
+        }
+        assert_eq!(animate(Color32::RED), Color32::RED);
+    }
+
+    #[test]
+    fn test_animate_zoom_to() {
+        use crate::RawInput;
+
+        let ctx = Context::default();
+        let run = |time: f64| {
+            let input = RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let output = ctx.run(input, |_| {});
+            (ctx.zoom_factor(), output.pixels_per_point)
+        };
+
+        run(0.0);
+        ctx.animate_zoom_to(2.0, 1.0);
+
+        // The logical zoom factor changes in steps:
+        let (zoom_factor, pixels_per_point) = run(0.2);
+        assert_eq!(zoom_factor, 1.5);
+        assert_eq!(pixels_per_point, 1.5);
+        assert!(
+            ctx.has_requested_repaint(),
+            "Should keep repainting while animating"
+        );
+
+        // …and ends up at the target:
+        assert_eq!(run(1.0).0, 2.0);
+        assert_eq!(run(1.1).0, 2.0);
+
+        // Setting the zoom factor cancels the animation:
+        ctx.animate_zoom_to(1.0, 1.0);
+        ctx.set_zoom_factor(3.0);
+        assert_eq!(run(1.2).0, 3.0);
+        assert_eq!(run(1.5).0, 3.0);
+    }
+
+    #[test]
+    fn test_request_repaint_after_for_other_viewport() {
+        use std::sync::{Arc, Mutex};
+        use std::time::Duration;
+
+        use crate::ViewportId;
+
+        let ctx = Context::default();
+        let requests = Arc::new(Mutex::new(vec![]));
+        ctx.set_request_repaint_callback({
+            let requests = Arc::clone(&requests);
+            move |info| {
+                requests
+                    .lock()
+                    .unwrap()
+                    .push((info.viewport_id, info.delay))
+            }
+        });
+
+        let overlay = ViewportId::from_hash_of("overlay");
+        ctx.request_repaint_after_for(Duration::from_secs(2), overlay);
+        ctx.request_repaint_after_for(Duration::from_secs(3), overlay);
+        ctx.request_repaint_after_for(Duration::from_secs(1), overlay);
+
+        let requests = requests.lock().unwrap();
+        assert!(
+            requests.iter().all(|(id, _)| *id == overlay),
+            "Only the targeted viewport should be woken up"
+        );
+        assert_eq!(
+            requests.len(),
+            2,
+            "A later deadline should not wake up the viewport again"
+        );
+        assert!(requests[1].1 < requests[0].1);
+    }
 }
@@ -1098,6 +1098,20 @@ impl KeyboardShortcut {
         }
         s
     }
+
+    /// Format the shortcut the way it is usually shown on the given operating system,
+    /// e.g. `⌘C` on Mac and `Ctrl+C` elsewhere.
+    ///
+    /// [`crate::Context::format_shortcut`] does the same for the current operating system,
+    /// but also falls back to names if the current font lacks the modifier symbols.
+    pub fn format_for_os(&self, os: crate::os::OperatingSystem) -> String {
+        let is_mac = os.is_mac();
+        if is_mac {
+            self.format(&ModifierNames::SYMBOLS, is_mac)
+        } else {
+            self.format(&ModifierNames::NAMES, is_mac)
+        }
+    }
 }
 
 #[test]
@@ -1113,6 +1127,12 @@ fn format_kb_shortcut() {
     );
     assert_eq!(cmd_shift_f.format(&ModifierNames::SYMBOLS, false), "⌃⇧F");
     assert_eq!(cmd_shift_f.format(&ModifierNames::SYMBOLS, true), "⇧⌘F");
+
+    use crate::os::OperatingSystem;
+    let cmd_c = KeyboardShortcut::new(Modifiers::COMMAND, Key::C);
+    assert_eq!(cmd_c.format_for_os(OperatingSystem::Mac), "⌘C");
+    assert_eq!(cmd_c.format_for_os(OperatingSystem::Windows), "Ctrl+C");
+    assert_eq!(cmd_c.format_for_os(OperatingSystem::Nix), "Ctrl+C");
 }
 
 // ----------------------------------------------------------------------------
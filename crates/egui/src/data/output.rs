@@ -430,6 +430,39 @@ impl CursorIcon {
     ];
 }
 
+/// How important a request for a [`CursorIcon`] is.
+///
+/// When several widgets request a cursor icon during the same pass,
+/// the request with the highest priority wins.
+/// For equal priorities, the last request wins.
+///
+/// Widgets usually only request a cursor icon when hovered,
+/// and only widgets in the top-most layer under the pointer are hovered.
+///
+/// See [`crate::Context::set_cursor_icon_with_priority`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CursorIconPriority(pub u8);
+
+impl CursorIconPriority {
+    /// No cursor icon has been requested.
+    ///
+    /// Requesting an icon with this priority only has an effect if nothing else has requested one.
+    pub const NONE: Self = Self(0);
+
+    /// Hovering selectable or editable text.
+    pub const TEXT: Self = Self(10);
+
+    /// Used by [`crate::Context::set_cursor_icon`], e.g. for links.
+    pub const DEFAULT: Self = Self(20);
+
+    /// Something that can be grabbed and dragged.
+    pub const GRAB: Self = Self(30);
+
+    /// Resize handles of windows, panels, etc.
+    pub const RESIZE: Self = Self(40);
+}
+
 /// Things that happened during this frame that the integration may be interested in.
 ///
 /// In particular, these events may be useful for accessibility, i.e. for screen readers.
@@ -673,6 +706,7 @@ impl WidgetInfo {
             WidgetType::TextEdit => "text edit",
             WidgetType::Button => "button",
             WidgetType::Checkbox => "checkbox",
+            WidgetType::ToggleSwitch => "toggle switch",
             WidgetType::RadioButton => "radio",
             WidgetType::RadioGroup => "radio group",
             WidgetType::SelectableLabel => "selectable",
@@ -693,6 +727,9 @@ impl WidgetInfo {
             if *typ == WidgetType::Checkbox {
                 let state = if *selected { "checked" } else { "unchecked" };
                 description = format!("{state} {description}");
+            } else if *typ == WidgetType::ToggleSwitch {
+                let state = if *selected { "on" } else { "off" };
+                description = format!("{description} {state}");
             } else {
                 description += if *selected { "selected" } else { "" };
             }
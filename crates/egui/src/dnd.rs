@@ -0,0 +1,146 @@
+//! Ready-made widgets built on top of drag-and-drop.
+//!
+//! For the building blocks, see [`crate::DragAndDrop`], [`crate::Ui::dnd_drag_source`]
+//! and [`crate::Ui::dnd_drop_zone`].
+
+use crate::{DragAndDrop, Id, NumExt as _, Pos2, Rect, Response, Ui, UiKind, Vec2, vec2};
+
+/// The payload of an item being dragged in a [`reorder_list`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ReorderItem {
+    list_id: Id,
+    index: usize,
+}
+
+/// Show a vertical list of items that the user can reorder by dragging them.
+///
+/// `item_ui` is called once for each item.
+/// While an item is being dragged, it follows the pointer,
+/// and a line shows where it will be inserted when released.
+/// Items can only be dropped within the same list.
+///
+/// If the list is inside a [`crate::ScrollArea`], it is scrolled when dragging close to its edges.
+///
+/// The `id_salt` must be unique among the lists in the same [`Ui`].
+///
+/// [`Response::changed`] is `true` on the frame the items were reordered.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut items = vec!["First", "Second", "Third"];
+/// egui::dnd::reorder_list(ui, "my_list", &mut items, |ui, item| {
+///     ui.label(*item);
+/// });
+/// # });
+/// ```
+#[doc(alias = "drag and drop")]
+pub fn reorder_list<T>(
+    ui: &mut Ui,
+    id_salt: impl std::hash::Hash,
+    items: &mut Vec<T>,
+    mut item_ui: impl FnMut(&mut Ui, &mut T),
+) -> Response {
+    let list_id = ui.make_persistent_id(id_salt);
+
+    let dragged = DragAndDrop::payload::<ReorderItem>(ui.ctx())
+        .filter(|payload| payload.list_id == list_id)
+        .map(|payload| payload.index);
+
+    let mut item_rects = Vec::with_capacity(items.len());
+    let mut response = ui
+        .vertical(|ui| {
+            for (index, item) in items.iter_mut().enumerate() {
+                let payload = ReorderItem { list_id, index };
+                let response = ui
+                    .dnd_drag_source(list_id.with(index), payload, |ui| item_ui(ui, item))
+                    .response;
+                item_rects.push(response.rect);
+            }
+        })
+        .response;
+
+    let Some(dragged_index) = dragged else {
+        return response;
+    };
+    let Some(pointer) = ui.ctx().pointer_interact_pos() else {
+        return response;
+    };
+
+    // Allow dropping a bit above and below the list, to make it easy to move items to the ends:
+    let drop_rect = response
+        .rect
+        .expand2(vec2(0.0, ui.spacing().interact_size.y));
+    if !drop_rect.contains(pointer) {
+        return response;
+    }
+
+    let insert_index = item_rects
+        .iter()
+        .filter(|rect| rect.center().y < pointer.y)
+        .count();
+    let line_y = insertion_line_y(&item_rects, insert_index, ui.spacing().item_spacing.y);
+    ui.painter().hline(
+        response.rect.x_range(),
+        line_y,
+        ui.visuals().widgets.active.fg_stroke,
+    );
+
+    auto_scroll(ui, pointer);
+
+    if ui.input(|i| i.pointer.any_released()) {
+        DragAndDrop::clear_payload(ui.ctx());
+
+        // Removing the dragged item shifts everything after it up one step:
+        let to = if dragged_index < insert_index {
+            insert_index - 1
+        } else {
+            insert_index
+        };
+        if to != dragged_index {
+            let item = items.remove(dragged_index);
+            items.insert(to, item);
+            response.mark_changed();
+        }
+    }
+
+    response
+}
+
+/// Where to show the insertion line for inserting before `insert_index`.
+fn insertion_line_y(item_rects: &[Rect], insert_index: usize, item_spacing: f32) -> f32 {
+    match (
+        insert_index.checked_sub(1).and_then(|i| item_rects.get(i)),
+        item_rects.get(insert_index),
+    ) {
+        (Some(above), Some(below)) => 0.5 * (above.bottom() + below.top()),
+        (Some(above), None) => above.bottom() + 0.5 * item_spacing,
+        (None, Some(below)) => below.top() - 0.5 * item_spacing,
+        (None, None) => 0.0,
+    }
+}
+
+/// Scroll any enclosing [`crate::ScrollArea`] when dragging close to its edges.
+fn auto_scroll(ui: &Ui, pointer: Pos2) {
+    let is_in_scroll_area = ui
+        .stack()
+        .iter()
+        .any(|frame| frame.kind() == Some(UiKind::ScrollArea));
+    if !is_in_scroll_area {
+        return;
+    }
+
+    let clip_rect = ui.clip_rect();
+    let edge = 2.0 * ui.spacing().interact_size.y;
+    let points_per_second = 400.0;
+    let dt = ui.input(|i| i.stable_dt).at_most(0.1);
+
+    let delta = if pointer.y < clip_rect.top() + edge {
+        points_per_second * dt
+    } else if clip_rect.bottom() - edge < pointer.y {
+        -points_per_second * dt
+    } else {
+        return;
+    };
+    ui.scroll_with_delta(Vec2::new(0.0, delta));
+    ui.ctx().request_repaint();
+}
@@ -78,6 +78,13 @@ pub(crate) struct GridLayout {
     spacing: Vec2,
     min_cell_size: Vec2,
     max_cell_size: Vec2,
+
+    /// Per-column overrides of `min_cell_size.x`.
+    min_col_widths: Vec<f32>,
+
+    /// Per-column overrides of `max_cell_size.x`.
+    max_col_widths: Vec<f32>,
+
     color_picker: Option<ColorPickerFn>,
 
     // Cursor:
@@ -113,6 +120,8 @@ impl GridLayout {
             spacing: ui.spacing().item_spacing,
             min_cell_size: ui.spacing().interact_size,
             max_cell_size: Vec2::INFINITY,
+            min_col_widths: Vec::new(),
+            max_col_widths: Vec::new(),
             color_picker: None,
 
             col: 0,
@@ -122,10 +131,24 @@ impl GridLayout {
 }
 
 impl GridLayout {
+    fn min_col_width(&self, col: usize) -> f32 {
+        self.min_col_widths
+            .get(col)
+            .copied()
+            .unwrap_or(self.min_cell_size.x)
+    }
+
+    fn max_col_width(&self, col: usize) -> f32 {
+        self.max_col_widths
+            .get(col)
+            .copied()
+            .unwrap_or(self.max_cell_size.x)
+    }
+
     fn prev_col_width(&self, col: usize) -> f32 {
         self.prev_state
             .col_width(col)
-            .unwrap_or(self.min_cell_size.x)
+            .unwrap_or_else(|| self.min_col_width(col))
     }
 
     fn prev_row_height(&self, row: usize) -> f32 {
@@ -135,33 +158,32 @@ impl GridLayout {
     }
 
     pub(crate) fn wrap_text(&self) -> bool {
-        self.max_cell_size.x.is_finite()
+        self.max_col_width(self.col).is_finite()
     }
 
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
         let is_last_column = Some(self.col + 1) == self.num_columns;
+        let min_col_width = self.min_col_width(self.col);
+        let max_col_width = self.max_col_width(self.col);
 
         let width = if is_last_column {
             // The first frame we don't really know the widths of the previous columns,
             // so returning a big available width here can cause trouble.
             if self.is_first_frame {
-                self.curr_state
-                    .col_width(self.col)
-                    .unwrap_or(self.min_cell_size.x)
+                self.curr_state.col_width(self.col).unwrap_or(min_col_width)
             } else {
-                (self.initial_available.right() - region.cursor.left())
-                    .at_most(self.max_cell_size.x)
+                (self.initial_available.right() - region.cursor.left()).at_most(max_col_width)
             }
-        } else if self.max_cell_size.x.is_finite() {
+        } else if max_col_width.is_finite() {
             // TODO(emilk): should probably heed `prev_state` here too
-            self.max_cell_size.x
+            max_col_width
         } else {
             // If we want to allow width-filling widgets like [`Separator`] in one of the first cells
             // then we need to make sure they don't spill out of the first cell:
             self.prev_state
                 .col_width(self.col)
                 .or_else(|| self.curr_state.col_width(self.col))
-                .unwrap_or(self.min_cell_size.x)
+                .unwrap_or(min_col_width)
         };
 
         // If something above was wider, we can be wider:
@@ -227,8 +249,10 @@ impl GridLayout {
             }
         }
 
-        self.curr_state
-            .set_min_col_width(self.col, widget_rect.width().max(self.min_cell_size.x));
+        self.curr_state.set_min_col_width(
+            self.col,
+            widget_rect.width().max(self.min_col_width(self.col)),
+        );
         self.curr_state
             .set_min_row_height(self.row, widget_rect.height().max(self.min_cell_size.y));
 
@@ -315,6 +339,8 @@ pub struct Grid {
     min_col_width: Option<f32>,
     min_row_height: Option<f32>,
     max_cell_size: Vec2,
+    min_col_widths: Vec<f32>,
+    max_col_widths: Vec<f32>,
     spacing: Option<Vec2>,
     start_row: usize,
     color_picker: Option<ColorPickerFn>,
@@ -329,6 +355,8 @@ impl Grid {
             min_col_width: None,
             min_row_height: None,
             max_cell_size: Vec2::INFINITY,
+            min_col_widths: Vec::new(),
+            max_col_widths: Vec::new(),
             spacing: None,
             start_row: 0,
             color_picker: None,
@@ -390,6 +418,24 @@ impl Grid {
         self
     }
 
+    /// Set the minimum width of individual columns, by column index.
+    ///
+    /// Columns not covered by this use [`Self::min_col_width`].
+    #[inline]
+    pub fn min_col_widths(mut self, min_col_widths: Vec<f32>) -> Self {
+        self.min_col_widths = min_col_widths;
+        self
+    }
+
+    /// Set the soft maximum width (wrapping width) of individual columns, by column index.
+    ///
+    /// Columns not covered by this use [`Self::max_col_width`].
+    #[inline]
+    pub fn max_col_widths(mut self, max_col_widths: Vec<f32>) -> Self {
+        self.max_col_widths = max_col_widths;
+        self
+    }
+
     /// Set spacing between columns/rows.
     /// Default: [`crate::style::Spacing::item_spacing`].
     #[inline]
@@ -423,6 +469,8 @@ impl Grid {
             min_col_width,
             min_row_height,
             max_cell_size,
+            min_col_widths,
+            max_col_widths,
             spacing,
             start_row,
             mut color_picker,
@@ -464,6 +512,8 @@ impl Grid {
                     color_picker,
                     min_cell_size: vec2(min_col_width, min_row_height),
                     max_cell_size,
+                    min_col_widths,
+                    max_col_widths,
                     spacing,
                     row: start_row,
                     ..GridLayout::new(ui, id, prev_state)
@@ -392,6 +392,13 @@ fn hit_test_on_close(close: &[WidgetRect], pos: Pos2) -> WidgetHits {
                         drag: Some(hit_drag),
                         ..Default::default()
                     }
+                } else if hit_drag.sense.passes_through_clicks() {
+                    // The top thing senses only drags, and explicitly lets clicks through.
+                    WidgetHits {
+                        click: Some(hit_click),
+                        drag: Some(hit_drag),
+                        ..Default::default()
+                    }
                 } else {
                     // The top things senses only drags,
                     // so we ignore the click-widget, because it would be confusing
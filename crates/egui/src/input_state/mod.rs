@@ -586,6 +586,10 @@ impl InputState {
     ///
     /// If your application supports non-proportional zooming,
     /// then you probably want to use [`Self::zoom_delta_2d`] instead.
+    ///
+    /// For a proportional zoom, this is equal to both components of [`Self::zoom_delta_2d`].
+    /// For a horizontal or vertical pinch this is the overall zoom of the gesture,
+    /// averaged over both axes.
     #[inline(always)]
     pub fn zoom_delta(&self) -> f32 {
         // If a multi touch gesture is detected, it measures the exact and linear proportions of
@@ -609,6 +613,17 @@ impl InputState {
     /// * `zoom = 1`: no change
     /// * `zoom < 1`: pinch together
     /// * `zoom > 1`: pinch spread
+    ///
+    /// Whether you get non-proportional zoom depends on what the platform reports:
+    ///
+    /// | Source                                         | Result |
+    /// |------------------------------------------------|--------|
+    /// | Touch screens (`Event::Touch`, e.g. iOS, Android, web, touch displays) | Horizontal/vertical pinch detected |
+    /// | Trackpad pinch (`Event::Zoom`, e.g. macOS via winit, Safari gesture events) | Always uniform |
+    /// | Browser trackpad pinch (reported as ctrl-scroll) | Always uniform |
+    /// | Ctrl-scroll with a mouse wheel                 | Uniform, unless a horizontal or vertical scroll modifier is held (see [`InputOptions`]) |
+    ///
+    /// Where only uniform zoom is available, both components equal [`Self::zoom_delta`].
     #[inline(always)]
     pub fn zoom_delta_2d(&self) -> Vec2 {
         // If a multi touch gesture is detected, it measures the exact and linear proportions of
@@ -1058,6 +1073,10 @@ pub struct PointerState {
     /// Used to check for triple-clicks.
     last_last_click_time: f64,
 
+    /// The [`Click::count`] of the last click.
+    /// Used so that a fourth fast click starts over at a single click.
+    last_click_count: u32,
+
     /// When was the pointer last moved?
     /// Used for things like showing hover ui/tooltip with a delay.
     last_move_time: f64,
@@ -1089,6 +1108,7 @@ impl Default for PointerState {
             started_decidedly_dragging: false,
             last_click_time: f64::NEG_INFINITY,
             last_last_click_time: f64::NEG_INFINITY,
+            last_click_count: 0,
             last_move_time: f64::NEG_INFINITY,
             pointer_events: vec![],
             options: Default::default(),
@@ -1166,9 +1186,10 @@ impl PointerState {
                                 (time - self.last_click_time) < self.options.max_double_click_delay;
                             let triple_click = (time - self.last_last_click_time)
                                 < (self.options.max_double_click_delay * 2.0);
-                            let count = if triple_click {
+                            // After a triple-click we start over, so four fast clicks count as 1, 2, 3, 1:
+                            let count = if triple_click && self.last_click_count == 2 {
                                 3
-                            } else if double_click {
+                            } else if double_click && self.last_click_count == 1 {
                                 2
                             } else {
                                 1
@@ -1176,6 +1197,7 @@ impl PointerState {
 
                             self.last_last_click_time = self.last_click_time;
                             self.last_click_time = time;
+                            self.last_click_count = count;
 
                             Some(Click {
                                 pos,
@@ -1263,10 +1285,19 @@ impl PointerState {
         self.motion
     }
 
-    /// Current velocity of pointer.
+    /// Current velocity of pointer, in points per second.
+    ///
+    /// This is smoothed over the movement of the last 0.1 seconds,
+    /// but can be ZERO when frame-rate is bad
+    /// (we need at least three samples spanning more than 10 ms).
     ///
-    /// This is smoothed over a few frames,
-    /// but can be ZERO when frame-rate is bad.
+    /// The velocity is tracked whether or not a button is down,
+    /// but the history is reset when a button is pressed.
+    /// It drops to zero once the pointer has been still for 0.1 seconds.
+    ///
+    /// When the pointer leaves the window (or a finger is lifted from a touch screen),
+    /// the velocity is still reported for the frame it happened in, so you can use it for
+    /// e.g. inertial panning, but it is zero from the next frame.
     #[inline(always)]
     pub fn velocity(&self) -> Vec2 {
         self.velocity
@@ -1443,6 +1474,28 @@ impl PointerState {
         })
     }
 
+    /// How many clicks in a row was the click with the given button this frame?
+    ///
+    /// 1 for a single click, 2 for a double-click and 3 for a triple-click.
+    /// After a triple-click the count starts over at 1.
+    /// The clicks must be within [`InputOptions::max_double_click_delay`] of each other
+    /// (or twice that for a triple-click).
+    ///
+    /// Returns 0 if the button wasn't clicked this frame.
+    pub fn button_click_count(&self, button: PointerButton) -> u32 {
+        self.pointer_events
+            .iter()
+            .filter_map(|event| match event {
+                PointerEvent::Released {
+                    click: Some(click),
+                    button: b,
+                } if *b == button => Some(click.count),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
     /// Was the primary button clicked this frame?
     pub fn primary_clicked(&self) -> bool {
         self.button_clicked(PointerButton::Primary)
@@ -1654,6 +1707,7 @@ impl PointerState {
             started_decidedly_dragging,
             last_click_time,
             last_last_click_time,
+            last_click_count,
             pointer_events,
             last_move_time,
             options: _,
@@ -1679,7 +1733,157 @@ impl PointerState {
         ));
         ui.label(format!("last_click_time: {last_click_time:#?}"));
         ui.label(format!("last_last_click_time: {last_last_click_time:#?}"));
+        ui.label(format!("last_click_count: {last_click_count}"));
         ui.label(format!("last_move_time: {last_move_time:#?}"));
         ui.label(format!("pointer_events: {pointer_events:?}"));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Click at each of the given times, and return the click count of each click.
+    fn click_counts(times: &[f64]) -> Vec<u32> {
+        let pos = Pos2::new(10.0, 10.0);
+        let mut input = InputState::default();
+        let mut counts = vec![];
+        for &time in times {
+            for pressed in [true, false] {
+                let raw_input = RawInput {
+                    time: Some(time),
+                    events: vec![Event::PointerButton {
+                        pos,
+                        button: PointerButton::Primary,
+                        pressed,
+                        modifiers: Modifiers::NONE,
+                    }],
+                    ..Default::default()
+                };
+                input = input.begin_pass(raw_input, false, 1.0, InputOptions::default());
+            }
+            counts.push(input.pointer.button_click_count(PointerButton::Primary));
+        }
+        counts
+    }
+
+    #[test]
+    fn test_click_count() {
+        // Default `max_double_click_delay` is 0.3 seconds.
+        assert_eq!(click_counts(&[1.0, 2.0, 3.0]), vec![1, 1, 1]);
+        assert_eq!(click_counts(&[1.0, 1.1]), vec![1, 2]);
+        assert_eq!(click_counts(&[1.0, 1.1, 1.2]), vec![1, 2, 3]);
+
+        // Four fast clicks start over rather than staying at a triple-click:
+        assert_eq!(click_counts(&[1.0, 1.1, 1.2, 1.3]), vec![1, 2, 3, 1]);
+        assert_eq!(
+            click_counts(&[1.0, 1.1, 1.2, 1.3, 1.4, 1.5]),
+            vec![1, 2, 3, 1, 2, 3]
+        );
+
+        // Too slow for a double-click, then a fast double-click:
+        assert_eq!(click_counts(&[1.0, 1.4, 1.5]), vec![1, 1, 2]);
+    }
+
+    #[test]
+    fn test_multi_touch_rotation() {
+        use crate::{TouchId, TouchPhase};
+
+        let device_id = TouchDeviceId(0);
+        let touch = |id, phase, pos| Event::Touch {
+            device_id,
+            id: TouchId(id),
+            phase,
+            pos,
+            force: None,
+        };
+        let mut input = InputState::default();
+        let mut step = |events| {
+            let raw_input = RawInput {
+                events,
+                ..Default::default()
+            };
+            input = std::mem::take(&mut input).begin_pass(
+                raw_input,
+                false,
+                1.0,
+                InputOptions::default(),
+            );
+            input.multi_touch()
+        };
+
+        // A gesture starts at the pointer position, so we need one:
+        assert_eq!(
+            step(vec![
+                Event::PointerMoved(Pos2::ZERO),
+                touch(0, TouchPhase::Start, Pos2::new(-10.0, 0.0)),
+                touch(1, TouchPhase::Start, Pos2::new(10.0, 0.0)),
+            ]),
+            None
+        );
+
+        let info = step(vec![]).expect("two touches should be a multi-touch gesture");
+        assert_eq!(info.num_touches, 2);
+        assert_eq!(info.rotation_delta, 0.0);
+
+        // Rotate both fingers a quarter turn around their center:
+        let info = step(vec![
+            touch(0, TouchPhase::Move, Pos2::new(0.0, -10.0)),
+            touch(1, TouchPhase::Move, Pos2::new(0.0, 10.0)),
+        ])
+        .unwrap();
+        assert_eq!(info.num_touches, 2);
+        assert!((info.rotation_delta - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+        assert_eq!(info.zoom_delta, 1.0);
+
+        // Lifting a finger ends the gesture:
+        assert_eq!(
+            step(vec![touch(1, TouchPhase::End, Pos2::new(0.0, 10.0))]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_pointer_velocity() {
+        let mut input = InputState::default();
+        let mut step = |time: f64, events| {
+            let raw_input = RawInput {
+                time: Some(time),
+                events,
+                ..Default::default()
+            };
+            input = std::mem::take(&mut input).begin_pass(
+                raw_input,
+                false,
+                1.0,
+                InputOptions::default(),
+            );
+            input.pointer.velocity()
+        };
+
+        // Move at 1000 points per second, without any button down:
+        let mut velocity = Vec2::ZERO;
+        for i in 0..5 {
+            let pos = Pos2::new(10.0 * i as f32, 0.0);
+            velocity = step(0.01 * i as f64, vec![Event::PointerMoved(pos)]);
+        }
+        assert!(
+            (velocity - vec2(1000.0, 0.0)).length() < 1.0,
+            "{velocity:?}"
+        );
+
+        // Stand still for longer than the smoothing window:
+        let mut velocity = Vec2::ZERO;
+        for i in 5..20 {
+            velocity = step(0.01 * i as f64, vec![]);
+        }
+        assert_eq!(velocity, Vec2::ZERO);
+
+        // Move, then leave the window:
+        step(0.21, vec![Event::PointerMoved(Pos2::new(50.0, 0.0))]);
+        step(0.22, vec![Event::PointerMoved(Pos2::new(60.0, 0.0))]);
+        let velocity = step(0.23, vec![Event::PointerGone]);
+        assert!(velocity.x > 0.0, "The final velocity is kept: {velocity:?}");
+        assert_eq!(step(0.24, vec![]), Vec2::ZERO);
+    }
+}
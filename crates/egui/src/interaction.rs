@@ -192,8 +192,11 @@ pub(crate) fn interact(
         // Check if we started dragging something new:
         if let Some(widget) = interaction.potential_drag_id.and_then(|id| widgets.get(id)) {
             if widget.enabled {
-                let is_dragged = if widget.sense.senses_click() && widget.sense.senses_drag() {
-                    // This widget is sensitive to both clicks and drags.
+                let is_dragged = if widget.sense.senses_click() && widget.sense.senses_drag()
+                    || widget.sense.passes_through_clicks()
+                {
+                    // This widget is sensitive to both clicks and drags
+                    // (or lets clicks through to what is below it).
                     // When the mouse first is pressed, it could be either,
                     // so we postpone the decision until we know.
                     input.pointer.is_decidedly_dragging()
@@ -151,6 +151,7 @@ impl Widget for &mut epaint::TessellationOptions {
                 debug_ignore_clip_rects,
                 bezier_tolerance,
                 epsilon: _,
+                miter_limit,
                 parallel_tessellation,
                 validate_meshes,
             } = self;
@@ -175,6 +176,12 @@ impl Widget for &mut epaint::TessellationOptions {
                 );
             });
 
+            ui.horizontal(|ui| {
+                ui.label("Miter limit");
+                ui.add(crate::DragValue::new(miter_limit).range(1.0..=100.0).speed(0.1))
+                    .on_hover_text("Sharper corners than this are beveled, for paths with miter joins.");
+            });
+
             ui.add_enabled(epaint::HAS_RAYON, crate::Checkbox::new(parallel_tessellation, "Parallelize tessellation")
                 ).on_hover_text("Only available if epaint was compiled with the rayon feature")
                 .on_disabled_hover_text("epaint was not compiled with the rayon feature");
@@ -189,6 +189,13 @@ impl PaintList {
         }
     }
 
+    /// Multiply the opacity of each [`Shape`] in range by this much, in-place
+    pub fn multiply_opacity_range(&mut self, start: ShapeIdx, end: ShapeIdx, opacity: f32) {
+        for ClippedShape { shape, .. } in &mut self.0[start.0..end.0] {
+            crate::painter::multiply_opacity(shape, opacity);
+        }
+    }
+
     /// Read-only access to all held shapes.
     pub fn all_entries(&self) -> impl ExactSizeIterator<Item = &ClippedShape> {
         self.0.iter()
@@ -217,6 +224,30 @@ impl GraphicLayers {
         self.0[layer_id.order as usize].get_mut(&layer_id.id)
     }
 
+    /// All the [`PaintList`]s, back-to-front.
+    ///
+    /// This is the same order that [`Self::drain`] uses.
+    pub fn ordered(&self, area_order: &[LayerId]) -> Vec<&PaintList> {
+        let mut lists = vec![];
+        for &order in &Order::ALL {
+            let order_map = &self.0[order as usize];
+            for layer_id in area_order {
+                if layer_id.order == order {
+                    lists.extend(order_map.get(&layer_id.id));
+                }
+            }
+
+            // Layers that are missing in `area_order`:
+            #[expect(clippy::iter_over_hash_type)]
+            for (id, list) in order_map {
+                if !area_order.contains(&LayerId::new(order, *id)) {
+                    lists.push(list);
+                }
+            }
+        }
+        lists
+    }
+
     pub fn drain(
         &mut self,
         area_order: &[LayerId],
@@ -412,6 +412,7 @@ pub mod containers;
 mod context;
 mod data;
 pub mod debug_text;
+pub mod dnd;
 mod drag_and_drop;
 pub(crate) mod grid;
 pub mod gui_zoom;
@@ -488,8 +489,8 @@ pub use self::{
         Key, UserData,
         input::*,
         output::{
-            self, CursorIcon, FullOutput, OpenUrl, OutputCommand, PlatformOutput,
-            UserAttentionType, WidgetInfo,
+            self, CursorIcon, CursorIconPriority, FullOutput, OpenUrl, OutputCommand,
+            PlatformOutput, UserAttentionType, WidgetInfo,
         },
     },
     drag_and_drop::DragAndDrop,
@@ -655,6 +656,9 @@ pub enum WidgetType {
 
     Checkbox,
 
+    /// An on/off switch, e.g. [`crate::ToggleSwitch`].
+    ToggleSwitch,
+
     RadioButton,
 
     /// A group of radio buttons.
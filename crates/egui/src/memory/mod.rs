@@ -851,6 +851,14 @@ impl Memory {
         self.focus()?.focused()
     }
 
+    /// The event filter of the widget with keyboard focus, see [`Self::set_focus_lock_filter`].
+    pub(crate) fn focus_lock_filter(&self) -> EventFilter {
+        self.focus()
+            .and_then(|focus| focus.focused_widget)
+            .map(|w| w.filter)
+            .unwrap_or_default()
+    }
+
     /// Set an event filter for a widget.
     ///
     /// This allows you to control whether the widget will loose focus
@@ -2,13 +2,14 @@ use std::sync::Arc;
 
 use emath::GuiRounding as _;
 use epaint::{
-    CircleShape, ClippedShape, CornerRadius, PathStroke, RectShape, Shape, Stroke, StrokeKind,
+    CircleShape, ClippedShape, CornerRadius, CubicBezierShape, PathStroke, QuadraticBezierShape,
+    RectShape, Shape, Stroke, StrokeKind,
     text::{FontsView, Galley, LayoutJob},
 };
 
 use crate::{
     Color32, Context, FontId,
-    emath::{Align2, Pos2, Rangef, Rect, Vec2},
+    emath::{Align2, Pos2, Rangef, Rect, TSTransform, Vec2},
     layers::{LayerId, PaintList, ShapeIdx},
 };
 
@@ -261,6 +262,35 @@ impl Painter {
         }
     }
 
+    /// Transform a shape with the given [`TSTransform`], then add it.
+    ///
+    /// The transform is baked into the geometry of the shape using [`Shape::transform`],
+    /// so it acts on screen coordinates, e.g. scaling around the top left corner of the screen.
+    ///
+    /// The clip rectangle of the painter is in screen space, and is _not_ transformed.
+    ///
+    /// To transform everything in a layer, including interaction, use [`Context::set_transform_layer`] instead.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{emath::TSTransform, Color32, Shape};
+    ///
+    /// let center = ui.max_rect().center();
+    ///
+    /// // Scale the circle by 2x around its own center:
+    /// let transform = TSTransform::from_translation(center.to_vec2())
+    ///     * TSTransform::from_scaling(2.0)
+    ///     * TSTransform::from_translation(-center.to_vec2());
+    /// ui.painter()
+    ///     .add_transformed(Shape::circle_filled(center, 8.0, Color32::RED), transform);
+    /// # });
+    /// ```
+    pub fn add_transformed(&self, shape: impl Into<Shape>, transform: TSTransform) -> ShapeIdx {
+        let mut shape = shape.into();
+        shape.transform(transform);
+        self.add(shape)
+    }
+
     /// Add many shapes at once.
     ///
     /// Calling this once is generally faster than calling [`Self::add`] multiple times.
@@ -369,6 +399,49 @@ impl Painter {
         self.add(Shape::line(points, stroke))
     }
 
+    /// Paints a dashed line connecting the points.
+    ///
+    /// The dash pattern continues across the points, so the dashes are evenly spaced.
+    /// Increase `phase` over time to animate the dashes along the line.
+    ///
+    /// See also [`Self::dashed_line_round_caps`] and [`Shape::dashed_line_with_phase`].
+    pub fn dashed_line(
+        &self,
+        points: &[Pos2],
+        stroke: impl Into<Stroke>,
+        dash_length: f32,
+        gap_length: f32,
+        phase: f32,
+    ) -> ShapeIdx {
+        self.add(Shape::dashed_line_with_phase(
+            points,
+            stroke,
+            dash_length,
+            gap_length,
+            phase,
+            false,
+        ))
+    }
+
+    /// Like [`Self::dashed_line`], but with round caps at the ends of each dash.
+    pub fn dashed_line_round_caps(
+        &self,
+        points: &[Pos2],
+        stroke: impl Into<Stroke>,
+        dash_length: f32,
+        gap_length: f32,
+        phase: f32,
+    ) -> ShapeIdx {
+        self.add(Shape::dashed_line_with_phase(
+            points,
+            stroke,
+            dash_length,
+            gap_length,
+            phase,
+            true,
+        ))
+    }
+
     /// Paints a horizontal line.
     pub fn hline(&self, x: impl Into<Rangef>, y: f32, stroke: impl Into<Stroke>) -> ShapeIdx {
         self.add(Shape::hline(x, y, stroke))
@@ -379,6 +452,55 @@ impl Painter {
         self.add(Shape::vline(x, y, stroke))
     }
 
+    /// Paints a cubic Bézier curve, e.g. for the wires in a node editor.
+    ///
+    /// `points` are the start point, the two control points, and the end point.
+    ///
+    /// If `fill` is not transparent, the curve is closed with a straight line
+    /// from the end point back to the start point, and the enclosed area is filled.
+    ///
+    /// `tolerance` is the max distance between the curve and the line segments used to draw it.
+    /// If `None`, [`epaint::TessellationOptions::bezier_tolerance`] is used.
+    pub fn cubic_bezier(
+        &self,
+        points: [Pos2; 4],
+        stroke: impl Into<PathStroke>,
+        fill: impl Into<Color32>,
+        tolerance: Option<f32>,
+    ) -> ShapeIdx {
+        let fill = fill.into();
+        let closed = fill != Color32::TRANSPARENT;
+        let bezier = CubicBezierShape::from_points_stroke(points, closed, fill, stroke);
+        if tolerance.is_some() {
+            let paths = bezier.to_path_shapes(tolerance, None);
+            self.add(Shape::Vec(paths.into_iter().map(Shape::Path).collect()))
+        } else {
+            self.add(bezier)
+        }
+    }
+
+    /// Paints a quadratic Bézier curve.
+    ///
+    /// `points` are the start point, the control point, and the end point.
+    ///
+    /// See [`Self::cubic_bezier`] for the meaning of `fill` and `tolerance`.
+    pub fn quadratic_bezier(
+        &self,
+        points: [Pos2; 3],
+        stroke: impl Into<PathStroke>,
+        fill: impl Into<Color32>,
+        tolerance: Option<f32>,
+    ) -> ShapeIdx {
+        let fill = fill.into();
+        let closed = fill != Color32::TRANSPARENT;
+        let bezier = QuadraticBezierShape::from_points_stroke(points, closed, fill, stroke);
+        if tolerance.is_some() {
+            self.add(bezier.to_path_shape(tolerance))
+        } else {
+            self.add(bezier)
+        }
+    }
+
     pub fn circle(
         &self,
         center: Pos2,
@@ -601,7 +723,7 @@ fn tint_shape_towards(shape: &mut Shape, target: Color32) {
     });
 }
 
-fn multiply_opacity(shape: &mut Shape, opacity: f32) {
+pub(crate) fn multiply_opacity(shape: &mut Shape, opacity: f32) {
     epaint::shape_transform::adjust_colors(shape, move |color| {
         if *color != Color32::PLACEHOLDER {
             *color = color.gamma_multiply(opacity);
@@ -1,6 +1,9 @@
 use ahash::HashMap;
 
-use crate::{Align, Id, IdMap, LayerId, Rangef, Rect, Vec2, WidgetRects, id::IdSet, style};
+use crate::{
+    Align, CursorIconPriority, Id, IdMap, LayerId, Rangef, Rect, Vec2, WidgetRects, id::IdSet,
+    style,
+};
 
 #[cfg(debug_assertions)]
 use crate::{Align2, Color32, FontId, NumExt as _, Painter, pos2};
@@ -225,6 +228,11 @@ pub struct PassState {
     /// as when swiping down on a touch-screen or track-pad with natural scrolling.
     pub scroll_delta: (Vec2, style::ScrollAnimation),
 
+    /// The priority of the cursor icon requested so far this pass.
+    ///
+    /// See [`crate::Context::set_cursor_icon_with_priority`].
+    pub cursor_icon_priority: CursorIconPriority,
+
     #[cfg(feature = "accesskit")]
     pub accesskit_state: Option<AccessKitPassState>,
 
@@ -247,6 +255,7 @@ impl Default for PassState {
             used_by_panels: Rect::NAN,
             scroll_target: [None, None],
             scroll_delta: (Vec2::default(), style::ScrollAnimation::none()),
+            cursor_icon_priority: CursorIconPriority::NONE,
             #[cfg(feature = "accesskit")]
             accesskit_state: None,
             highlight_next_pass: Default::default(),
@@ -270,6 +279,7 @@ impl PassState {
             used_by_panels,
             scroll_target,
             scroll_delta,
+            cursor_icon_priority,
             #[cfg(feature = "accesskit")]
             accesskit_state,
             highlight_next_pass,
@@ -287,6 +297,7 @@ impl PassState {
         *used_by_panels = Rect::NOTHING;
         *scroll_target = [None, None];
         *scroll_delta = Default::default();
+        *cursor_icon_priority = CursorIconPriority::NONE;
 
         #[cfg(debug_assertions)]
         {
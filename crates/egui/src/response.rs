@@ -1,8 +1,8 @@
-use std::{any::Any, sync::Arc};
+use std::{any::Any, sync::Arc, time::Duration};
 
 use crate::{
-    Context, CursorIcon, Id, LayerId, PointerButton, Popup, PopupKind, Sense, Tooltip, Ui,
-    WidgetRect, WidgetText,
+    Context, CursorIcon, CursorIconPriority, Id, LayerId, PointerButton, Popup, PopupKind, Sense,
+    Tooltip, Ui, WidgetRect, WidgetText,
     emath::{Align, Pos2, Rect, Vec2},
     pass_state,
 };
@@ -205,6 +205,25 @@ impl Response {
         self.triple_clicked_by(PointerButton::Primary)
     }
 
+    /// How many clicks in a row was the primary click on this widget this frame?
+    ///
+    /// 1 for a single click, 2 for a double-click and 3 for a triple-click,
+    /// after which it starts over, so four fast clicks report 1, 2, 3, 1.
+    ///
+    /// Returns 0 if the widget wasn't clicked with the primary button this frame
+    /// (including when it was "clicked" via the keyboard or accessibility integrations).
+    ///
+    /// See [`crate::PointerState::button_click_count`].
+    #[inline]
+    pub fn click_count(&self) -> u32 {
+        if self.flags.contains(Flags::CLICKED) {
+            self.ctx
+                .input(|i| i.pointer.button_click_count(PointerButton::Primary))
+        } else {
+            0
+        }
+    }
+
     /// Returns true if this widget was double-clicked this frame by the given button.
     #[inline]
     pub fn double_clicked_by(&self, button: PointerButton) -> bool {
@@ -233,6 +252,20 @@ impl Response {
     /// so that clicking a button in an area will not be considered as clicking "elsewhere" from the area.
     ///
     /// Clicks on other layers above this widget *will* be considered as clicking elsewhere.
+    ///
+    /// Only clicks count: pressing down and then dragging elsewhere will not.
+    /// Clicks with any mouse button count.
+    ///
+    /// This is useful for closing custom popups:
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut is_open = true;
+    /// let response = ui.group(|ui| ui.label("My popup")).response;
+    /// if response.clicked_elsewhere() {
+    ///     is_open = false;
+    /// }
+    /// # });
+    /// ```
     pub fn clicked_elsewhere(&self) -> bool {
         let (pointer_interact_pos, any_click) = self
             .ctx
@@ -434,7 +467,8 @@ impl Response {
         if self.hovered() && !self.sense.senses_click() {
             // Things that can be drag-dropped should use the Grab cursor icon,
             // but if the thing is _also_ clickable, that can be annoying.
-            self.ctx.set_cursor_icon(CursorIcon::Grab);
+            self.ctx
+                .set_cursor_icon_with_priority(CursorIcon::Grab, CursorIconPriority::GRAB);
         }
     }
 
@@ -575,6 +609,35 @@ impl Response {
         self
     }
 
+    /// Like [`Self::on_hover_ui`], but with a custom delay instead of
+    /// [`crate::style::Interaction::tooltip_delay`].
+    ///
+    /// The delay is counted from when the pointer last moved, clicked or scrolled,
+    /// so it restarts when the pointer leaves and re-enters the widget.
+    /// Use [`Duration::ZERO`] to show the tooltip immediately.
+    ///
+    /// As with other tooltips, there is no delay if another tooltip was just shown
+    /// (see [`crate::style::Interaction::tooltip_grace_time`]).
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use std::time::Duration;
+    /// ui.label("Slow")
+    ///     .on_hover_ui_after(Duration::from_millis(800), |ui| {
+    ///         ui.label("This took a while");
+    ///     });
+    /// # });
+    /// ```
+    #[doc(alias = "tooltip")]
+    pub fn on_hover_ui_after(self, delay: Duration, add_contents: impl FnOnce(&mut Ui)) -> Self {
+        let mut tooltip = Tooltip::for_widget(&self);
+        tooltip.popup = tooltip.popup.open(
+            self.enabled() && Tooltip::should_show_tooltip_after(&self, true, delay.as_secs_f32()),
+        );
+        tooltip.show(add_contents);
+        self
+    }
+
     /// Show this UI when hovering if the widget is disabled.
     pub fn on_disabled_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         Tooltip::for_disabled(&self).show(add_contents);
@@ -852,6 +915,7 @@ impl Response {
             }
             WidgetType::Image => Role::Image,
             WidgetType::Checkbox => Role::CheckBox,
+            WidgetType::ToggleSwitch => Role::Switch,
             WidgetType::RadioButton => Role::RadioButton,
             WidgetType::RadioGroup => Role::RadioGroup,
             WidgetType::ComboBox => Role::ComboBox,
@@ -19,6 +19,12 @@ bitflags::bitflags! {
         /// Anything interactive + labels that can be focused
         /// for the benefit of screen readers.
         const FOCUSABLE = 1<<2;
+
+        /// Only used together with [`Self::DRAG`] (and without [`Self::CLICK`]):
+        /// a press that turns out to be a click is passed through to the widget below.
+        ///
+        /// See [`Sense::drag_passthrough_click`].
+        const PASSTHROUGH_CLICK = 1<<3;
     }
 }
 
@@ -34,6 +40,9 @@ impl std::fmt::Debug for Sense {
         if self.is_focusable() {
             write!(f, " focusable")?;
         }
+        if self.passes_through_clicks() {
+            write!(f, " passthrough_click")?;
+        }
         write!(f, " }}")
     }
 }
@@ -65,6 +74,23 @@ impl Sense {
         Self::DRAG | Self::FOCUSABLE
     }
 
+    /// Sense drags and hover, but let clicks pass through to whatever is behind.
+    ///
+    /// With [`Self::drag`], pressing on the widget blocks any widget behind it from being clicked.
+    /// With this, a press only becomes a drag once the pointer has moved more than
+    /// [`crate::InputOptions::max_click_dist`] (6 points by default)
+    /// or has been held down for longer than [`crate::InputOptions::max_click_duration`].
+    /// If it is released before that, it is a click on the clickable widget behind this one (if any),
+    /// and this widget reports no interaction at all.
+    ///
+    /// Like [`Self::click_and_drag`], this introduces a latency when starting a drag.
+    ///
+    /// See [`crate::PointerState::is_decidedly_dragging`] for details.
+    #[inline]
+    pub fn drag_passthrough_click() -> Self {
+        Self::drag() | Self::PASSTHROUGH_CLICK
+    }
+
     /// Sense both clicks, drags and hover (e.g. a slider or window).
     ///
     /// Note that this will introduce a latency when dragging,
@@ -98,4 +124,10 @@ impl Sense {
     pub fn is_focusable(&self) -> bool {
         self.contains(Self::FOCUSABLE)
     }
+
+    /// See [`Self::drag_passthrough_click`].
+    #[inline]
+    pub fn passes_through_clicks(&self) -> bool {
+        self.contains(Self::DRAG | Self::PASSTHROUGH_CLICK) && !self.senses_click()
+    }
 }
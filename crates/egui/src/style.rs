@@ -323,6 +323,12 @@ pub struct Style {
     /// Colors etc.
     pub visuals: Visuals,
 
+    /// Named sets of widget visuals, e.g. `"danger"` or `"primary"`.
+    ///
+    /// Widgets opt into one of these by name, e.g. with [`crate::Button::class`].
+    /// They are usually registered with [`crate::Context::register_style_class`].
+    pub classes: BTreeMap<String, Widgets>,
+
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
@@ -366,7 +372,18 @@ impl Style {
     }
 
     pub fn interact_selectable(&self, response: &Response, selected: bool) -> WidgetVisuals {
-        let mut visuals = *self.visuals.widgets.style(response);
+        self.interact_selectable_with(&self.visuals.widgets, response, selected)
+    }
+
+    /// Like [`Self::interact_selectable`], but picks the visuals from `widgets`
+    /// instead of [`Visuals::widgets`].
+    pub fn interact_selectable_with(
+        &self,
+        widgets: &Widgets,
+        response: &Response,
+        selected: bool,
+    ) -> WidgetVisuals {
+        let mut visuals = *widgets.style(response);
         if selected {
             visuals.weak_bg_fill = self.visuals.selection.bg_fill;
             visuals.bg_fill = self.visuals.selection.bg_fill;
@@ -381,6 +398,36 @@ impl Style {
         &self.visuals.widgets.noninteractive
     }
 
+    /// The [`Widgets`] of the given style class (see [`Self::classes`]).
+    ///
+    /// Falls back to [`Visuals::widgets`] for unknown classes,
+    /// logging a warning the first time each unknown class is used.
+    pub fn class_widgets(&self, ctx: &crate::Context, class: &str) -> &Widgets {
+        if let Some(widgets) = self.classes.get(class) {
+            widgets
+        } else {
+            // Only remember the unknown classes if we are going to warn about them:
+            #[cfg(feature = "log")]
+            {
+                let is_new = ctx.data_mut(|data| {
+                    data.get_temp_mut_or_default::<ahash::HashSet<String>>(crate::Id::new(
+                        "unknown_style_classes",
+                    ))
+                    .insert(class.to_owned())
+                });
+                if is_new {
+                    log::warn!("Unknown style class {class:?}, using the default widget visuals");
+                }
+            }
+            #[cfg(not(feature = "log"))]
+            {
+                let _ = ctx;
+            }
+
+            &self.visuals.widgets
+        }
+    }
+
     /// All known text styles.
     pub fn text_styles(&self) -> Vec<TextStyle> {
         self.text_styles.keys().cloned().collect()
@@ -1228,6 +1275,48 @@ impl WidgetVisuals {
     pub fn rounding(&self) -> CornerRadius {
         self.corner_radius
     }
+
+    /// Interpolate towards `other`, so that `0.0 => self, 1.0 => other`.
+    ///
+    /// Colors are interpolated in linear space, to avoid muddy midpoints.
+    ///
+    /// This is useful for animating the transition between e.g. [`Widgets::inactive`] and [`Widgets::hovered`],
+    /// using [`crate::Context::animate_value_with_time`] or similar to drive `t`.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        fn lerp_stroke(a: Stroke, b: Stroke, t: f32) -> Stroke {
+            Stroke::new(
+                emath::lerp(a.width..=b.width, t),
+                a.color.lerp_linear(b.color, t),
+            )
+        }
+
+        fn lerp_u8(a: u8, b: u8, t: f32) -> u8 {
+            emath::lerp(a as f32..=b as f32, t).round() as u8
+        }
+
+        let Self {
+            bg_fill,
+            weak_bg_fill,
+            bg_stroke,
+            corner_radius,
+            fg_stroke,
+            expansion,
+        } = *self;
+
+        Self {
+            bg_fill: bg_fill.lerp_linear(other.bg_fill, t),
+            weak_bg_fill: weak_bg_fill.lerp_linear(other.weak_bg_fill, t),
+            bg_stroke: lerp_stroke(bg_stroke, other.bg_stroke, t),
+            corner_radius: CornerRadius {
+                nw: lerp_u8(corner_radius.nw, other.corner_radius.nw, t),
+                ne: lerp_u8(corner_radius.ne, other.corner_radius.ne, t),
+                sw: lerp_u8(corner_radius.sw, other.corner_radius.sw, t),
+                se: lerp_u8(corner_radius.se, other.corner_radius.se, t),
+            },
+            fg_stroke: lerp_stroke(fg_stroke, other.fg_stroke, t),
+            expansion: emath::lerp(expansion..=other.expansion, t),
+        }
+    }
 }
 
 /// Options for help debug egui by adding extra visualization
@@ -1330,6 +1419,7 @@ impl Default for Style {
             spacing: Spacing::default(),
             interaction: Interaction::default(),
             visuals: Visuals::default(),
+            classes: Default::default(),
             animation_time: 6.0 / 60.0, // If we make this too slow, it will be too obvious that our panel animations look like shit :(
             #[cfg(debug_assertions)]
             debug: Default::default(),
@@ -1638,6 +1728,7 @@ impl Style {
             spacing,
             interaction,
             visuals,
+            classes: _, // can't be edited in the UI
             animation_time,
             #[cfg(debug_assertions)]
             debug,
@@ -3,8 +3,8 @@ use std::sync::Arc;
 use emath::TSTransform;
 
 use crate::{
-    Context, CursorIcon, Event, Galley, Id, LayerId, Plugin, Pos2, Rect, Response, Ui,
-    layers::ShapeIdx, text::CCursor, text_selection::CCursorRange,
+    Context, CursorIcon, CursorIconPriority, Event, Galley, Id, LayerId, Plugin, Pos2, Rect,
+    Response, Ui, layers::ShapeIdx, text::CCursor, text_selection::CCursorRange,
 };
 
 use super::{
@@ -147,7 +147,7 @@ impl Plugin for LabelSelectionState {
 
     fn on_end_pass(&mut self, ctx: &Context) {
         if self.is_dragging {
-            ctx.set_cursor_icon(CursorIcon::Text);
+            ctx.set_cursor_icon_with_priority(CursorIcon::Text, CursorIconPriority::TEXT);
         }
 
         if !self.has_reached_primary || !self.has_reached_secondary {
@@ -499,7 +499,8 @@ impl LabelSelectionState {
         let global_from_galley = global_from_layer * layer_from_galley;
 
         if response.hovered() {
-            ui.ctx().set_cursor_icon(CursorIcon::Text);
+            ui.ctx()
+                .set_cursor_icon_with_priority(CursorIcon::Text, CursorIconPriority::TEXT);
         }
 
         self.any_hovered |= response.hovered();
@@ -11,10 +11,10 @@ use crate::ClosableTag;
 use crate::Stroke;
 use crate::containers::menu;
 use crate::{
-    Align, Color32, Context, CursorIcon, DragAndDrop, Id, InnerResponse, InputState, IntoAtoms,
-    LayerId, Memory, Order, Painter, PlatformOutput, Pos2, Rangef, Rect, Response, Rgba, RichText,
-    Sense, Style, TextStyle, TextWrapMode, UiBuilder, UiKind, UiStack, UiStackInfo, Vec2,
-    WidgetRect, WidgetText,
+    Align, Color32, Context, CursorIcon, CursorIconPriority, DragAndDrop, Id, InnerResponse,
+    InputState, IntoAtoms, LayerId, Memory, Order, Painter, PlatformOutput, Pos2, Rangef, Rect,
+    Response, Rgba, RichText, Sense, Style, TextStyle, TextWrapMode, UiBuilder, UiKind, UiStack,
+    UiStackInfo, Vec2, WidgetRect, WidgetText,
     containers::{CollapsingHeader, CollapsingResponse, Frame},
     ecolor::Hsva,
     emath, epaint, grid,
@@ -26,7 +26,7 @@ use crate::{
     vec2, widgets,
     widgets::{
         Button, Checkbox, DragValue, Hyperlink, Image, ImageSource, Label, Link, RadioButton,
-        Separator, Spinner, TextEdit, Widget, color_picker,
+        Separator, Spinner, TextEdit, ToggleSwitch, Widget, color_picker,
     },
 };
 // ----------------------------------------------------------------------------
@@ -77,6 +77,9 @@ pub struct Ui {
     /// They are therefore only good for Id:s that has no state.
     next_auto_id_salt: u64,
 
+    /// How many times [`Ui::auto_id`] has been called from each source location.
+    auto_id_counts: crate::IdMap<u64>,
+
     /// Specifies paint layer, clip rectangle and a reference to [`Context`].
     painter: Painter,
 
@@ -162,6 +165,7 @@ impl Ui {
             id,
             unique_id: id,
             next_auto_id_salt: id.with("auto").value(),
+            auto_id_counts: Default::default(),
             painter: Painter::new(ctx, layer_id, clip_rect),
             style,
             placer,
@@ -313,6 +317,7 @@ impl Ui {
             id: stable_id,
             unique_id,
             next_auto_id_salt,
+            auto_id_counts: Default::default(),
             painter,
             style,
             placer,
@@ -650,7 +655,7 @@ impl Ui {
 
     /// Like [`Self::set_opacity`], but multiplies the given value with the current opacity.
     ///
-    /// See also: [`Self::set_opacity`] and [`Self::opacity`].
+    /// See also: [`Self::set_opacity`], [`Self::opacity`] and [`Self::with_multiplied_opacity`].
     pub fn multiply_opacity(&mut self, opacity: f32) {
         self.painter.multiply_opacity(opacity);
     }
@@ -1067,6 +1072,43 @@ impl Ui {
         Id::new(self.next_auto_id_salt).with(id_salt)
     }
 
+    /// Create an [`Id`] based on the source location of the caller.
+    ///
+    /// This is an alternative to wrapping widgets in [`Self::push_id`] just to avoid id clashes.
+    /// Two calls from different places in the code get different ids,
+    /// and repeated calls from the same place (e.g. in a loop) are told apart by counting them.
+    ///
+    /// The id combines [`Self::id`], the file, line and column of the caller,
+    /// and how many times this `Ui` has called `auto_id` from that location before.
+    /// This means the ids are the same each frame, as long as:
+    /// * the `Ui` has the same [`Self::id`],
+    /// * and each call site is reached the same number of times, in the same order.
+    ///
+    /// Unlike [`Self::next_auto_id`], the result does not change when widgets are added or
+    /// removed elsewhere in the `Ui`. However, the id of the n:th call in a loop will change if
+    /// items before it come and go, so if you store state for a list that can be reordered,
+    /// use [`Self::make_persistent_id`] with a key that identifies the item instead.
+    /// The id will also change if the calling code is moved, e.g. when editing the source file,
+    /// so it is mostly useful for state that doesn't need to survive a restart.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let ids: Vec<egui::Id> = (0..3).map(|_| ui.auto_id()).collect();
+    /// assert_ne!(ids[0], ids[1]);
+    /// assert_ne!(ids[1], ids[2]);
+    /// assert!(!ids.contains(&ui.auto_id()));
+    /// # });
+    /// ```
+    #[track_caller]
+    pub fn auto_id(&mut self) -> Id {
+        let location = std::panic::Location::caller();
+        let location_id = Id::new((location.file(), location.line(), location.column()));
+        let count = self.auto_id_counts.entry(location_id).or_default();
+        let id = self.id.with(location_id).with(*count);
+        *count += 1;
+        id
+    }
+
     /// Pretend like `count` widgets have been allocated.
     pub fn skip_ahead_auto_ids(&mut self, count: usize) {
         self.next_auto_id_salt = self.next_auto_id_salt.wrapping_add(count as u64);
@@ -1090,6 +1132,31 @@ impl Ui {
         )
     }
 
+    /// Make the given rectangle a handle for moving the [`crate::Window`] (or [`crate::Area`]) this [`Ui`] is in.
+    ///
+    /// Dragging the returned response moves the window.
+    /// Add the handle before any widgets covering the same rectangle,
+    /// so that those widgets are on top and get their clicks as usual.
+    ///
+    /// This is mostly useful together with [`crate::Window::drag_bounds_from_title`].
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// egui::Window::new("Inspector")
+    ///     .drag_bounds_from_title(false)
+    ///     .show(ctx, |ui| {
+    ///         let size = egui::vec2(ui.available_width(), 16.0);
+    ///         let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+    ///         ui.window_drag_handle(rect).on_hover_cursor(egui::CursorIcon::Grab);
+    ///         ui.label("Drag the strip above to move this window");
+    ///     });
+    /// # });
+    /// ```
+    pub fn window_drag_handle(&self, rect: Rect) -> Response {
+        let id = crate::Area::drag_handle_id(self.layer_id().id);
+        self.interact(rect, id, Sense::drag())
+    }
+
     /// Deprecated: use [`Self::interact`] instead.
     #[deprecated = "The contains_pointer argument is ignored. Use `ui.interact` instead."]
     pub fn interact_with_hovered(
@@ -1824,6 +1891,14 @@ impl Ui {
     /// });
     /// # });
     /// ```
+    ///
+    /// Like [`Self::scope`], this returns whatever the closure returns in [`InnerResponse::inner`]:
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.add_enabled_ui(false, |ui| ui.button("Disabled")).inner;
+    /// assert!(!response.enabled());
+    /// # });
+    /// ```
     pub fn add_enabled_ui<R>(
         &mut self,
         enabled: bool,
@@ -2106,6 +2181,14 @@ impl Ui {
         Checkbox::new(checked, atoms).ui(self)
     }
 
+    /// Show an iOS-style [`ToggleSwitch`] with a label, for turning something on or off.
+    ///
+    /// See also [`Self::checkbox`].
+    #[inline]
+    pub fn toggle_switch<'a>(&mut self, on: &'a mut bool, atoms: impl IntoAtoms<'a>) -> Response {
+        ToggleSwitch::new(on, atoms).ui(self)
+    }
+
     /// Acts like a checkbox, but looks like a [`Button::selectable`].
     ///
     /// Click to toggle to bool.
@@ -2131,6 +2214,9 @@ impl Ui {
     /// Show a [`RadioButton`]. It is selected if `*current_value == selected_value`.
     /// If clicked, `selected_value` is assigned to `*current_value`.
     ///
+    /// [`Response::changed`] is only `true` if `*current_value` was actually changed,
+    /// i.e. not when clicking the already selected option.
+    ///
     /// ```
     /// # egui::__run_test_ui(|ui| {
     ///
@@ -2172,6 +2258,9 @@ impl Ui {
     /// Show selectable text. It is selected if `*current_value == selected_value`.
     /// If clicked, `selected_value` is assigned to `*current_value`.
     ///
+    /// [`Response::changed`] is only `true` if `*current_value` was actually changed,
+    /// i.e. not when clicking the already selected option.
+    ///
     /// Example: `ui.selectable_value(&mut my_enum, Enum::Alternative, "Alternative")`.
     ///
     /// See also [`Button::selectable`] and [`Self::toggle_value`].
@@ -2448,6 +2537,35 @@ impl Ui {
         self.scope_dyn(ui_builder, Box::new(add_contents))
     }
 
+    /// Create a scoped child ui that uses the given [`Widgets`](crate::style::Widgets) visuals.
+    ///
+    /// This is short for setting `ui.visuals_mut().widgets` inside of a [`Self::scope`].
+    /// The style of `self` is left untouched, so nested calls work as expected,
+    /// with the innermost call winning.
+    ///
+    /// Calling [`Self::reset_style`] inside the scope resets to the [`Context`] style,
+    /// discarding these visuals too.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut widgets = ui.visuals().widgets.clone();
+    /// widgets.inactive.weak_bg_fill = egui::Color32::DARK_RED;
+    /// ui.scope_styled(widgets, |ui| {
+    ///     ui.button("Delete");
+    /// });
+    /// # });
+    /// ```
+    pub fn scope_styled<R>(
+        &mut self,
+        widgets: crate::style::Widgets,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.scope(|ui| {
+            ui.visuals_mut().widgets = widgets;
+            add_contents(ui)
+        })
+    }
+
     /// Create a child, add content to it, and then allocate only what was used in the parent `Ui`.
     pub fn scope_dyn<'c, R>(
         &mut self,
@@ -2564,7 +2682,7 @@ impl Ui {
     /// Centering is almost always what you want if you are
     /// planning to mix widgets or use different types of text.
     ///
-    /// If you don't want the contents to be centered, use [`Self::horizontal_top`] instead.
+    /// If you don't want the contents to be centered, use [`Self::horizontal_aligned`] instead.
     ///
     /// The returned [`Response`] will only have checked for mouse hover
     /// but can be used for tooltips (`on_hover_text`).
@@ -2582,7 +2700,35 @@ impl Ui {
     /// See also [`Self::with_layout`] for more options.
     #[inline]
     pub fn horizontal<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
-        self.horizontal_with_main_wrap_dyn(false, Box::new(add_contents))
+        self.horizontal_with_main_wrap_dyn(false, Align::Center, Box::new(add_contents))
+    }
+
+    /// Like [`Self::horizontal`], but with a choice of how to align the elements vertically.
+    ///
+    /// [`Align::Min`] aligns them with the top of the row, [`Align::Center`] (like [`Self::horizontal`])
+    /// centers them, and [`Align::Max`] aligns them with the bottom.
+    ///
+    /// Unlike [`Self::horizontal_top`], this doesn't allocate the full available height:
+    /// the row is as tall as its tallest element.
+    ///
+    /// This does not wrap. For a wrapping row, the same alignment applies to each row:
+    /// `ui.with_layout(egui::Layout::left_to_right(align).with_main_wrap(true), …)`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.horizontal_aligned(egui::Align::Min, |ui| {
+    ///     ui.add_sized([100.0, 100.0], egui::Button::new("Tall"));
+    ///     ui.label("Top-aligned");
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn horizontal_aligned<R>(
+        &mut self,
+        align: Align,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        self.horizontal_with_main_wrap_dyn(false, align, Box::new(add_contents))
     }
 
     /// Like [`Self::horizontal`], but allocates the full vertical height and then centers elements vertically.
@@ -2601,6 +2747,9 @@ impl Ui {
     }
 
     /// Like [`Self::horizontal`], but aligns content with top.
+    ///
+    /// This allocates the full available height.
+    /// Use `ui.horizontal_aligned(Align::Min, …)` for a row that is only as tall as its contents.
     pub fn horizontal_top<R>(
         &mut self,
         add_contents: impl FnOnce(&mut Ui) -> R,
@@ -2634,12 +2783,13 @@ impl Ui {
         &mut self,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
-        self.horizontal_with_main_wrap_dyn(true, Box::new(add_contents))
+        self.horizontal_with_main_wrap_dyn(true, Align::Center, Box::new(add_contents))
     }
 
     fn horizontal_with_main_wrap_dyn<'c, R>(
         &mut self,
         main_wrap: bool,
+        cross_align: Align,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
         let initial_size = vec2(
@@ -2648,9 +2798,9 @@ impl Ui {
         );
 
         let layout = if self.placer.prefer_right_to_left() {
-            Layout::right_to_left(Align::Center)
+            Layout::right_to_left(cross_align)
         } else {
-            Layout::left_to_right(Align::Center)
+            Layout::left_to_right(cross_align)
         }
         .with_main_wrap(main_wrap);
 
@@ -2800,6 +2950,41 @@ impl Ui {
         self.columns_dyn(num_columns, Box::new(add_contents))
     }
 
+    /// Split a [`Ui`] into as many columns as fit, with each column at least `min_width` wide.
+    ///
+    /// There is always at least one column, even if it is narrower than `min_width`,
+    /// and at most 256, e.g. for an unbounded width in a horizontal [`crate::ScrollArea`].
+    /// The leftover width is divided evenly between the columns.
+    ///
+    /// Returns the number of columns used, which is also the length of the slice passed to `add_contents`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let items = ["Apple", "Banana", "Cherry", "Durian", "Elderberry"];
+    /// ui.columns_min_width(100.0, |columns| {
+    ///     let num_columns = columns.len();
+    ///     for (i, item) in items.iter().enumerate() {
+    ///         columns[i % num_columns].label(*item);
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn columns_min_width(
+        &mut self,
+        min_width: f32,
+        add_contents: impl FnOnce(&mut [Self]),
+    ) -> usize {
+        const MAX_COLUMNS: f32 = 256.0;
+
+        let spacing = self.spacing().item_spacing.x;
+        // Each column but the last is followed by spacing:
+        let num_columns = ((self.available_width() + spacing) / (min_width + spacing).max(1.0))
+            .floor()
+            .clamp(1.0, MAX_COLUMNS) as usize;
+        self.columns_dyn(num_columns, Box::new(add_contents));
+        num_columns
+    }
+
     fn columns_dyn<'c, R>(
         &mut self,
         num_columns: usize,
@@ -2907,6 +3092,9 @@ impl Ui {
     ///
     /// In contrast to [`Response::dnd_set_drag_payload`],
     /// this function will paint the widget at the mouse cursor while the user is dragging.
+    ///
+    /// The payload is delivered by [`Self::dnd_drop_zone`] when released over it.
+    /// If it is released anywhere else (or the user presses escape), the payload is discarded.
     #[doc(alias = "drag and drop")]
     pub fn dnd_drag_source<Payload, R>(
         &mut self,
@@ -2945,9 +3133,11 @@ impl Ui {
             let InnerResponse { inner, response } = self.scope(add_contents);
 
             // Check for drags:
-            let dnd_response = self
-                .interact(response.rect, id, Sense::drag())
-                .on_hover_cursor(CursorIcon::Grab);
+            let dnd_response = self.interact(response.rect, id, Sense::drag());
+            if dnd_response.hovered() {
+                self.ctx()
+                    .set_cursor_icon_with_priority(CursorIcon::Grab, CursorIconPriority::GRAB);
+            }
 
             InnerResponse::new(inner, dnd_response | response)
         }
@@ -3032,6 +3222,62 @@ impl Ui {
 
         r
     }
+
+    /// Create a new Scope and multiply the opacity of every shape painted in it.
+    ///
+    /// `opacity` must be between 0.0 and 1.0, where 0.0 means fully transparent (i.e., invisible)
+    /// and 1.0 means fully opaque.
+    ///
+    /// Unlike [`Self::multiply_opacity`], which only affects what is painted with the painter of this [`Ui`],
+    /// this fades _all_ shapes painted to this layer while `add_contents` runs,
+    /// e.g. also those painted with [`Context::layer_painter`] or with a painter with its own opacity.
+    /// This makes it suitable for fading out a whole panel, e.g. for a loading overlay:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let is_loading = true;
+    /// let opacity = if is_loading { 0.3 } else { 1.0 };
+    /// ui.with_multiplied_opacity(opacity, |ui| {
+    ///     ui.add_enabled_ui(!is_loading, |ui| {
+    ///         ui.label("Some contents");
+    ///         if ui.button("Reload").clicked() {
+    ///             /* … */
+    ///         }
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// This does _not_ composite the contents as a group:
+    /// each shape is faded on its own, since egui has no way of rendering to an intermediate texture.
+    /// So where the faded shapes overlap (e.g. text on top of a button),
+    /// the shapes underneath will show through.
+    /// Shapes in other layers (e.g. popups) and [`crate::PaintCallback`]s are not faded.
+    ///
+    /// Like [`Self::with_visual_transform`], this only affects visuals, not interaction.
+    pub fn with_multiplied_opacity<R>(
+        &mut self,
+        opacity: f32,
+        add_contents: impl FnOnce(&mut Self) -> R,
+    ) -> InnerResponse<R> {
+        let start_idx = self.ctx().graphics(|gx| {
+            gx.get(self.layer_id())
+                .map_or(crate::layers::ShapeIdx(0), |l| l.next_idx())
+        });
+
+        let r = self.scope_dyn(UiBuilder::new(), Box::new(add_contents));
+
+        if opacity.is_finite() && opacity < 1.0 {
+            let opacity = opacity.max(0.0);
+            self.ctx().graphics_mut(|g| {
+                let list = g.entry(self.layer_id());
+                let end_idx = list.next_idx();
+                list.multiply_opacity_range(start_idx, end_idx, opacity);
+            });
+        }
+
+        r
+    }
 }
 
 /// # Menus
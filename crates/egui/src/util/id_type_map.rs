@@ -81,6 +81,12 @@ enum Element {
         /// The actual value.
         value: Box<dyn Any + 'static + Send + Sync>,
 
+        /// The result of [`std::any::type_name`] for the value, for introspection.
+        ///
+        /// Only stored in debug builds, to keep the elements small.
+        #[cfg(debug_assertions)]
+        type_name: &'static str,
+
         /// How to clone the value.
         clone_fn: fn(&Box<dyn Any + 'static + Send + Sync>) -> Box<dyn Any + 'static + Send + Sync>,
 
@@ -99,11 +105,15 @@ impl Clone for Element {
         match &self {
             Self::Value {
                 value,
+                #[cfg(debug_assertions)]
+                type_name,
                 clone_fn,
                 #[cfg(feature = "persistence")]
                 serialize_fn,
             } => Self::Value {
                 value: clone_fn(value),
+                #[cfg(debug_assertions)]
+                type_name: *type_name,
                 clone_fn: *clone_fn,
                 #[cfg(feature = "persistence")]
                 serialize_fn: *serialize_fn,
@@ -141,6 +151,8 @@ impl Element {
     pub(crate) fn new_temp<T: 'static + Any + Clone + Send + Sync>(t: T) -> Self {
         Self::Value {
             value: Box::new(t),
+            #[cfg(debug_assertions)]
+            type_name: std::any::type_name::<T>(),
             clone_fn: |x| {
                 let x = x.downcast_ref::<T>().unwrap(); // This unwrap will never panic, because we always construct this type using this `new` function and because we return &mut reference only with this type `T`, so type cannot change.
                 Box::new(x.clone())
@@ -155,6 +167,8 @@ impl Element {
     pub(crate) fn new_persisted<T: SerializableAny>(t: T) -> Self {
         Self::Value {
             value: Box::new(t),
+            #[cfg(debug_assertions)]
+            type_name: std::any::type_name::<T>(),
             clone_fn: |x| {
                 let x = x.downcast_ref::<T>().unwrap(); // This unwrap will never panic, because we always construct this type using this `new` function and because we return &mut reference only with this type `T`, so type cannot change.
                 Box::new(x.clone())
@@ -387,6 +401,24 @@ impl IdTypeMap {
         self.map.get(&hash).and_then(|x| x.get_temp()).cloned()
     }
 
+    /// Read a value by reference, without trying to deserialize a persisted value.
+    ///
+    /// Unlike [`Self::get_temp`] this does not clone the value,
+    /// so it is useful for values that are expensive to clone.
+    ///
+    /// ```
+    /// # use egui::{Id, util::IdTypeMap};
+    /// let id = Id::new("big");
+    /// let mut map: IdTypeMap = Default::default();
+    /// map.insert_temp(id, vec![0_u8; 1024]);
+    /// assert_eq!(map.get_temp_ref::<Vec<u8>>(id).map(|v| v.len()), Some(1024));
+    /// ```
+    #[inline]
+    pub fn get_temp_ref<T: 'static>(&self, id: Id) -> Option<&T> {
+        let hash = hash(TypeId::of::<T>(), id);
+        self.map.get(&hash).and_then(|x| x.get_temp())
+    }
+
     /// Read a value, optionally deserializing it if available.
     ///
     /// NOTE: A mutable `self` is needed because internally this deserializes on first call
@@ -429,6 +461,9 @@ impl IdTypeMap {
         self.get_persisted_mut_or_insert_with(id, Default::default)
     }
 
+    /// Get a mutable reference to a value, inserting one if there is none.
+    ///
+    /// `insert_with` is only called if the value needs to be created.
     pub fn get_temp_mut_or_insert_with<T: 'static + Any + Clone + Send + Sync>(
         &mut self,
         id: Id,
@@ -515,6 +550,41 @@ impl IdTypeMap {
         self.map.len()
     }
 
+    /// Iterate over the [`Id`] and type name of all values that are not serialized.
+    ///
+    /// The type names are only known in debug builds, and are `None` otherwise.
+    /// Persisted values that have been loaded but not yet read are skipped,
+    /// since we don't know their types (see [`Self::count_serialized`]).
+    ///
+    /// This is useful for debugging, e.g. to find temporary values that keep accumulating.
+    /// The order is unspecified.
+    ///
+    /// ```
+    /// # use egui::{Id, util::IdTypeMap};
+    /// let mut map: IdTypeMap = Default::default();
+    /// map.insert_temp(Id::new("a"), 42_i32);
+    /// let type_name = cfg!(debug_assertions).then_some("i32");
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), vec![(Id::new("a"), type_name)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Id, Option<&'static str>)> + '_ {
+        self.map.iter().filter_map(|(hash, element)| match element {
+            Element::Value {
+                #[cfg(debug_assertions)]
+                type_name,
+                ..
+            } => {
+                // The key is `id XOR type_id`, so we can recover the id:
+                let id = Id::from_hash(hash ^ element.type_id().value());
+                #[cfg(debug_assertions)]
+                let type_name = Some(*type_name);
+                #[cfg(not(debug_assertions))]
+                let type_name = None;
+                Some((id, type_name))
+            }
+            Element::Serialized(_) => None,
+        })
+    }
+
     /// Count how many values are stored but not yet deserialized.
     #[inline]
     pub fn count_serialized(&self) -> usize {
@@ -734,6 +804,64 @@ fn test_two_id_x_two_types() {
     assert_eq!(map.get_temp::<String>(b), Some("Hello World".to_owned()));
 }
 
+#[test]
+fn test_get_temp_ref() {
+    let id = Id::new("a");
+    let mut map: IdTypeMap = Default::default();
+    assert_eq!(map.get_temp_ref::<String>(id), None);
+
+    let mut num_calls = 0;
+    map.get_temp_mut_or_insert_with(id, || {
+        num_calls += 1;
+        "Hello".to_owned()
+    })
+    .push_str(" World");
+    map.get_temp_mut_or_insert_with(id, || {
+        num_calls += 1;
+        String::new()
+    });
+    assert_eq!(num_calls, 1, "The default should only be constructed once");
+
+    assert_eq!(
+        map.get_temp_ref::<String>(id).map(String::as_str),
+        Some("Hello World")
+    );
+    assert_eq!(map.get_temp_ref::<i32>(id), None, "Wrong type");
+
+    // Persisted values are returned too, as long as they have been deserialized:
+    map.insert_persisted(id, 42_i32);
+    assert_eq!(map.get_temp_ref::<i32>(id), Some(&42));
+}
+
+#[test]
+fn test_iter() {
+    let a = Id::new("a");
+    let b = Id::new("b");
+    let mut map: IdTypeMap = Default::default();
+    assert!(map.is_empty());
+
+    map.insert_temp(a, 42_i32);
+    map.insert_temp(a, "Hello".to_owned());
+    map.insert_persisted(b, 13.37_f64);
+    assert_eq!(map.len(), 3);
+
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by_key(|(_, type_name)| *type_name);
+    if cfg!(debug_assertions) {
+        assert_eq!(
+            entries,
+            vec![
+                (a, Some(std::any::type_name::<String>())),
+                (b, Some("f64")),
+                (a, Some("i32"))
+            ]
+        );
+    } else {
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries.iter().filter(|(id, _)| *id == a).count(), 2);
+    }
+}
+
 #[test]
 fn test_one_id_two_types() {
     let id = Id::new("a");
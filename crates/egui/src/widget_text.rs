@@ -41,6 +41,8 @@ pub struct RichText {
     underline: bool,
     italics: bool,
     raised: bool,
+    superscript: bool,
+    subscript: bool,
 }
 
 impl Default for RichText {
@@ -62,6 +64,8 @@ impl Default for RichText {
             underline: Default::default(),
             italics: Default::default(),
             raised: Default::default(),
+            superscript: Default::default(),
+            subscript: Default::default(),
         }
     }
 }
@@ -122,6 +126,15 @@ impl From<Cow<'_, str>> for RichText {
     }
 }
 
+/// The size of [`RichText::superscript`] and [`RichText::subscript`], relative to the normal size.
+const SCRIPT_SIZE_FACTOR: f32 = 0.65;
+
+/// How far up [`RichText::superscript`] is moved, relative to the normal font size.
+const SUPERSCRIPT_SHIFT: f32 = 0.4;
+
+/// How far down [`RichText::subscript`] is moved, relative to the normal font size.
+const SUBSCRIPT_SHIFT: f32 = 0.1;
+
 impl RichText {
     #[inline]
     pub fn new(text: impl Into<String>) -> Self {
@@ -287,6 +300,38 @@ impl RichText {
         self
     }
 
+    /// Smaller text, moved up above the baseline, e.g. for the `2` in "x²".
+    ///
+    /// The size and offset are relative to the font size the text would otherwise have.
+    ///
+    /// Use [`Self::append_to`] to mix it with normal text in the same [`LayoutJob`]:
+    /// ```
+    /// use egui::{Align, FontSelection, RichText, Style, text::LayoutJob};
+    ///
+    /// let style = Style::default();
+    /// let mut job = LayoutJob::default();
+    /// RichText::new("x").append_to(&mut job, &style, FontSelection::Default, Align::Center);
+    /// RichText::new("2")
+    ///     .superscript()
+    ///     .append_to(&mut job, &style, FontSelection::Default, Align::Center);
+    /// ```
+    #[inline]
+    pub fn superscript(mut self) -> Self {
+        self.superscript = true;
+        self.subscript = false;
+        self
+    }
+
+    /// Smaller text, moved down below the baseline, e.g. for the `2` in "H₂O".
+    ///
+    /// The size and offset are relative to the font size the text would otherwise have.
+    #[inline]
+    pub fn subscript(mut self) -> Self {
+        self.subscript = true;
+        self.superscript = false;
+        self
+    }
+
     /// Fill-color behind the text.
     #[inline]
     pub fn background_color(mut self, background_color: impl Into<Color32>) -> Self {
@@ -319,6 +364,9 @@ impl RichText {
         if let Some(family) = &self.family {
             font_id.family = family.clone();
         }
+        if self.superscript || self.subscript {
+            font_id.size *= SCRIPT_SIZE_FACTOR;
+        }
         fonts.row_height(&font_id)
     }
 
@@ -398,12 +446,14 @@ impl RichText {
             underline,
             italics,
             raised,
+            superscript,
+            subscript,
         } = self;
 
         let line_color = text_color.unwrap_or_else(|| style.visuals.text_color());
         let text_color = text_color.unwrap_or(crate::Color32::PLACEHOLDER);
 
-        let font_id = {
+        let mut font_id = {
             let mut font_id = text_style
                 .or_else(|| style.override_text_style.clone())
                 .map_or_else(
@@ -422,6 +472,17 @@ impl RichText {
             font_id
         };
 
+        let baseline_shift = if superscript {
+            SUPERSCRIPT_SHIFT * font_id.size
+        } else if subscript {
+            -SUBSCRIPT_SHIFT * font_id.size
+        } else {
+            0.0
+        };
+        if superscript || subscript {
+            font_id.size *= SCRIPT_SIZE_FACTOR;
+        }
+
         let background_color = if code {
             style.visuals.code_bg_color
         } else {
@@ -439,7 +500,10 @@ impl RichText {
             crate::Stroke::NONE
         };
 
-        let valign = if raised {
+        let valign = if superscript || subscript {
+            // The baseline shift is relative to the bottom of the row:
+            crate::Align::BOTTOM
+        } else if raised {
             crate::Align::TOP
         } else {
             default_valign
@@ -455,9 +519,11 @@ impl RichText {
                 background: background_color,
                 italics,
                 underline,
+                underline_style: Default::default(),
                 strikethrough,
                 valign,
                 expand_bg,
+                baseline_shift,
             },
         )
     }
@@ -669,6 +735,18 @@ impl WidgetText {
         self.map_rich_text(|text| text.raised())
     }
 
+    /// Prefer using [`RichText`] directly!
+    #[inline]
+    pub fn superscript(self) -> Self {
+        self.map_rich_text(|text| text.superscript())
+    }
+
+    /// Prefer using [`RichText`] directly!
+    #[inline]
+    pub fn subscript(self) -> Self {
+        self.map_rich_text(|text| text.subscript())
+    }
+
     /// Prefer using [`RichText`] directly!
     #[inline]
     pub fn background_color(self, background_color: impl Into<Color32>) -> Self {
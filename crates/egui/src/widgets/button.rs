@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use crate::{
-    Atom, AtomExt as _, AtomKind, AtomLayout, AtomLayoutResponse, Color32, CornerRadius, Frame,
-    Image, IntoAtoms, NumExt as _, Response, Sense, Stroke, TextStyle, TextWrapMode, Ui, Vec2,
-    Widget, WidgetInfo, WidgetText, WidgetType,
+    Align, Atom, AtomExt as _, AtomKind, AtomLayout, AtomLayoutResponse, Color32, CornerRadius,
+    FontSelection, Frame, Image, IntoAtoms, KeyboardShortcut, NumExt as _, Response, Sense, Stroke,
+    Style, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, WidgetType,
+    style::WidgetVisuals, text::LayoutJob,
 };
 
 /// Clickable button with text.
@@ -28,6 +31,8 @@ pub struct Button<'a> {
     fill: Option<Color32>,
     stroke: Option<Stroke>,
     small: bool,
+    big: bool,
+    primary: bool,
     frame: Option<bool>,
     frame_when_inactive: bool,
     min_size: Vec2,
@@ -35,9 +40,17 @@ pub struct Button<'a> {
     selected: bool,
     image_tint_follows_text_color: bool,
     limit_image_size: bool,
+    icon_gutter: Option<Atom<'a>>,
+    subtitle: Option<WidgetText>,
+    shortcut: Option<KeyboardShortcut>,
+    visuals: Option<WidgetVisuals>,
+    class: Option<&'a str>,
 }
 
 impl<'a> Button<'a> {
+    /// How much larger [`Self::big`] buttons are than normal ones.
+    pub const BIG_FACTOR: f32 = 1.5;
+
     pub fn new(atoms: impl IntoAtoms<'a>) -> Self {
         Self {
             layout: AtomLayout::new(atoms.into_atoms())
@@ -46,6 +59,8 @@ impl<'a> Button<'a> {
             fill: None,
             stroke: None,
             small: false,
+            big: false,
+            primary: false,
             frame: None,
             frame_when_inactive: true,
             min_size: Vec2::ZERO,
@@ -53,6 +68,11 @@ impl<'a> Button<'a> {
             selected: false,
             image_tint_follows_text_color: false,
             limit_image_size: false,
+            icon_gutter: None,
+            subtitle: None,
+            shortcut: None,
+            visuals: None,
+            class: None,
         }
     }
 
@@ -156,6 +176,29 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Make this a big button, with more padding and a taller minimum height.
+    ///
+    /// The [`crate::Spacing::button_padding`] and minimum height are scaled by [`Self::BIG_FACTOR`].
+    /// A larger [`Self::min_size`] still applies.
+    #[inline]
+    pub fn big(mut self) -> Self {
+        self.big = true;
+        self
+    }
+
+    /// Make this a primary button, i.e. the main action of the surrounding ui.
+    ///
+    /// A primary button is filled with the selection color ([`crate::Visuals::selection`]),
+    /// and uses the matching high-contrast text color.
+    /// It always has a frame, unless you turn it off with [`Self::frame`].
+    ///
+    /// [`Self::fill`] and [`Self::visuals`] take precedence.
+    #[inline]
+    pub fn primary(mut self) -> Self {
+        self.primary = true;
+        self
+    }
+
     /// Turn off the frame
     #[inline]
     pub fn frame(mut self, frame: bool) -> Self {
@@ -203,6 +246,39 @@ impl<'a> Button<'a> {
         self.corner_radius(corner_radius)
     }
 
+    /// Override the [`WidgetVisuals`] used to paint this button.
+    ///
+    /// By default the visuals come from the [`crate::Style`], and depend on
+    /// whether the button is hovered, pressed, selected, etc.
+    /// When set, these visuals are used for all interaction states instead.
+    ///
+    /// [`Self::fill`], [`Self::stroke`] and [`Self::corner_radius`] still take precedence.
+    #[inline]
+    pub fn visuals(mut self, visuals: WidgetVisuals) -> Self {
+        self.visuals = Some(visuals);
+        self
+    }
+
+    /// Paint this button using the visuals of a named style class.
+    ///
+    /// Register classes with [`crate::Context::register_style_class`].
+    /// Unknown class names fall back to the default visuals, and log a warning once.
+    ///
+    /// [`Self::visuals`] takes precedence.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// if ui.add(egui::Button::new("Delete").class("danger")).clicked() {
+    ///     /* … */
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
     /// If true, the tint of the image is multiplied by the widget text color.
     ///
     /// This makes sense for images that are white, that should have the same color as the text color.
@@ -234,6 +310,79 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Show a keyboard shortcut on the right side of the button, in weak color.
+    ///
+    /// Like [`Self::shortcut_text`], but the shortcut is formatted for you
+    /// with [`crate::Context::format_shortcut`] (e.g. `⌘S` on Mac and `Ctrl+S` elsewhere).
+    ///
+    /// This only shows the shortcut. To act on it, use [`crate::InputState::consume_shortcut`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let save = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S);
+    /// if ui.add(egui::Button::new("Save").shortcut(save)).clicked()
+    ///     || ui.input_mut(|i| i.consume_shortcut(&save))
+    /// {
+    ///     // Save the file
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    /// Show an icon to the left of the contents, in a gutter of fixed width.
+    ///
+    /// The gutter is reserved even if `icon` is `None`,
+    /// so that the texts of all items in a menu line up, regardless of which of them have an icon.
+    /// The gutter is [`crate::style::Spacing::icon_width`] wide, so it is the same for all items in a menu.
+    ///
+    /// Use [`Self::image_tint_follows_text_color`] for icons that should follow the theme.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let icon = egui::include_image!("../../assets/ferris.png");
+    /// ui.menu_button("File", |ui| {
+    ///     ui.add(egui::Button::new("Save").icon_gutter(Some(egui::Image::new(icon))));
+    ///     ui.add(egui::Button::new("Save as…").icon_gutter(None));
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn icon_gutter(mut self, icon: Option<Image<'a>>) -> Self {
+        self.icon_gutter = Some(icon.map_or_else(Atom::default, Atom::from));
+        self
+    }
+
+    /// Show a second, dimmer line of text below the text of the button.
+    ///
+    /// The text of the button becomes a title in the strong text color
+    /// (unless it is a [`crate::text::LayoutJob`] or [`crate::Galley`]),
+    /// and the subtitle is shown below it, in small and weak text.
+    /// The button grows to fit both lines.
+    ///
+    /// Combine it with [`Self::selectable`] and [`Self::icon_gutter`] for e.g. sidebar navigation lists:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let icon = egui::include_image!("../../assets/ferris.png");
+    /// # let mut selected = 0;
+    /// let button = egui::Button::selectable(selected == 0, "Inbox")
+    ///     .icon_gutter(Some(egui::Image::new(icon)))
+    ///     .subtitle("3 unread messages");
+    /// if ui.add(button).clicked() {
+    ///     selected = 0;
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn subtitle(mut self, subtitle: impl Into<WidgetText>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
     /// Show some text on the right side of the button.
     #[inline]
     pub fn right_text(mut self, right_text: impl Into<Atom<'a>>) -> Self {
@@ -256,6 +405,8 @@ impl<'a> Button<'a> {
             fill,
             stroke,
             small,
+            big,
+            primary,
             frame,
             frame_when_inactive,
             mut min_size,
@@ -263,10 +414,19 @@ impl<'a> Button<'a> {
             selected,
             image_tint_follows_text_color,
             limit_image_size,
+            icon_gutter,
+            subtitle,
+            shortcut,
+            visuals,
+            class,
         } = self;
 
+        let size_factor = if big { Self::BIG_FACTOR } else { 1.0 };
+
         if !small {
-            min_size.y = min_size.y.at_least(ui.spacing().interact_size.y);
+            min_size.y = min_size
+                .y
+                .at_least(size_factor * ui.spacing().interact_size.y);
         }
 
         if limit_image_size {
@@ -279,12 +439,44 @@ impl<'a> Button<'a> {
             });
         }
 
+        if let Some(subtitle) = subtitle {
+            // Put the subtitle below the first text, so the title and subtitle form one atom:
+            let mut subtitle = Some(subtitle);
+            layout.map_atoms(|mut atom| {
+                atom.kind = match atom.kind {
+                    AtomKind::Text(title) => match subtitle.take() {
+                        Some(subtitle) => {
+                            AtomKind::Text(title_and_subtitle(ui.style(), title, subtitle))
+                        }
+                        None => AtomKind::Text(title),
+                    },
+                    other => other,
+                };
+                atom
+            });
+            if let Some(subtitle) = subtitle {
+                // There was no text to put it below:
+                layout.push_right(subtitle.small().weak());
+            }
+        }
+
+        if let Some(mut icon) = icon_gutter {
+            icon.size = Some(Vec2::splat(ui.spacing().icon_width));
+            layout.push_left(icon);
+        }
+
+        if let Some(shortcut) = shortcut {
+            let shortcut_text = WidgetText::from(ui.ctx().format_shortcut(&shortcut)).weak();
+            layout.push_right(Atom::grow());
+            layout.push_right(shortcut_text);
+        }
+
         let text = layout.text().map(String::from);
 
-        let has_frame_margin = frame.unwrap_or_else(|| ui.visuals().button_frame);
+        let has_frame_margin = frame.unwrap_or_else(|| primary || ui.visuals().button_frame);
 
         let mut button_padding = if has_frame_margin {
-            ui.spacing().button_padding
+            size_factor * ui.spacing().button_padding
         } else {
             Vec2::ZERO
         };
@@ -298,7 +490,14 @@ impl<'a> Button<'a> {
             .allocate(ui);
 
         let response = if ui.is_rect_visible(prepared.response.rect) {
-            let visuals = ui.style().interact_selectable(&prepared.response, selected);
+            let visuals = visuals.unwrap_or_else(|| {
+                let style = ui.style();
+                let widgets = match class {
+                    Some(class) => style.class_widgets(ui.ctx(), class),
+                    None => &style.visuals.widgets,
+                };
+                style.interact_selectable_with(widgets, &prepared.response, selected || primary)
+            });
 
             let visible_frame = if frame_when_inactive {
                 has_frame_margin
@@ -346,6 +545,31 @@ impl<'a> Button<'a> {
     }
 }
 
+/// Lay out `title` with `subtitle` on a line below it.
+fn title_and_subtitle(style: &Style, title: WidgetText, subtitle: WidgetText) -> WidgetText {
+    let fallback_font = FontSelection::Style(TextStyle::Button);
+    let mut job = Arc::unwrap_or_clone(title.strong().into_layout_job(
+        style,
+        fallback_font.clone(),
+        Align::Center,
+    ));
+    let subtitle = subtitle
+        .small()
+        .weak()
+        .into_layout_job(style, fallback_font, Align::Center);
+
+    for (i, section) in subtitle.sections.iter().enumerate() {
+        let text = &subtitle.text[section.byte_range.clone()];
+        if i == 0 {
+            let format = section.format.clone();
+            job.append(&format!("\n{text}"), 0.0, format);
+        } else {
+            job.append(text, section.leading_space, section.format.clone());
+        }
+    }
+    WidgetText::LayoutJob(Arc::new(job))
+}
+
 impl Widget for Button<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
         self.atom_ui(ui).response
@@ -38,8 +38,11 @@ impl<'a> Checkbox<'a> {
 
     /// Display an indeterminate state (neither checked nor unchecked)
     ///
-    /// This only affects the checkbox's appearance. It will still toggle its boolean value when
-    /// clicked.
+    /// This is shown as a dash, and reported as "mixed" to screen readers.
+    /// Clicking an indeterminate checkbox always checks it,
+    /// e.g. so that a "select all" checkbox selects everything when only some items are selected.
+    /// As usual, the response is only [`Response::changed`] if the value changed,
+    /// so pass `false` for a "select all" checkbox that is indeterminate.
     #[inline]
     pub fn indeterminate(mut self, indeterminate: bool) -> Self {
         self.indeterminate = indeterminate;
@@ -75,8 +78,11 @@ impl Widget for Checkbox<'_> {
             .allocate(ui);
 
         if prepared.response.clicked() {
-            *checked = !*checked;
-            prepared.response.mark_changed();
+            let new_checked = indeterminate || !*checked;
+            if new_checked != *checked {
+                *checked = new_checked;
+                prepared.response.mark_changed();
+            }
         }
         prepared.response.widget_info(|| {
             if indeterminate {
@@ -3,8 +3,8 @@
 use std::{cmp::Ordering, ops::RangeInclusive};
 
 use crate::{
-    Button, CursorIcon, Id, Key, MINUS_CHAR_STR, Modifiers, NumExt as _, Response, RichText, Sense,
-    TextEdit, TextWrapMode, Ui, Widget, WidgetInfo, emath, text,
+    Button, CursorIcon, CursorIconPriority, Id, Key, MINUS_CHAR_STR, Modifiers, NumExt as _,
+    Response, RichText, Sense, TextEdit, TextWrapMode, Ui, Widget, WidgetInfo, emath, text,
 };
 
 // ----------------------------------------------------------------------------
@@ -38,6 +38,8 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
 pub struct DragValue<'a> {
     get_set_value: GetSetValue<'a>,
     speed: f64,
+    slow_speed_factor: f64,
+    fast_speed_factor: f64,
     prefix: String,
     suffix: String,
     range: RangeInclusive<f64>,
@@ -69,6 +71,8 @@ impl<'a> DragValue<'a> {
         Self {
             get_set_value: Box::new(get_set_value),
             speed: 1.0,
+            slow_speed_factor: 0.1,
+            fast_speed_factor: 10.0,
             prefix: Default::default(),
             suffix: Default::default(),
             range: f64::NEG_INFINITY..=f64::INFINITY,
@@ -90,6 +94,29 @@ impl<'a> DragValue<'a> {
         self
     }
 
+    /// How much to multiply the [`Self::speed`] with while dragging with a modifier held down.
+    ///
+    /// Holding Shift multiplies it with `slow`, for fine control,
+    /// and holding Ctrl (Cmd on Mac) multiplies it with `fast`, for coarse control.
+    /// Pressing or releasing a modifier mid-drag only affects the movement from then on,
+    /// so the value never jumps.
+    ///
+    /// Both factors should be finite and greater than zero.
+    /// Default: `(0.1, 10.0)`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_f32: f32 = 0.0;
+    /// ui.add(egui::DragValue::new(&mut my_f32).modifier_speed(0.01, 100.0));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn modifier_speed(mut self, slow: impl Into<f64>, fast: impl Into<f64>) -> Self {
+        self.slow_speed_factor = slow.into();
+        self.fast_speed_factor = fast.into();
+        self
+    }
+
     /// Sets valid range for the value.
     ///
     /// By default all values are clamped to this range, even when not interacted with.
@@ -431,6 +458,8 @@ impl Widget for DragValue<'_> {
         let Self {
             mut get_set_value,
             speed,
+            slow_speed_factor,
+            fast_speed_factor,
             range,
             clamp_existing_to_range,
             prefix,
@@ -442,10 +471,21 @@ impl Widget for DragValue<'_> {
             update_while_editing,
         } = self;
 
-        let shift = ui.input(|i| i.modifiers.shift_only());
         // The widget has the same ID whether it's in edit or button mode.
         let id = ui.next_auto_id();
-        let is_slow_speed = shift && ui.ctx().is_being_dragged(id);
+        let speed_factor = if ui.ctx().is_being_dragged(id) {
+            ui.input(|i| {
+                if i.modifiers.shift_only() {
+                    slow_speed_factor
+                } else if i.modifiers.command_only() {
+                    fast_speed_factor
+                } else {
+                    1.0
+                }
+            })
+        } else {
+            1.0
+        };
 
         // The following ensures that when a `DragValue` receives focus,
         // it is immediately rendered in edit mode, rather than being rendered
@@ -466,7 +506,9 @@ impl Widget for DragValue<'_> {
         let aim_rad = ui.input(|i| i.aim_radius() as f64);
 
         let auto_decimals = (aim_rad / speed.abs()).log10().ceil().clamp(0.0, 15.0) as usize;
-        let auto_decimals = auto_decimals + is_slow_speed as usize;
+        // Show more decimals when dragging slowly:
+        let auto_decimals =
+            auto_decimals + speed_factor.recip().log10().ceil().clamp(0.0, 15.0) as usize;
         let max_decimals = max_decimals
             .unwrap_or(auto_decimals + 2)
             .at_least(min_decimals);
@@ -608,8 +650,13 @@ impl Widget for DragValue<'_> {
                 CursorIcon::ResizeWest
             };
 
-            let response = ui.add(button);
-            let mut response = response.on_hover_cursor(cursor_icon);
+            let mut response = ui.add(button);
+            if response.hovered() || response.dragged() {
+                // Like other draggable widgets, this should win over e.g. links below it,
+                // but not over actual resize handles:
+                ui.ctx()
+                    .set_cursor_icon_with_priority(cursor_icon, CursorIconPriority::GRAB);
+            }
 
             if ui.style().explanation_tooltips {
                 response = response.on_hover_text(format!(
@@ -630,12 +677,11 @@ impl Widget for DragValue<'_> {
                 ui.memory_mut(|mem| mem.request_focus(id));
                 select_all_text(ui, id, response.id, &value_text);
             } else if response.dragged() {
-                ui.ctx().set_cursor_icon(cursor_icon);
-
                 let mdelta = response.drag_delta();
                 let delta_points = mdelta.x - mdelta.y; // Increase to the right and up
 
-                let speed = if is_slow_speed { speed / 10.0 } else { speed };
+                // Only the movement of this frame is scaled, so changing modifiers mid-drag won't jump the value:
+                let speed = speed * speed_factor;
 
                 let delta_value = delta_points as f64 * speed;
 
@@ -8,7 +8,7 @@ use epaint::{
 
 use crate::{
     Color32, Context, CornerRadius, Id, Mesh, Painter, Rect, Response, Sense, Shape, Spinner,
-    TextStyle, TextureOptions, Ui, Vec2, Widget, WidgetInfo, WidgetType,
+    Stroke, StrokeKind, TextStyle, TextureOptions, Ui, Vec2, Widget, WidgetInfo, WidgetType,
     load::{Bytes, SizeHint, SizedTexture, TextureLoadResult, TexturePoll},
     pos2,
 };
@@ -178,6 +178,30 @@ impl<'a> Image<'a> {
         self
     }
 
+    /// Fill an exact size with the image, cropping whatever doesn't fit.
+    ///
+    /// Like `object-fit: cover` in CSS: the image is scaled uniformly to cover the whole box,
+    /// and the overflow is cropped equally on both sides.
+    ///
+    /// If [`Image::max_size`] is set, this is guaranteed to never exceed that limit.
+    #[inline]
+    pub fn fit_to_cover(mut self, size: Vec2) -> Self {
+        self.size.fit = ImageFit::Cover(size);
+        self
+    }
+
+    /// Fit the whole image inside an exact size, leaving empty space around it if needed.
+    ///
+    /// Like `object-fit: contain` in CSS: the full `size` is allocated,
+    /// and the image is scaled uniformly to fit inside it and centered (letterboxed).
+    ///
+    /// If [`Image::max_size`] is set, this is guaranteed to never exceed that limit.
+    #[inline]
+    pub fn fit_to_contain(mut self, size: Vec2) -> Self {
+        self.size.fit = ImageFit::Contain(size);
+        self
+    }
+
     /// Fit the image to a fraction of the available space.
     ///
     /// If [`Image::max_size`] is set, this is guaranteed to never exceed that limit.
@@ -268,6 +292,18 @@ impl<'a> Image<'a> {
         self.corner_radius(corner_radius)
     }
 
+    /// Draw an outline around the image.
+    ///
+    /// The border is painted on top of the image, inside its rectangle,
+    /// and follows the [`Self::corner_radius`].
+    ///
+    /// The default is no border ([`Stroke::NONE`]).
+    #[inline]
+    pub fn border(mut self, border: impl Into<Stroke>) -> Self {
+        self.image_options.border = border.into();
+        self
+    }
+
     /// Show a spinner when the image is loading.
     ///
     /// By default this uses the value of [`crate::Visuals::image_loading_spinners`].
@@ -396,12 +432,30 @@ impl<'a> Image<'a> {
             },
         );
 
+        self.paint_texture_load_result(ui, &texture, rect);
+    }
+
+    /// Paint the texture, adjusted to [`ImageFit::Cover`] and [`ImageFit::Contain`].
+    fn paint_texture_load_result(&self, ui: &Ui, tlr: &TextureLoadResult, rect: Rect) {
+        let mut rect = rect;
+        let mut options = Cow::Borrowed(&self.image_options);
+        if let Ok(TexturePoll::Ready { texture }) = tlr {
+            let (fitted_rect, uv) = self
+                .size
+                .fit
+                .fit_rect_and_uv(rect, options.uv, texture.size);
+            if fitted_rect != rect || uv != options.uv {
+                rect = fitted_rect;
+                options.to_mut().uv = uv;
+            }
+        }
+
         paint_texture_load_result(
             ui,
-            &texture,
+            tlr,
             rect,
             self.show_loading_spinner,
-            &self.image_options,
+            &options,
             self.alt_text.as_deref(),
         );
     }
@@ -420,14 +474,7 @@ impl Widget for Image<'_> {
             info
         });
         if ui.is_rect_visible(rect) {
-            paint_texture_load_result(
-                ui,
-                &tlr,
-                rect,
-                self.show_loading_spinner,
-                &self.image_options,
-                self.alt_text.as_deref(),
-            );
+            self.paint_texture_load_result(ui, &tlr, rect);
         }
         texture_load_result_response(&self.source(ui.ctx()), &tlr, response)
     }
@@ -478,6 +525,20 @@ pub enum ImageFit {
     ///
     /// Ignores how much space is actually available in the ui.
     Exact(Vec2),
+
+    /// Fill an exact size, keeping the aspect ratio and cropping the image centrally.
+    ///
+    /// Like `object-fit: cover` in CSS.
+    ///
+    /// Ignores how much space is actually available in the ui.
+    Cover(Vec2),
+
+    /// Fit the whole image inside an exact size, keeping the aspect ratio and centering it.
+    ///
+    /// Like `object-fit: contain` in CSS.
+    ///
+    /// Ignores how much space is actually available in the ui.
+    Contain(Vec2),
 }
 
 impl ImageFit {
@@ -485,7 +546,36 @@ impl ImageFit {
         match self {
             Self::Original { scale } => image_size * scale,
             Self::Fraction(fract) => available_size * fract,
-            Self::Exact(size) => size,
+            Self::Exact(size) | Self::Cover(size) | Self::Contain(size) => size,
+        }
+    }
+
+    /// Where to paint an image of `image_size` inside the allocated `rect`, and with what uv range.
+    ///
+    /// For [`Self::Cover`] the `uv` range is cropped around its center,
+    /// and for [`Self::Contain`] the rectangle is shrunk around its center.
+    /// All other modes return `rect` and `uv` unchanged.
+    pub fn fit_rect_and_uv(self, rect: Rect, uv: Rect, image_size: Vec2) -> (Rect, Rect) {
+        if !(image_size.x > 0.0 && image_size.y > 0.0) || !rect.is_positive() {
+            return (rect, uv);
+        }
+        let ratio = rect.size() / image_size;
+
+        match self {
+            Self::Cover(_) => {
+                let scale = ratio.max_elem();
+                let shown_fraction = rect.size() / (scale * image_size);
+                let uv = Rect::from_center_size(uv.center(), shown_fraction * uv.size());
+                (rect, uv)
+            }
+            Self::Contain(_) => {
+                let scale = ratio.min_elem();
+                (
+                    Rect::from_center_size(rect.center(), scale * image_size),
+                    uv,
+                )
+            }
+            Self::Original { .. } | Self::Fraction(_) | Self::Exact(_) => (rect, uv),
         }
     }
 }
@@ -504,7 +594,7 @@ impl ImageSize {
                 return SizeHint::Scale((pixels_per_point * scale).ord());
             }
             ImageFit::Fraction(fract) => available_size * fract,
-            ImageFit::Exact(size) => size,
+            ImageFit::Exact(size) | ImageFit::Cover(size) | ImageFit::Contain(size) => size,
         };
         let point_size = point_size.at_most(max_size);
 
@@ -547,6 +637,10 @@ impl ImageSize {
                 let scale_to_size = size.min(max_size);
                 scale_to_fit(image_source_size, scale_to_size, maintain_aspect_ratio)
             }
+            ImageFit::Cover(size) | ImageFit::Contain(size) => {
+                // The whole box is always allocated; the aspect ratio is kept when painting.
+                size.min(max_size)
+            }
         }
     }
 }
@@ -834,6 +928,12 @@ pub struct ImageOptions {
     /// Due to limitations in the current implementation,
     /// this will turn off any rotation of the image.
     pub corner_radius: CornerRadius,
+
+    /// An outline painted on top of the image, inside its rectangle.
+    ///
+    /// The default is no border ([`Stroke::NONE`]).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub border: Stroke,
 }
 
 impl Default for ImageOptions {
@@ -844,6 +944,7 @@ impl Default for ImageOptions {
             tint: Color32::WHITE,
             rotation: None,
             corner_radius: CornerRadius::ZERO,
+            border: Stroke::NONE,
         }
     }
 }
@@ -873,13 +974,34 @@ pub fn paint_texture_at(
 
             let mut mesh = Mesh::with_texture(texture.id);
             mesh.add_rect_with_uv(rect, options.uv, options.tint);
-            mesh.rotate(rot, rect.min + origin * rect.size());
+            let origin = rect.min + origin * rect.size();
+            mesh.rotate(rot, origin);
             painter.add(Shape::mesh(mesh));
+
+            if !options.border.is_empty() {
+                let inner_rect = rect.shrink(0.5 * options.border.width);
+                let corners = [
+                    inner_rect.left_top(),
+                    inner_rect.right_top(),
+                    inner_rect.right_bottom(),
+                    inner_rect.left_bottom(),
+                ]
+                .map(|corner| origin + rot * (corner - origin));
+                painter.add(Shape::closed_line(corners.to_vec(), options.border));
+            }
         }
         None => {
+            // The texture is clipped by the rounded corners of the fill,
+            // and the border is painted on top of it, inside the rectangle.
             painter.add(
-                RectShape::filled(rect, options.corner_radius, options.tint)
-                    .with_texture(texture.id, options.uv),
+                RectShape::new(
+                    rect,
+                    options.corner_radius,
+                    options.tint,
+                    options.border,
+                    StrokeKind::Inside,
+                )
+                .with_texture(texture.id, options.uv),
             );
         }
     }
@@ -970,3 +1092,37 @@ fn is_animated_image_uri(uri: &str) -> bool {
 fn are_animated_image_bytes(bytes: &[u8]) -> bool {
     has_gif_magic_header(bytes) || has_webp_header(bytes)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ImageFit, Rect, Vec2, pos2};
+
+    #[test]
+    fn test_fit_rect_and_uv() {
+        let rect = Rect::from_min_size(pos2(10.0, 20.0), Vec2::splat(100.0));
+        let full_uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+        let wide_image = Vec2::new(400.0, 200.0);
+
+        // Cover crops the sides equally, keeping the full height:
+        let (cover_rect, cover_uv) =
+            ImageFit::Cover(rect.size()).fit_rect_and_uv(rect, full_uv, wide_image);
+        assert_eq!(cover_rect, rect);
+        assert_eq!(
+            cover_uv,
+            Rect::from_min_max(pos2(0.25, 0.0), pos2(0.75, 1.0))
+        );
+
+        // Contain letterboxes, keeping the full width:
+        let (contain_rect, contain_uv) =
+            ImageFit::Contain(rect.size()).fit_rect_and_uv(rect, full_uv, wide_image);
+        assert_eq!(contain_uv, full_uv);
+        assert_eq!(
+            contain_rect,
+            Rect::from_min_max(pos2(10.0, 45.0), pos2(110.0, 95.0))
+        );
+
+        let (exact_rect, exact_uv) =
+            ImageFit::Exact(rect.size()).fit_rect_and_uv(rect, full_uv, wide_image);
+        assert_eq!((exact_rect, exact_uv), (rect, full_uv));
+    }
+}
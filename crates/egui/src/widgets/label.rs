@@ -1,8 +1,10 @@
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
 
 use crate::{
-    Align, Direction, FontSelection, Galley, Pos2, Response, Sense, Stroke, TextWrapMode, Ui,
-    Widget, WidgetInfo, WidgetText, WidgetType, epaint, pos2, text_selection::LabelSelectionState,
+    Align, Color32, CursorIcon, Direction, FontSelection, Galley, Pos2, Response, Sense, Stroke,
+    TextWrapMode, Ui, Widget, WidgetInfo, WidgetText, WidgetType, epaint, pos2,
+    text::{LayoutJob, LayoutSection},
+    text_selection::LabelSelectionState,
 };
 
 /// Static text.
@@ -29,6 +31,7 @@ pub struct Label {
     selectable: Option<bool>,
     halign: Option<Align>,
     show_tooltip_when_elided: bool,
+    auto_links: bool,
 }
 
 impl Label {
@@ -40,6 +43,7 @@ impl Label {
             selectable: None,
             halign: None,
             show_tooltip_when_elided: true,
+            auto_links: false,
         }
     }
 
@@ -67,6 +71,11 @@ impl Label {
     }
 
     /// Set [`Self::wrap_mode`] to [`TextWrapMode::Truncate`].
+    ///
+    /// The text is shown on a single row, and if it is too wide it is cut off with a `…`.
+    /// The full text is then shown on hover, unless you turn that off
+    /// with [`Self::show_tooltip_when_elided`].
+    #[doc(alias = "truncate_with_ellipsis")]
     #[inline]
     pub fn truncate(mut self) -> Self {
         self.wrap_mode = Some(TextWrapMode::Truncate);
@@ -119,6 +128,8 @@ impl Label {
 
     /// Show the full text when hovered, if the text was elided.
     ///
+    /// No tooltip is added if all of the text fit.
+    ///
     /// By default, this is true.
     ///
     /// ```
@@ -128,11 +139,32 @@ impl Label {
     ///     .on_hover_text("completely different text");
     /// # });
     /// ```
+    #[doc(alias = "show_tooltip_when_truncated")]
     #[inline]
     pub fn show_tooltip_when_elided(mut self, show: bool) -> Self {
         self.show_tooltip_when_elided = show;
         self
     }
+
+    /// Turn any `http://` and `https://` URLs in the text into clickable links.
+    ///
+    /// Clicking a link opens it with [`crate::Context::open_url`], just like a [`crate::Hyperlink`].
+    /// Punctuation at the end of a URL (like the period ending a sentence) is not part of the link.
+    ///
+    /// This has no effect if the label was given a pre-made [`Galley`].
+    ///
+    /// By default, this is false.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(egui::Label::new("Read more at https://www.egui.rs.").auto_links(true));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn auto_links(mut self, auto_links: bool) -> Self {
+        self.auto_links = auto_links;
+        self
+    }
 }
 
 impl Label {
@@ -186,6 +218,14 @@ impl Label {
             valign,
         ));
 
+        if self.auto_links {
+            let links = find_urls(&layout_job.text);
+            if !links.is_empty() {
+                color_links(&mut layout_job, &links, ui.visuals().hyperlink_color);
+                sense |= Sense::click();
+            }
+        }
+
         let available_width = ui.available_width();
 
         let wrap_mode = self.wrap_mode.unwrap_or_else(|| ui.wrap_mode());
@@ -274,11 +314,16 @@ impl Widget for Label {
 
         let selectable = self.selectable;
         let show_tooltip_when_elided = self.show_tooltip_when_elided;
+        let auto_links = self.auto_links && !matches!(self.text, WidgetText::Galley(_));
 
         let (galley_pos, galley, mut response) = self.layout_in_ui(ui);
         response
             .widget_info(|| WidgetInfo::labeled(WidgetType::Label, ui.is_enabled(), galley.text()));
 
+        if auto_links {
+            open_clicked_link(ui, &response, galley_pos, &galley);
+        }
+
         if ui.is_rect_visible(response.rect) {
             if show_tooltip_when_elided && galley.elided {
                 // Keep the sections and text, but reset everything else (especially wrapping):
@@ -324,3 +369,151 @@ impl Widget for Label {
         response
     }
 }
+
+/// Open the link under the pointer, if it was clicked,
+/// and show that the links can be clicked when hovered.
+fn open_clicked_link(ui: &Ui, response: &Response, galley_pos: Pos2, galley: &Galley) {
+    let Some(pointer_pos) = response.hover_pos() else {
+        return;
+    };
+    let local_pos = pointer_pos - galley_pos.to_vec2();
+    let Some(glyph) = galley.glyphs().find(|glyph| glyph.rect.contains(local_pos)) else {
+        return;
+    };
+    let text = galley.text();
+    let Some(link) = find_urls(text)
+        .into_iter()
+        .find(|link| link.contains(&glyph.byte_range.start))
+    else {
+        return;
+    };
+
+    ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+
+    if response.clicked_with_open_in_background() {
+        ui.ctx().open_url(crate::OpenUrl::new_tab(&text[link]));
+    } else if response.clicked() {
+        ui.ctx().open_url(crate::OpenUrl::same_tab(&text[link]));
+    }
+}
+
+/// The byte ranges of all `http://` and `https://` URLs in the text.
+///
+/// Trailing punctuation, like a period ending a sentence, is not included.
+fn find_urls(text: &str) -> Vec<Range<usize>> {
+    let mut urls = vec![];
+    let mut search_start = 0;
+
+    while let Some(offset) = text[search_start..].find("http") {
+        let start = search_start + offset;
+        let rest = &text[start..];
+
+        let scheme_len = if rest.starts_with("https://") {
+            "https://".len()
+        } else if rest.starts_with("http://") {
+            "http://".len()
+        } else {
+            0
+        };
+        let is_start_of_word = text[..start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_alphanumeric());
+        if scheme_len == 0 || !is_start_of_word {
+            search_start = start + "http".len();
+            continue;
+        }
+
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"'))
+            .unwrap_or(rest.len());
+        let mut url = &rest[..len];
+        while let Some(last) = url.chars().next_back() {
+            let is_trailing_punctuation = match last {
+                '.' | ',' | ';' | ':' | '!' | '?' | '\'' => true,
+                // Keep balanced parentheses, e.g. in Wikipedia links:
+                ')' => url.matches('(').count() < url.matches(')').count(),
+                ']' => url.matches('[').count() < url.matches(']').count(),
+                _ => false,
+            };
+            if !is_trailing_punctuation {
+                break;
+            }
+            url = &url[..url.len() - last.len_utf8()];
+        }
+
+        if scheme_len < url.len() {
+            urls.push(start..start + url.len());
+        }
+        search_start = start + len;
+    }
+
+    urls
+}
+
+/// Split the sections of the job so that the links get their own sections, with the given color.
+fn color_links(job: &mut LayoutJob, links: &[Range<usize>], color: Color32) {
+    let mut sections = Vec::with_capacity(job.sections.len() + 2 * links.len());
+
+    for section in std::mem::take(&mut job.sections) {
+        if section.byte_range.is_empty() {
+            sections.push(section);
+            continue;
+        }
+
+        let mut start = section.byte_range.start;
+        let mut leading_space = section.leading_space;
+        while start < section.byte_range.end {
+            let (end, is_link) = match links.iter().find(|link| start < link.end) {
+                Some(link) if link.start <= start => (link.end, true),
+                Some(link) => (link.start, false),
+                None => (section.byte_range.end, false),
+            };
+            let end = end.min(section.byte_range.end);
+
+            let mut format = section.format.clone();
+            if is_link {
+                format.color = color;
+            }
+            sections.push(LayoutSection {
+                leading_space,
+                byte_range: start..end,
+                format,
+            });
+
+            leading_space = 0.0;
+            start = end;
+        }
+    }
+
+    job.sections = sections;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_urls;
+
+    fn urls(text: &str) -> Vec<&str> {
+        find_urls(text)
+            .into_iter()
+            .map(|range| &text[range])
+            .collect()
+    }
+
+    #[test]
+    fn test_find_urls() {
+        assert_eq!(urls("no links here"), Vec::<&str>::new());
+        assert_eq!(urls("https://egui.rs"), vec!["https://egui.rs"]);
+        assert_eq!(
+            urls("See http://example.com/a?b=c, and https://egui.rs."),
+            vec!["http://example.com/a?b=c", "https://egui.rs"]
+        );
+        assert_eq!(
+            urls("(see https://en.wikipedia.org/wiki/Rust_(programming_language))"),
+            vec!["https://en.wikipedia.org/wiki/Rust_(programming_language)"]
+        );
+        assert_eq!(urls("Just https:// is not a link"), Vec::<&str>::new());
+        assert_eq!(urls("nothttps://egui.rs"), Vec::<&str>::new());
+        assert_eq!(urls("Åäö https://egui.rs/ü!"), vec!["https://egui.rs/ü"]);
+    }
+}
@@ -19,8 +19,10 @@ mod radio_button;
 mod selected_label;
 mod separator;
 mod slider;
+mod sparkline;
 mod spinner;
 pub mod text_edit;
+mod toggle_switch;
 
 #[expect(deprecated)]
 pub use self::selected_label::SelectableLabel;
@@ -40,8 +42,10 @@ pub use self::{
     radio_button::RadioButton,
     separator::Separator,
     slider::{Slider, SliderClamping, SliderOrientation},
+    sparkline::Sparkline,
     spinner::Spinner,
     text_edit::{TextBuffer, TextEdit},
+    toggle_switch::ToggleSwitch,
 };
 
 // ----------------------------------------------------------------------------
@@ -19,6 +19,7 @@ pub struct ProgressBar {
     text: Option<ProgressBarText>,
     fill: Option<Color32>,
     animate: bool,
+    indeterminate: bool,
     corner_radius: Option<CornerRadius>,
 }
 
@@ -32,6 +33,7 @@ impl ProgressBar {
             text: None,
             fill: None,
             animate: false,
+            indeterminate: false,
             corner_radius: None,
         }
     }
@@ -84,6 +86,17 @@ impl ProgressBar {
         self
     }
 
+    /// Show that something is happening, without knowing how far along it is.
+    ///
+    /// The progress is ignored, and instead a highlighted segment repeatedly sweeps across the bar.
+    /// Note that this will cause the UI to be redrawn while the bar is visible.
+    /// Defaults to `false`.
+    #[inline]
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
     /// Set the rounding of the progress bar.
     ///
     /// If [`Self::corner_radius`] and [`Self::animate`] are used simultaneously, the animation is not
@@ -111,10 +124,11 @@ impl Widget for ProgressBar {
             text,
             fill,
             animate,
+            indeterminate,
             corner_radius,
         } = self;
 
-        let animate = animate && progress < 1.0;
+        let animate = animate && progress < 1.0 && !indeterminate;
 
         let desired_width =
             desired_width.unwrap_or_else(|| ui.available_size_before_wrap().x.at_least(96.0));
@@ -128,13 +142,20 @@ impl Widget for ProgressBar {
             } else {
                 WidgetInfo::new(WidgetType::ProgressIndicator)
             };
-            info.value = Some((progress as f64 * 100.0).floor());
+            if !indeterminate {
+                info.value = Some((progress as f64 * 100.0).floor());
+            }
 
             info
         });
+        #[cfg(feature = "accesskit")]
+        if indeterminate {
+            ui.ctx()
+                .accesskit_node_builder(response.id, |builder| builder.set_busy());
+        }
 
         if ui.is_rect_visible(response.rect) {
-            if animate {
+            if animate || indeterminate {
                 ui.ctx().request_repaint();
             }
 
@@ -146,9 +167,18 @@ impl Widget for ProgressBar {
                 .rect_filled(outer_rect, corner_radius, visuals.extreme_bg_color);
             let min_width =
                 2.0 * f32::max(corner_radius.sw as _, corner_radius.nw as _).at_most(half_height);
-            let filled_width = (outer_rect.width() * progress).at_least(min_width);
-            let inner_rect =
-                Rect::from_min_size(outer_rect.min, vec2(filled_width, outer_rect.height()));
+            let inner_rect = if indeterminate {
+                // A segment sliding from just outside the left edge to just outside the right edge:
+                let segment_width = (outer_rect.width() / 3.0).at_least(min_width);
+                let seconds_per_sweep = 1.5;
+                let t = (ui.input(|i| i.time) / seconds_per_sweep).fract() as f32;
+                let left = lerp(outer_rect.left() - segment_width..=outer_rect.right(), t);
+                Rect::from_x_y_ranges(left..=left + segment_width, outer_rect.y_range())
+                    .intersect(outer_rect)
+            } else {
+                let filled_width = (outer_rect.width() * progress).at_least(min_width);
+                Rect::from_min_size(outer_rect.min, vec2(filled_width, outer_rect.height()))
+            };
 
             let (dark, bright) = (0.7, 1.0);
             let color_factor = if animate {
@@ -188,6 +218,7 @@ impl Widget for ProgressBar {
             if let Some(text_kind) = text {
                 let text = match text_kind {
                     ProgressBarText::Custom(text) => text,
+                    ProgressBarText::Percentage if indeterminate => WidgetText::default(),
                     ProgressBarText::Percentage => {
                         format!("{}%", (progress * 100.0) as usize).into()
                     }
@@ -20,6 +20,7 @@ type NumParser<'a> = Box<dyn 'a + Fn(&str) -> Option<f64>>;
 /// Combined into one function (rather than two) to make it easier
 /// for the borrow checker.
 type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
+type OnRelease<'a> = Box<dyn 'a + FnMut(f64)>;
 
 fn get(get_set_value: &mut GetSetValue<'_>) -> f64 {
     (get_set_value)(None)
@@ -35,8 +36,16 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
 struct SliderSpec {
     logarithmic: bool,
 
+    /// Set by [`Slider::log_base`]: use a logarithmic scale with this base,
+    /// with a linear band around zero.
+    ///
+    /// Takes precedence over `logarithmic`.
+    log_base: Option<f64>,
+
     /// For logarithmic sliders, the smallest positive value we are interested in.
     /// 1 for integer sliders, maybe 1e-6 for others.
+    ///
+    /// Values closer to zero than this are mapped linearly.
     smallest_positive: f64,
 
     /// For logarithmic sliders, the largest positive value we are interested in
@@ -118,6 +127,7 @@ pub struct Slider<'a> {
     trailing_fill: Option<bool>,
     handle_shape: Option<HandleShape>,
     update_while_editing: bool,
+    on_release: Option<OnRelease<'a>>,
 }
 
 impl<'a> Slider<'a> {
@@ -146,6 +156,7 @@ impl<'a> Slider<'a> {
             range,
             spec: SliderSpec {
                 logarithmic: false,
+                log_base: None,
                 smallest_positive: 1e-6,
                 largest_finite: f64::INFINITY,
             },
@@ -165,6 +176,7 @@ impl<'a> Slider<'a> {
             trailing_fill: None,
             handle_shape: None,
             update_while_editing: true,
+            on_release: None,
         }
     }
 
@@ -221,14 +233,50 @@ impl<'a> Slider<'a> {
     /// This is great for when the slider spans a huge range,
     /// e.g. from one to a million.
     /// The default is OFF.
+    ///
+    /// See also [`Self::log_base`].
     #[inline]
     pub fn logarithmic(mut self, logarithmic: bool) -> Self {
         self.spec.logarithmic = logarithmic;
+        if !logarithmic {
+            self.spec.log_base = None;
+        }
+        self
+    }
+
+    /// Make this a logarithmic slider with the given base,
+    /// so that each power of `log_base` takes up the same amount of space.
+    ///
+    /// The range may touch or cross zero:
+    /// values closer to zero than [`Self::smallest_positive`] are mapped linearly,
+    /// and that band takes up the same space as one power of `log_base` on each side of zero.
+    ///
+    /// Note that this differs from [`Self::logarithmic`] for ranges that touch or cross zero,
+    /// where [`Self::logarithmic`] instead splits the slider between the negative and positive side
+    /// based on the number of decades on each side.
+    ///
+    /// `log_base` must be greater than one.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut frequency = 440.0;
+    /// // Each octave takes up the same amount of space:
+    /// ui.add(egui::Slider::new(&mut frequency, 20.0..=20_000.0).log_base(2.0));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn log_base(mut self, log_base: f64) -> Self {
+        debug_assert!(
+            1.0 < log_base,
+            "Slider::log_base: base must be greater than one, but was {log_base}"
+        );
+        self.spec.log_base = Some(log_base);
         self
     }
 
     /// For logarithmic sliders that includes zero:
     /// what is the smallest positive value you want to be able to select?
+    /// Values closer to zero than this are mapped linearly.
     /// The default is `1` for integer sliders and `1e-6` for real sliders.
     #[inline]
     pub fn smallest_positive(mut self, smallest_positive: f64) -> Self {
@@ -649,6 +697,26 @@ impl<'a> Slider<'a> {
         self.update_while_editing = update;
         self
     }
+
+    /// Called when the user commits a new value, with the final value.
+    ///
+    /// Unlike [`Response::changed`], which is set on every frame the value moves,
+    /// this fires once per commit: when a drag (or click) on the slider is released,
+    /// when an arrow key is released, or when the user stops dragging or editing the value text.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut volume = 0.5;
+    /// ui.add(egui::Slider::new(&mut volume, 0.0..=1.0).on_release(|volume| {
+    ///     println!("Volume set to {volume}");
+    /// }));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn on_release(mut self, on_release: impl 'a + FnMut(f64)) -> Self {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
 }
 
 impl Slider<'_> {
@@ -661,8 +729,10 @@ impl Slider<'_> {
         ui.allocate_response(desired_size, Sense::drag())
     }
 
-    /// Just the slider, no text
-    fn slider_ui(&mut self, ui: &Ui, response: &Response) {
+    /// Just the slider, no text.
+    ///
+    /// Returns `true` if the value was committed using the keyboard or accessibility actions.
+    fn slider_ui(&mut self, ui: &Ui, response: &Response) -> bool {
         let rect = &response.rect;
         let handle_shape = self
             .handle_shape
@@ -685,6 +755,7 @@ impl Slider<'_> {
 
         let mut decrement = 0usize;
         let mut increment = 0usize;
+        let mut committed = false;
 
         if response.has_focus() {
             ui.ctx().memory_mut(|m| {
@@ -713,6 +784,7 @@ impl Slider<'_> {
             ui.input(|input| {
                 decrement += input.num_presses(dec_key);
                 increment += input.num_presses(inc_key);
+                committed |= input.key_released(dec_key) || input.key_released(inc_key);
             });
         }
 
@@ -720,8 +792,13 @@ impl Slider<'_> {
         {
             use accesskit::Action;
             ui.input(|input| {
-                decrement += input.num_accesskit_action_requests(response.id, Action::Decrement);
-                increment += input.num_accesskit_action_requests(response.id, Action::Increment);
+                let accesskit_decrement =
+                    input.num_accesskit_action_requests(response.id, Action::Decrement);
+                let accesskit_increment =
+                    input.num_accesskit_action_requests(response.id, Action::Increment);
+                committed |= accesskit_decrement + accesskit_increment > 0;
+                decrement += accesskit_decrement;
+                increment += accesskit_increment;
             });
         }
 
@@ -766,6 +843,7 @@ impl Slider<'_> {
                 for request in input.accesskit_action_requests(response.id, Action::SetValue) {
                     if let Some(ActionData::NumericValue(new_value)) = request.data {
                         self.set_value(new_value);
+                        committed = true;
                     }
                 }
             });
@@ -846,6 +924,8 @@ impl Slider<'_> {
                 }
             }
         }
+
+        committed
     }
 
     fn marker_center(&self, position_1d: f32, rail_rect: &Rect) -> Pos2 {
@@ -970,7 +1050,8 @@ impl Slider<'_> {
             .text_style_height(&TextStyle::Body)
             .at_least(ui.spacing().interact_size.y);
         let mut response = self.allocate_slider_space(ui, thickness);
-        self.slider_ui(ui, &response);
+        let mut committed = self.slider_ui(ui, &response);
+        committed |= response.drag_stopped();
 
         let value = self.get_value();
         if value != old_value {
@@ -1009,6 +1090,7 @@ impl Slider<'_> {
                 .unwrap_or_else(|| ui.style().visuals.handle_shape);
             let position_range = self.position_range(&response.rect, &handle_shape);
             let value_response = self.value_ui(ui, position_range);
+            committed |= value_response.drag_stopped() || value_response.lost_focus();
             if value_response.gained_focus()
                 || value_response.has_focus()
                 || value_response.lost_focus()
@@ -1038,6 +1120,13 @@ impl Slider<'_> {
             }
         }
 
+        if committed {
+            let value = self.get_value();
+            if let Some(on_release) = &mut self.on_release {
+                on_release(value);
+            }
+        }
+
         response
     }
 }
@@ -1078,6 +1167,10 @@ fn value_from_normalized(normalized: f64, range: RangeInclusive<f64>, spec: &Sli
         min
     } else if normalized >= 1.0 {
         max
+    } else if let Some(log_base) = spec.log_base {
+        let (min_log, max_log) = log_range(min, max, log_base, spec);
+        let log = lerp(min_log..=max_log, normalized);
+        value_from_log(log, log_base, spec)
     } else if spec.logarithmic {
         if max <= 0.0 {
             // non-positive range
@@ -1130,6 +1223,10 @@ fn normalized_from_value(value: f64, range: RangeInclusive<f64>, spec: &SliderSp
         0.0
     } else if value >= max {
         1.0
+    } else if let Some(log_base) = spec.log_base {
+        let (min_log, max_log) = log_range(min, max, log_base, spec);
+        let value_log = log_from_value(value, log_base, spec);
+        remap_clamp(value_log, min_log..=max_log, 0.0..=1.0)
     } else if spec.logarithmic {
         if max <= 0.0 {
             // non-positive range
@@ -1169,6 +1266,57 @@ fn normalized_from_value(value: f64, range: RangeInclusive<f64>, spec: &SliderSp
     }
 }
 
+/// Where on the logarithmic scale is this value?
+///
+/// Magnitudes above `smallest_positive` are logarithmic,
+/// with one unit per power of `log_base`.
+/// The band `-smallest_positive..=smallest_positive` around zero is linear,
+/// and is given one unit on each side of zero.
+/// This makes the scale continuous and symmetric, so ranges can touch or cross zero.
+fn log_from_value(value: f64, log_base: f64, spec: &SliderSpec) -> f64 {
+    let magnitude = value.abs();
+    if magnitude < spec.smallest_positive {
+        value / spec.smallest_positive
+    } else {
+        value.signum() * (1.0 + (magnitude / spec.smallest_positive).log(log_base))
+    }
+}
+
+/// Inverse of [`log_from_value`].
+fn value_from_log(log: f64, log_base: f64, spec: &SliderSpec) -> f64 {
+    if log.abs() < 1.0 {
+        log * spec.smallest_positive
+    } else {
+        log.signum() * spec.smallest_positive * log_base.powf(log.abs() - 1.0)
+    }
+}
+
+/// The range on the logarithmic scale, with infinities replaced by something finite.
+fn log_range(min: f64, max: f64, log_base: f64, spec: &SliderSpec) -> (f64, f64) {
+    assert!(
+        min <= max,
+        "min must be less than or equal to max, but was min={min} and max={max}"
+    );
+
+    let log = |value: f64| log_from_value(value, log_base, spec);
+
+    let max_log = if max < INFINITY {
+        log(max)
+    } else if min < spec.largest_finite && spec.largest_finite < INFINITY {
+        log(spec.largest_finite)
+    } else {
+        log(min.at_least(1.0)) + INF_RANGE_MAGNITUDE
+    };
+    let min_log = if -INFINITY < min {
+        log(min)
+    } else if -spec.largest_finite < max && spec.largest_finite < INFINITY {
+        -log(spec.largest_finite)
+    } else {
+        -log((-max).at_least(1.0)) - INF_RANGE_MAGNITUDE
+    };
+    (min_log, max_log)
+}
+
 fn range_log10(min: f64, max: f64, spec: &SliderSpec) -> (f64, f64) {
     assert!(spec.logarithmic, "spec must be logarithmic");
     assert!(
@@ -1221,3 +1369,129 @@ fn logarithmic_zero_cutoff(min: f64, max: f64) -> f64 {
     );
     cutoff
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{SliderSpec, normalized_from_value, value_from_normalized};
+
+    fn log_spec(log_base: f64, smallest_positive: f64) -> SliderSpec {
+        SliderSpec {
+            logarithmic: false,
+            log_base: Some(log_base),
+            smallest_positive,
+            largest_finite: f64::INFINITY,
+        }
+    }
+
+    fn assert_approx_eq(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-12,
+            "Expected {expected}, got {actual}"
+        );
+    }
+
+    fn assert_round_trip(values: &[f64], range: std::ops::RangeInclusive<f64>, spec: &SliderSpec) {
+        let mut last_normalized = -1.0;
+        for &value in values {
+            let normalized = normalized_from_value(value, range.clone(), spec);
+            assert!(
+                last_normalized < normalized,
+                "{value} should be to the right of the previous value"
+            );
+            last_normalized = normalized;
+
+            let round_trip = value_from_normalized(normalized, range.clone(), spec);
+            assert!(
+                (round_trip - value).abs() <= 1e-9 * value.abs().max(1.0),
+                "{value} round-tripped to {round_trip}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_logarithmic_keeps_decade_split_around_zero() {
+        let spec = SliderSpec {
+            logarithmic: true,
+            log_base: None,
+            smallest_positive: 1e-6,
+            largest_finite: f64::INFINITY,
+        };
+
+        // From zero, the track starts at `smallest_positive`, without a linear band:
+        let range = 0.0..=1.0;
+        assert_approx_eq(normalized_from_value(1e-3, range.clone(), &spec), 0.5);
+
+        // Crossing zero, the split is based on the number of decades on each side:
+        let range = -10.0..=1000.0;
+        assert_approx_eq(normalized_from_value(0.0, range.clone(), &spec), 0.25);
+        assert_round_trip(&[-10.0, -1.0, 0.0, 1.0, 42.0, 1000.0], range, &spec);
+    }
+
+    #[test]
+    fn test_log_slider_from_zero() {
+        let range = 0.0..=1000.0;
+        let spec = log_spec(10.0, 1.0);
+
+        // The linear band 0-1, then one decade each for 1-10, 10-100 and 100-1000:
+        assert_approx_eq(normalized_from_value(0.0, range.clone(), &spec), 0.0);
+        assert_approx_eq(normalized_from_value(0.5, range.clone(), &spec), 0.125);
+        assert_approx_eq(normalized_from_value(1.0, range.clone(), &spec), 0.25);
+        assert_approx_eq(normalized_from_value(10.0, range.clone(), &spec), 0.5);
+        assert_approx_eq(normalized_from_value(1000.0, range.clone(), &spec), 1.0);
+
+        assert_round_trip(&[0.0, 0.25, 1.0, 3.0, 42.0, 999.0, 1000.0], range, &spec);
+    }
+
+    #[test]
+    fn test_log_slider_base() {
+        let range = 0.0..=1024.0;
+        let spec = log_spec(2.0, 1.0);
+
+        // A linear band, then ten octaves:
+        assert_approx_eq(normalized_from_value(1.0, range.clone(), &spec), 1.0 / 11.0);
+        assert_approx_eq(
+            normalized_from_value(32.0, range.clone(), &spec),
+            6.0 / 11.0,
+        );
+
+        assert_round_trip(&[0.0, 0.5, 1.0, 3.0, 42.0, 1000.0], range, &spec);
+    }
+
+    #[test]
+    fn test_log_slider_crossing_zero() {
+        let range = -100.0..=100.0;
+        let spec = log_spec(10.0, 1e-6);
+
+        assert_approx_eq(normalized_from_value(0.0, range.clone(), &spec), 0.5);
+        assert_approx_eq(value_from_normalized(0.5, range.clone(), &spec), 0.0);
+
+        for value in [1e-7, 1e-6, 0.1, 1.0, 50.0] {
+            let positive = normalized_from_value(value, range.clone(), &spec);
+            let negative = normalized_from_value(-value, range.clone(), &spec);
+            assert!(
+                (positive + negative - 1.0).abs() < 1e-12,
+                "{value} and -{value} should be symmetric around the center"
+            );
+        }
+
+        assert_round_trip(
+            &[-100.0, -3.0, -1e-6, -1e-7, 0.0, 1e-7, 1e-6, 3.0, 100.0],
+            range,
+            &spec,
+        );
+    }
+
+    #[test]
+    fn test_log_slider_infinite_range() {
+        let spec = log_spec(10.0, 1e-6);
+        for range in [0.0..=f64::INFINITY, f64::NEG_INFINITY..=f64::INFINITY] {
+            assert_eq!(
+                value_from_normalized(1.0, range.clone(), &spec),
+                f64::INFINITY
+            );
+            let almost_max = value_from_normalized(0.99, range.clone(), &spec);
+            assert!(almost_max.is_finite(), "Got {almost_max} for {range:?}");
+            assert_round_trip(&[0.0, 1.0, 1e6], range, &spec);
+        }
+    }
+}
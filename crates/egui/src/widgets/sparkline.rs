@@ -0,0 +1,243 @@
+use epaint::{
+    Color32, Mesh, Pos2, Rangef, Rect, Shape, Stroke,
+    emath::{lerp, remap_clamp},
+    vec2,
+};
+
+use crate::{Response, Sense, Ui, Vec2, Widget, WidgetInfo, WidgetType};
+
+/// A tiny inline line chart, e.g. for showing a trend in a table cell.
+///
+/// The values are spread out evenly over the width,
+/// and scaled to their min/max (or [`Self::y_range`]) over the height.
+/// Non-finite values (e.g. missing samples stored as NaN) leave a gap in the line.
+///
+/// This is meant to be cheap enough to show hundreds of them per frame.
+/// For anything more advanced, use a proper plotting crate.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let values = [1.0, 3.0, 2.0, 5.0, 4.0];
+/// ui.add(
+///     egui::Sparkline::new(&values)
+///         .size(egui::vec2(60.0, 16.0))
+///         .fill(egui::Color32::from_rgba_unmultiplied(100, 150, 250, 60))
+///         .highlight_last(true),
+/// );
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Sparkline<'a> {
+    values: &'a [f32],
+    size: Option<Vec2>,
+    stroke: Option<Stroke>,
+    fill: Option<Color32>,
+    highlight_last: bool,
+    y_range: Option<Rangef>,
+}
+
+impl<'a> Sparkline<'a> {
+    pub fn new(values: &'a [f32]) -> Self {
+        Self {
+            values,
+            size: None,
+            stroke: None,
+            fill: None,
+            highlight_last: false,
+            y_range: None,
+        }
+    }
+
+    /// The exact size of the sparkline.
+    ///
+    /// Defaults to four times the style's `interact_size.y` wide, and `interact_size.y` tall.
+    #[inline]
+    pub fn size(mut self, size: Vec2) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// The stroke of the line.
+    ///
+    /// Defaults to the foreground stroke of non-interactive widgets.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// Fill the area under the line with this color.
+    ///
+    /// Default: no fill.
+    #[inline]
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = Some(fill.into());
+        self
+    }
+
+    /// Mark the last value with a dot.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn highlight_last(mut self, highlight_last: bool) -> Self {
+        self.highlight_last = highlight_last;
+        self
+    }
+
+    /// Use a fixed range of values for the height, instead of the min/max of the values.
+    ///
+    /// Values outside of this range are clamped to the edges.
+    /// If `min > max` the range is flipped around, and a non-finite range is ignored.
+    #[inline]
+    pub fn y_range(mut self, y_range: impl Into<Rangef>) -> Self {
+        self.y_range = Some(y_range.into());
+        self
+    }
+
+    /// The range of values that is mapped to the height of the sparkline.
+    fn resolved_y_range(&self) -> Option<Rangef> {
+        let y_range = self
+            .y_range
+            .filter(|range| range.min.is_finite() && range.max.is_finite())
+            .map(|range| Rangef::new(range.min.min(range.max), range.min.max(range.max)));
+        let range = y_range.or_else(|| {
+            let mut finite_values = self.values.iter().copied().filter(|v| v.is_finite());
+            let first = finite_values.next()?;
+            Some(finite_values.fold(Rangef::point(first), |range, value| {
+                Rangef::new(range.min.min(value), range.max.max(value))
+            }))
+        })?;
+
+        if range.span() > 0.0 {
+            Some(range)
+        } else {
+            // All values are the same; draw a flat line in the middle.
+            Some(range.expand(0.5))
+        }
+    }
+
+    /// The points of the line in the given rectangle,
+    /// split into separate runs wherever there is a non-finite value.
+    fn runs(&self, rect: Rect, y_range: Rangef) -> Vec<Vec<Pos2>> {
+        let last_index = self.values.len().saturating_sub(1).max(1) as f32;
+        let mut runs = vec![];
+        let mut run = vec![];
+        for (i, &value) in self.values.iter().enumerate() {
+            if !value.is_finite() {
+                if !run.is_empty() {
+                    runs.push(std::mem::take(&mut run));
+                }
+                continue;
+            }
+            let x = if self.values.len() == 1 {
+                rect.center().x
+            } else {
+                lerp(rect.left()..=rect.right(), i as f32 / last_index)
+            };
+            let y = remap_clamp(value, y_range.into(), rect.bottom()..=rect.top());
+            run.push(Pos2::new(x, y));
+        }
+        if !run.is_empty() {
+            runs.push(run);
+        }
+        runs
+    }
+
+    /// Paint the sparkline in the given rectangle.
+    pub fn paint_at(&self, ui: &Ui, rect: Rect) {
+        if !ui.is_rect_visible(rect) {
+            return;
+        }
+        let Some(y_range) = self.resolved_y_range() else {
+            return;
+        };
+
+        let stroke = self
+            .stroke
+            .unwrap_or(ui.visuals().widgets.noninteractive.fg_stroke);
+
+        let runs = self.runs(rect, y_range);
+
+        let painter = ui.painter();
+
+        if let Some(fill) = self.fill {
+            // The area under the line isn't convex, so build it out of one quad per segment.
+            let mut mesh = Mesh::default();
+            for points in &runs {
+                let idx0 = mesh.vertices.len() as u32;
+                mesh.reserve_vertices(2 * points.len());
+                mesh.reserve_triangles(2 * points.len().saturating_sub(1));
+                for (i, point) in points.iter().enumerate() {
+                    mesh.colored_vertex(*point, fill);
+                    mesh.colored_vertex(Pos2::new(point.x, rect.bottom()), fill);
+                    if i > 0 {
+                        let idx = idx0 + 2 * i as u32;
+                        mesh.add_triangle(idx - 2, idx - 1, idx);
+                        mesh.add_triangle(idx - 1, idx, idx + 1);
+                    }
+                }
+            }
+            painter.add(Shape::mesh(mesh));
+        }
+
+        let last_point = runs.last().and_then(|points| points.last()).copied();
+        for points in runs {
+            if points.len() > 1 {
+                painter.line(points, stroke);
+            }
+        }
+
+        if self.highlight_last {
+            if let Some(last_point) = last_point {
+                painter.circle_filled(last_point, stroke.width + 1.0, stroke.color);
+            }
+        }
+    }
+}
+
+impl Widget for Sparkline<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let size = self.size.unwrap_or_else(|| {
+            let height = ui.style().spacing.interact_size.y;
+            vec2(4.0 * height, height)
+        });
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        response.widget_info(|| WidgetInfo::new(WidgetType::Image));
+        self.paint_at(ui, rect);
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sparkline;
+    use epaint::{Rangef, Rect, pos2};
+
+    #[test]
+    fn test_non_finite_values_split_the_line() {
+        let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(40.0, 10.0));
+        let values = [0.0, 1.0, f32::NAN, 1.0, f32::INFINITY, f32::NAN, 0.0, 1.0];
+        let sparkline = Sparkline::new(&values);
+        let y_range = sparkline.resolved_y_range().unwrap();
+        assert_eq!(y_range, Rangef::new(0.0, 1.0));
+
+        let runs = sparkline.runs(rect, y_range);
+        let xs: Vec<Vec<f32>> = runs
+            .iter()
+            .map(|points| points.iter().map(|p| (p.x * 7.0 / 40.0).round()).collect())
+            .collect();
+        assert_eq!(xs, vec![vec![0.0, 1.0], vec![3.0], vec![6.0, 7.0]]);
+    }
+
+    #[test]
+    fn test_flipped_y_range_is_sorted() {
+        let values = [0.0, 1.0];
+        let sparkline = Sparkline::new(&values).y_range(Rangef::new(2.0, -2.0));
+        assert_eq!(sparkline.resolved_y_range(), Some(Rangef::new(-2.0, 2.0)));
+
+        // A NaN range falls back to the range of the values:
+        let sparkline = Sparkline::new(&values).y_range(Rangef::new(f32::NAN, 1.0));
+        assert_eq!(sparkline.resolved_y_range(), Some(Rangef::new(0.0, 1.0)));
+    }
+}
@@ -11,6 +11,10 @@ pub struct Spinner {
     /// Uses the style's `interact_size` if `None`.
     size: Option<f32>,
     color: Option<Color32>,
+    /// One turn per second if `None`.
+    speed: Option<f32>,
+    /// Oscillates if `None`.
+    arc_fraction: Option<f32>,
 }
 
 impl Spinner {
@@ -34,6 +38,25 @@ impl Spinner {
         self
     }
 
+    /// Sets how fast the spinner rotates, in radians per second.
+    ///
+    /// The default is one full turn per second (`TAU`).
+    /// Negative values spin counter-clockwise.
+    #[inline]
+    pub fn speed(mut self, radians_per_sec: f32) -> Self {
+        self.speed = Some(radians_per_sec);
+        self
+    }
+
+    /// Sets how much of the circle is visible, in the `0..=1` range.
+    ///
+    /// By default the arc grows and shrinks over time.
+    #[inline]
+    pub fn arc_fraction(mut self, arc_fraction: f32) -> Self {
+        self.arc_fraction = Some(arc_fraction.clamp(0.0, 1.0));
+        self
+    }
+
     /// Paint the spinner in the given rectangle.
     pub fn paint_at(&self, ui: &Ui, rect: Rect) {
         if ui.is_rect_visible(rect) {
@@ -45,8 +68,13 @@ impl Spinner {
             let radius = (rect.height() / 2.0) - 2.0;
             let n_points = (radius.round() as u32).clamp(8, 128);
             let time = ui.input(|i| i.time);
-            let start_angle = time * std::f64::consts::TAU;
-            let end_angle = start_angle + 240f64.to_radians() * time.sin();
+            let speed = self.speed.map_or(std::f64::consts::TAU, f64::from);
+            let start_angle = time * speed;
+            let arc_angle = match self.arc_fraction {
+                Some(arc_fraction) => std::f64::consts::TAU * arc_fraction as f64,
+                None => 240f64.to_radians() * time.sin(),
+            };
+            let end_angle = start_angle + arc_angle;
             let points: Vec<Pos2> = (0..n_points)
                 .map(|i| {
                     let angle = lerp(start_angle..=end_angle, i as f64 / n_points as f64);
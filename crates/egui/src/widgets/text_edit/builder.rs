@@ -7,9 +7,10 @@ use epaint::{
 };
 
 use crate::{
-    Align, Align2, Color32, Context, CursorIcon, Event, EventFilter, FontSelection, Id, ImeEvent,
-    Key, KeyboardShortcut, Margin, Modifiers, NumExt as _, Response, Sense, Shape, TextBuffer,
-    TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, WidgetWithState, epaint,
+    Align, Align2, Color32, Context, CursorIcon, CursorIconPriority, Event, EventFilter,
+    FontSelection, Id, ImeEvent, Key, KeyboardShortcut, Margin, Modifiers, NumExt as _, Response,
+    Sense, Shape, TextBuffer, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText,
+    WidgetWithState, epaint,
     os::OperatingSystem,
     output::OutputEvent,
     response, text_selection,
@@ -89,6 +90,10 @@ pub struct TextEdit<'t> {
     char_limit: usize,
     return_key: Option<KeyboardShortcut>,
     background_color: Option<Color32>,
+    on_change: Option<Box<dyn FnMut(&str) + 't>>,
+    char_filter: Option<Box<dyn Fn(char) -> bool + 't>>,
+    select_all_on_focus: bool,
+    select_all_on_click_focus: bool,
 }
 
 impl WidgetWithState for TextEdit<'_> {
@@ -148,6 +153,10 @@ impl<'t> TextEdit<'t> {
             char_limit: usize::MAX,
             return_key: Some(KeyboardShortcut::new(Modifiers::NONE, Key::Enter)),
             background_color: None,
+            on_change: None,
+            char_filter: None,
+            select_all_on_focus: false,
+            select_all_on_click_focus: false,
         }
     }
 
@@ -341,6 +350,31 @@ impl<'t> TextEdit<'t> {
         self
     }
 
+    /// When `true`, all text is selected when the [`TextEdit`] gains focus from the keyboard
+    /// (e.g. by pressing Tab) or from [`Response::request_focus`],
+    /// so that typing replaces the contents.
+    ///
+    /// This only happens on the frame the focus is gained, not while it is kept.
+    /// Gaining focus by clicking is controlled separately by [`Self::select_all_on_click_focus`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn select_all_on_focus(mut self, select_all_on_focus: bool) -> Self {
+        self.select_all_on_focus = select_all_on_focus;
+        self
+    }
+
+    /// When `true`, all text is selected when the [`TextEdit`] gains focus by being clicked.
+    ///
+    /// When `false` (default), the cursor is placed where the user clicked.
+    ///
+    /// See also [`Self::select_all_on_focus`].
+    #[inline]
+    pub fn select_all_on_click_focus(mut self, select_all_on_click_focus: bool) -> Self {
+        self.select_all_on_click_focus = select_all_on_click_focus;
+        self
+    }
+
     /// When `true` (default), overflowing text will be clipped.
     ///
     /// When `false`, widget width will expand to make all text visible.
@@ -396,6 +430,45 @@ impl<'t> TextEdit<'t> {
         self.return_key = return_key.into();
         self
     }
+
+    /// Called with the new text whenever the user changes it.
+    ///
+    /// This is called after the edit has been applied, on the same frames as [`Response::changed`]
+    /// (typing, pasting, cutting, committed IME input etc.), but not when only the cursor moves
+    /// or the focus changes.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_string = String::new();
+    /// let mut search_query = None;
+    /// ui.add(egui::TextEdit::singleline(&mut my_string).on_change(|text| {
+    ///     search_query = Some(text.to_owned());
+    /// }));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn on_change(mut self, on_change: impl FnMut(&str) + 't) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Only allow inserting characters for which the filter returns `true`.
+    ///
+    /// The filter is applied to each character as it is typed, pasted, or committed by an IME,
+    /// so that only the allowed characters are inserted (and the cursor ends up after them).
+    /// Text that is already in the buffer is not affected.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_string = String::new();
+    /// ui.add(egui::TextEdit::singleline(&mut my_string).char_filter(|c| c.is_ascii_hexdigit()));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn char_filter(mut self, char_filter: impl Fn(char) -> bool + 't) -> Self {
+        self.char_filter = Some(Box::new(char_filter));
+        self
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -493,6 +566,10 @@ impl TextEdit<'_> {
             char_limit,
             return_key,
             background_color: _,
+            mut on_change,
+            char_filter,
+            select_all_on_focus,
+            select_all_on_click_focus,
         } = self;
 
         let text_color = text_color
@@ -573,6 +650,7 @@ impl TextEdit<'_> {
         let text_clip_rect = rect;
         let painter = ui.painter_at(text_clip_rect.expand(1.0)); // expand to avoid clipping cursor
 
+        let mut focused_by_pointer = false;
         if interactive {
             if let Some(pointer_pos) = response.interact_pointer_pos() {
                 if response.hovered() && text.is_mutable() {
@@ -605,6 +683,7 @@ impl TextEdit<'_> {
 
                 if did_interact || response.clicked() {
                     ui.memory_mut(|mem| mem.request_focus(response.id));
+                    focused_by_pointer = true;
 
                     state.last_interaction_time = ui.ctx().input(|i| i.time);
                 }
@@ -612,7 +691,21 @@ impl TextEdit<'_> {
         }
 
         if interactive && response.hovered() {
-            ui.ctx().set_cursor_icon(CursorIcon::Text);
+            ui.ctx()
+                .set_cursor_icon_with_priority(CursorIcon::Text, CursorIconPriority::TEXT);
+        }
+
+        if interactive && ui.memory(|mem| mem.gained_focus(id)) {
+            let select_all = if focused_by_pointer {
+                select_all_on_click_focus
+            } else {
+                select_all_on_focus
+            };
+            if select_all {
+                state
+                    .cursor
+                    .set_char_range(Some(CCursorRange::select_all(&galley)));
+            }
         }
 
         let mut cursor_range = None;
@@ -638,12 +731,16 @@ impl TextEdit<'_> {
                 password,
                 default_cursor_range,
                 char_limit,
+                char_filter.as_deref(),
                 event_filter,
                 return_key,
             );
 
             if changed {
                 response.mark_changed();
+                if let Some(on_change) = &mut on_change {
+                    on_change(text.as_str());
+                }
             }
             cursor_range = Some(new_cursor_range);
         }
@@ -914,6 +1011,7 @@ fn events(
     password: bool,
     default_cursor_range: CCursorRange,
     char_limit: usize,
+    char_filter: Option<&dyn Fn(char) -> bool>,
     event_filter: EventFilter,
     return_key: Option<KeyboardShortcut>,
 ) -> (bool, CCursorRange) {
@@ -934,6 +1032,14 @@ fn events(
         }
     };
 
+    let filter_chars = |text: &str| -> String {
+        if let Some(char_filter) = char_filter {
+            text.chars().filter(|&c| char_filter(c)).collect()
+        } else {
+            text.to_owned()
+        }
+    };
+
     let mut any_change = false;
 
     let mut events = ui.input(|i| i.filtered_events(&event_filter));
@@ -964,14 +1070,14 @@ fn events(
                 }
             }
             Event::Paste(text_to_insert) => {
+                let text_to_insert = if multiline {
+                    filter_chars(text_to_insert)
+                } else {
+                    filter_chars(&text_to_insert.replace(['\r', '\n'], " "))
+                };
                 if !text_to_insert.is_empty() {
                     let mut ccursor = text.delete_selected(&cursor_range);
-                    if multiline {
-                        text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
-                    } else {
-                        let single_line = text_to_insert.replace(['\r', '\n'], " ");
-                        text.insert_text_at(&mut ccursor, &single_line, char_limit);
-                    }
+                    text.insert_text_at(&mut ccursor, &text_to_insert, char_limit);
 
                     Some(CCursorRange::one(ccursor))
                 } else {
@@ -980,10 +1086,11 @@ fn events(
             }
             Event::Text(text_to_insert) => {
                 // Newlines are handled by `Key::Enter`.
+                let text_to_insert = filter_chars(text_to_insert);
                 if !text_to_insert.is_empty() && text_to_insert != "\n" && text_to_insert != "\r" {
                     let mut ccursor = text.delete_selected(&cursor_range);
 
-                    text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
+                    text.insert_text_at(&mut ccursor, &text_to_insert, char_limit);
 
                     Some(CCursorRange::one(ccursor))
                 } else {
@@ -1102,8 +1209,13 @@ fn events(
                             && cursor_range.secondary.index
                                 == state.ime_cursor_range.secondary.index
                         {
+                            // Replace the preedit text with the (filtered) committed text:
                             let mut ccursor = text.delete_selected(&cursor_range);
-                            text.insert_text_at(&mut ccursor, prediction, char_limit);
+                            text.insert_text_at(
+                                &mut ccursor,
+                                &filter_chars(prediction),
+                                char_limit,
+                            );
                             Some(CCursorRange::one(ccursor))
                         } else {
                             let ccursor = cursor_range.primary;
@@ -23,4 +23,77 @@ pub struct TextEditOutput {
     pub cursor_range: Option<CCursorRange>,
 }
 
+impl TextEditOutput {
+    /// The number of visual rows the text was laid out in,
+    /// including rows created by soft-wrapping.
+    pub fn wrapped_row_count(&self) -> usize {
+        self.galley.rows.len()
+    }
+
+    /// For each logical line of the text (separated by `\n`),
+    /// the range of [`crate::Galley::rows`] it was laid out in.
+    ///
+    /// A line that was soft-wrapped spans more than one row.
+    /// This is useful for e.g. showing line numbers next to a code editor:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut code = "fn main() {\n}\n".to_owned();
+    /// let output = egui::TextEdit::multiline(&mut code).show(ui);
+    /// let line_rows = output.logical_line_rows();
+    /// assert_eq!(line_rows.len(), 3);
+    /// for (line_index, rows) in line_rows.into_iter().enumerate() {
+    ///     let first_row = &output.galley.rows[rows.start];
+    ///     let y = output.galley_pos.y + first_row.pos.y;
+    ///     // Paint `line_index + 1` at `y` in the gutter…
+    /// }
+    /// # });
+    /// ```
+    pub fn logical_line_rows(&self) -> Vec<std::ops::Range<usize>> {
+        let rows = &self.galley.rows;
+        let mut line_rows = vec![];
+        let mut start = 0;
+        for (i, row) in rows.iter().enumerate() {
+            if row.ends_with_newline {
+                line_rows.push(start..i + 1);
+                start = i + 1;
+            }
+        }
+        line_rows.push(start..rows.len());
+        line_rows
+    }
+}
+
 // TODO(emilk): add `output.paint` and `output.store` and split out that code from `TextEdit::show`.
+
+#[cfg(test)]
+mod tests {
+    use crate::{CentralPanel, Context, TextEdit};
+
+    #[test]
+    fn test_logical_line_rows() {
+        let ctx = Context::default();
+        let mut line_rows = vec![];
+        let mut wrapped_row_count = 0;
+        let mut last_row_is_empty = false;
+        let _ = ctx.run(Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let mut text = "Short\nA line that is long enough to be wrapped\n".to_owned();
+                let output = TextEdit::multiline(&mut text).desired_width(100.0).show(ui);
+                line_rows = output.logical_line_rows();
+                wrapped_row_count = output.wrapped_row_count();
+                last_row_is_empty = output.galley.rows.last().unwrap().glyphs.is_empty();
+            });
+        });
+
+        // The trailing newline starts an empty last line:
+        assert_eq!(line_rows.len(), 3, "{line_rows:?}");
+        assert_eq!(line_rows[0], 0..1);
+        assert_eq!(line_rows[1].start, 1);
+        assert!(1 < line_rows[1].len(), "The second line should wrap");
+        assert_eq!(line_rows[2], line_rows[1].end..line_rows[1].end + 1);
+        assert!(last_row_is_empty);
+
+        assert_eq!(wrapped_row_count, line_rows[2].end);
+    }
+}
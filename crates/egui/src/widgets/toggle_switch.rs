@@ -0,0 +1,130 @@
+use crate::{
+    Atom, AtomLayout, Atoms, Color32, Id, IntoAtoms, Response, Sense, StrokeKind, Ui, Widget,
+    WidgetInfo, WidgetType, lerp, pos2, vec2,
+};
+
+/// iOS-style toggle switch, for turning something on or off.
+///
+/// Usually you'd use [`Ui::toggle_switch`] instead.
+///
+/// It works like a [`crate::Checkbox`]: click it (or its label), or focus it and press space, to toggle it.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_bool = true;
+/// // These are equivalent:
+/// ui.toggle_switch(&mut my_bool, "Dark mode");
+/// ui.add(egui::ToggleSwitch::new(&mut my_bool, "Dark mode"));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct ToggleSwitch<'a> {
+    on: &'a mut bool,
+    atoms: Atoms<'a>,
+    on_color: Option<Color32>,
+    off_color: Option<Color32>,
+}
+
+impl<'a> ToggleSwitch<'a> {
+    pub fn new(on: &'a mut bool, atoms: impl IntoAtoms<'a>) -> Self {
+        Self {
+            on,
+            atoms: atoms.into_atoms(),
+            on_color: None,
+            off_color: None,
+        }
+    }
+
+    /// A switch without a label.
+    ///
+    /// Consider labelling it for screen readers anyway,
+    /// e.g. with [`Response::labelled_by`] if there is a label next to it.
+    pub fn without_text(on: &'a mut bool) -> Self {
+        Self::new(on, ())
+    }
+
+    /// The color of the track when the switch is on.
+    ///
+    /// By default, the selection color of the style is used.
+    #[inline]
+    pub fn on_color(mut self, on_color: impl Into<Color32>) -> Self {
+        self.on_color = Some(on_color.into());
+        self
+    }
+
+    /// The color of the track when the switch is off.
+    ///
+    /// By default, the background color of inactive widgets is used.
+    #[inline]
+    pub fn off_color(mut self, off_color: impl Into<Color32>) -> Self {
+        self.off_color = Some(off_color.into());
+        self
+    }
+}
+
+impl Widget for ToggleSwitch<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            on,
+            mut atoms,
+            on_color,
+            off_color,
+        } = self;
+
+        let switch_size = ui.spacing().interact_size.y * vec2(2.0, 1.0);
+        let switch_id = Id::new("egui::toggle_switch");
+        atoms.push_left(Atom::custom(switch_id, switch_size));
+
+        let text = atoms.text().map(String::from);
+
+        let mut prepared = AtomLayout::new(atoms)
+            .sense(Sense::click())
+            .min_size(switch_size)
+            .allocate(ui);
+
+        if prepared.response.clicked() {
+            *on = !*on;
+            prepared.response.mark_changed();
+        }
+        prepared.response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::ToggleSwitch,
+                ui.is_enabled(),
+                *on,
+                text.as_deref().unwrap_or(""),
+            )
+        });
+
+        if !ui.is_rect_visible(prepared.response.rect) {
+            return prepared.response;
+        }
+
+        let how_on = ui.ctx().animate_bool_responsive(prepared.response.id, *on);
+
+        let off_visuals = ui.style().interact_selectable(&prepared.response, false);
+        let on_visuals = ui.style().interact_selectable(&prepared.response, true);
+        let visuals = if *on { on_visuals } else { off_visuals };
+
+        prepared.fallback_text_color = ui.style().interact(&prepared.response).text_color();
+        let response = prepared.paint(ui);
+
+        if let Some(rect) = response.rect(switch_id) {
+            let off_fill = off_color.unwrap_or(off_visuals.bg_fill);
+            let on_fill = on_color.unwrap_or(on_visuals.bg_fill);
+            let fill = off_fill.lerp_linear(on_fill, how_on);
+
+            let rect = rect.expand(visuals.expansion);
+            let radius = 0.5 * rect.height();
+            ui.painter()
+                .rect(rect, radius, fill, visuals.bg_stroke, StrokeKind::Inside);
+
+            // The knob slides from left to right as the switch turns on:
+            let knob_x = lerp((rect.left() + radius)..=(rect.right() - radius), how_on);
+            let center = pos2(knob_x, rect.center().y);
+            ui.painter()
+                .circle(center, 0.75 * radius, fill, visuals.fg_stroke);
+        }
+
+        response.response
+    }
+}
@@ -21,6 +21,8 @@ pub struct ImageViewer {
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 enum ChosenFit {
     ExactSize,
+    Cover,
+    Contain,
     Fraction,
     OriginalSize,
 }
@@ -29,6 +31,8 @@ impl ChosenFit {
     fn as_str(&self) -> &'static str {
         match self {
             Self::ExactSize => "exact size",
+            Self::Cover => "cover",
+            Self::Contain => "contain",
             Self::Fraction => "fraction",
             Self::OriginalSize => "original size",
         }
@@ -133,6 +137,16 @@ impl eframe::App for ImageViewer {
                         ChosenFit::ExactSize,
                         ChosenFit::ExactSize.as_str(),
                     );
+                    ui.selectable_value(
+                        &mut self.chosen_fit,
+                        ChosenFit::Cover,
+                        ChosenFit::Cover.as_str(),
+                    );
+                    ui.selectable_value(
+                        &mut self.chosen_fit,
+                        ChosenFit::Contain,
+                        ChosenFit::Contain.as_str(),
+                    );
                     ui.selectable_value(
                         &mut self.chosen_fit,
                         ChosenFit::Fraction,
@@ -156,6 +170,26 @@ impl eframe::App for ImageViewer {
                     ui.add(Slider::new(&mut size.x, 0.0..=2048.0).text("width"));
                     ui.add(Slider::new(&mut size.y, 0.0..=2048.0).text("height"));
                 }
+                ChosenFit::Cover => {
+                    if !matches!(self.fit, ImageFit::Cover(_)) {
+                        self.fit = ImageFit::Cover(Vec2::splat(128.0));
+                    }
+                    let ImageFit::Cover(size) = &mut self.fit else {
+                        unreachable!()
+                    };
+                    ui.add(Slider::new(&mut size.x, 0.0..=2048.0).text("width"));
+                    ui.add(Slider::new(&mut size.y, 0.0..=2048.0).text("height"));
+                }
+                ChosenFit::Contain => {
+                    if !matches!(self.fit, ImageFit::Contain(_)) {
+                        self.fit = ImageFit::Contain(Vec2::splat(128.0));
+                    }
+                    let ImageFit::Contain(size) = &mut self.fit else {
+                        unreachable!()
+                    };
+                    ui.add(Slider::new(&mut size.x, 0.0..=2048.0).text("width"));
+                    ui.add(Slider::new(&mut size.y, 0.0..=2048.0).text("height"));
+                }
                 ChosenFit::Fraction => {
                     if !matches!(self.fit, ImageFit::Fraction(_)) {
                         self.fit = ImageFit::Fraction(Vec2::splat(1.0));
@@ -216,6 +250,8 @@ impl eframe::App for ImageViewer {
                     ImageFit::Original { scale } => image = image.fit_to_original_size(scale),
                     ImageFit::Fraction(fract) => image = image.fit_to_fraction(fract),
                     ImageFit::Exact(size) => image = image.fit_to_exact_size(size),
+                    ImageFit::Cover(size) => image = image.fit_to_cover(size),
+                    ImageFit::Contain(size) => image = image.fit_to_contain(size),
                 }
                 image = image.maintain_aspect_ratio(self.maintain_aspect_ratio);
                 image = image.max_size(self.max_size);
@@ -76,6 +76,7 @@ impl Default for DemoGroups {
                 Box::<super::code_editor::CodeEditor>::default(),
                 Box::<super::code_example::CodeExample>::default(),
                 Box::<super::dancing_strings::DancingStrings>::default(),
+                Box::<super::donut_chart::DonutChart>::default(),
                 Box::<super::drag_and_drop::DragAndDropDemo>::default(),
                 Box::<super::extra_viewport::ExtraViewport>::default(),
                 Box::<super::font_book::FontBook>::default(),
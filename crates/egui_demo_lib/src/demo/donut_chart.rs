@@ -0,0 +1,143 @@
+use std::f32::consts::TAU;
+
+use egui::{
+    Color32, Context, Frame, Pos2, Sense, Slider, Stroke, Ui, Window, epaint::ArcShape, vec2,
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DonutChart {
+    /// The value of each slice.
+    values: Vec<f32>,
+
+    /// The size of the hole, as a fraction of the radius.
+    hole: f32,
+
+    /// The gap between the slices, in points.
+    gap: f32,
+}
+
+impl Default for DonutChart {
+    fn default() -> Self {
+        Self {
+            values: vec![5.0, 3.0, 2.0, 1.5, 1.0],
+            hole: 0.5,
+            gap: 2.0,
+        }
+    }
+}
+
+impl crate::Demo for DonutChart {
+    fn name(&self) -> &'static str {
+        "🍩 Donut Chart"
+    }
+
+    fn show(&mut self, ctx: &Context, open: &mut bool) {
+        use crate::View as _;
+        Window::new(self.name())
+            .open(open)
+            .default_size(vec2(320.0, 400.0))
+            .vscroll(false)
+            .show(ctx, |ui| self.ui(ui));
+    }
+}
+
+impl crate::View for DonutChart {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.add(Slider::new(&mut self.hole, 0.0..=0.9).text("Hole"));
+        ui.add(Slider::new(&mut self.gap, 0.0..=10.0).text("Gap"));
+        ui.horizontal(|ui| {
+            for (i, value) in self.values.iter_mut().enumerate() {
+                ui.add(egui::DragValue::new(value).range(0.0..=100.0).speed(0.1))
+                    .on_hover_text(format!("Slice {}", i + 1));
+            }
+        });
+
+        Frame::canvas(ui.style()).show(ui, |ui| {
+            let size = ui.available_width().min(ui.available_height()).max(64.0);
+            let (response, painter) = ui.allocate_painter(vec2(size, size), Sense::hover());
+            let rect = response.rect;
+            let center = rect.center();
+            let radius = 0.5 * rect.width() - 8.0;
+
+            let hover_angle = response
+                .hover_pos()
+                .filter(|pos| {
+                    let distance = pos.distance(center);
+                    self.hole * radius <= distance && distance <= radius
+                })
+                .map(|pos| angle_from_top(center, pos));
+
+            let total: f32 = self.values.iter().sum();
+            if total <= 0.0 {
+                return;
+            }
+
+            // Start at the top, and go around clockwise:
+            let mut start_angle = -0.25 * TAU;
+            for (i, &value) in self.values.iter().enumerate() {
+                let span = TAU * value / total;
+                let end_angle = start_angle + span;
+
+                let hovered = hover_angle.is_some_and(|angle| {
+                    let angle = angle - 0.25 * TAU;
+                    start_angle <= angle && angle < end_angle
+                });
+
+                // Make the gap a constant width, instead of a constant angle:
+                let gap_angle = (self.gap / radius).min(0.5 * span);
+                let color = slice_color(i);
+                let (outer_radius, stroke) = if hovered {
+                    (
+                        radius + 6.0,
+                        Stroke::new(1.5, ui.visuals().strong_text_color()),
+                    )
+                } else {
+                    (radius, Stroke::NONE)
+                };
+
+                painter.add(
+                    ArcShape::pie(
+                        center,
+                        outer_radius,
+                        start_angle + 0.5 * gap_angle,
+                        end_angle - 0.5 * gap_angle,
+                        color,
+                        stroke,
+                    )
+                    .with_inner_radius(self.hole * radius),
+                );
+
+                if hovered {
+                    response.clone().on_hover_text(format!(
+                        "Slice {}: {value:.1} ({:.0}%)",
+                        i + 1,
+                        100.0 * value / total
+                    ));
+                }
+
+                start_angle = end_angle;
+            }
+        });
+
+        ui.vertical_centered(|ui| {
+            ui.add(crate::egui_github_link_file!());
+        });
+    }
+}
+
+/// The clockwise angle from straight up, in `0..TAU`.
+fn angle_from_top(center: Pos2, pos: Pos2) -> f32 {
+    ((pos - center).angle() + 0.25 * TAU).rem_euclid(TAU)
+}
+
+fn slice_color(i: usize) -> Color32 {
+    const COLORS: [Color32; 5] = [
+        Color32::from_rgb(0x4e, 0x79, 0xa7),
+        Color32::from_rgb(0xf2, 0x8e, 0x2b),
+        Color32::from_rgb(0xe1, 0x57, 0x59),
+        Color32::from_rgb(0x76, 0xb7, 0xb2),
+        Color32::from_rgb(0x59, 0xa1, 0x4f),
+    ];
+    COLORS[i % COLORS.len()]
+}
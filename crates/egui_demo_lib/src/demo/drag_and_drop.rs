@@ -5,6 +5,9 @@ use egui::{Color32, Context, Frame, Id, Ui, Window, vec2};
 pub struct DragAndDropDemo {
     /// columns with items
     columns: Vec<Vec<String>>,
+
+    /// items of the reorderable list
+    list: Vec<String>,
 }
 
 impl Default for DragAndDropDemo {
@@ -18,6 +21,9 @@ impl Default for DragAndDropDemo {
             .into_iter()
             .map(|v| v.into_iter().map(ToString::to_string).collect())
             .collect(),
+            list: ["First", "Second", "Third", "Fourth"]
+                .map(ToString::to_string)
+                .into(),
         }
     }
 }
@@ -134,6 +140,12 @@ impl crate::View for DragAndDropDemo {
             column.insert(to.row, item);
         }
 
+        ui.separator();
+        ui.label("Reorder the items of a list with egui::dnd::reorder_list:");
+        egui::dnd::reorder_list(ui, "reorder_list", &mut self.list, |ui, item| {
+            ui.label(item.as_str());
+        });
+
         ui.vertical_centered(|ui| {
             ui.add(crate::egui_github_link_file!());
         });
@@ -1,8 +1,9 @@
 use super::{Demo, View};
 
 use egui::{
-    Align, Align2, Checkbox, CollapsingHeader, Color32, ComboBox, Context, FontId, Resize,
-    RichText, Sense, Slider, Stroke, TextFormat, TextStyle, Ui, Vec2, Window, vec2,
+    Align, Align2, Button, Checkbox, CollapsingHeader, Color32, ComboBox, Context, FontId,
+    FontSelection, Resize, RichText, Sense, Slider, Stroke, TextFormat, TextStyle, Ui, Vec2,
+    Window, vec2,
 };
 
 /// Showcase some ui code
@@ -72,6 +73,10 @@ impl View for MiscDemoWindow {
                 self.widgets.ui(ui);
             });
 
+        CollapsingHeader::new("Buttons")
+            .default_open(false)
+            .show(ui, buttons_ui);
+
         CollapsingHeader::new("Text layout")
             .default_open(false)
             .show(ui, |ui| {
@@ -134,9 +139,11 @@ impl View for MiscDemoWindow {
                         *check = all_checked;
                     }
                 }
-                for (i, checked) in self.checklist.iter_mut().enumerate() {
-                    ui.checkbox(checked, format!("Item {}", i + 1));
-                }
+                ui.indent("checklist", |ui| {
+                    for (i, checked) in self.checklist.iter_mut().enumerate() {
+                        ui.checkbox(checked, format!("Item {}", i + 1));
+                    }
+                });
             });
 
         ui.collapsing("Columns", |ui| {
@@ -243,6 +250,24 @@ fn label_ui(ui: &mut egui::Ui) {
 
 // ----------------------------------------------------------------------------
 
+fn buttons_ui(ui: &mut Ui) {
+    ui.horizontal(|ui| {
+        ui.add(Button::new("Primary").primary());
+        ui.add(Button::new("Secondary"));
+    });
+    ui.horizontal(|ui| {
+        ui.add(Button::new("Big primary").primary().big());
+        ui.add(Button::new("Big secondary").big());
+    });
+    ui.add(
+        Button::new("Big, with a larger min_size")
+            .big()
+            .min_size(vec2(250.0, 50.0)),
+    );
+}
+
+// ----------------------------------------------------------------------------
+
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct Widgets {
@@ -734,6 +759,24 @@ fn text_layout_demo(ui: &mut Ui) {
     );
 
     ui.label(job);
+
+    // Superscript and subscript, e.g. for exponents and chemical formulas:
+    let mut job = LayoutJob::default();
+    for text in [
+        RichText::new("x"),
+        RichText::new("2").superscript(),
+        RichText::new(" and H"),
+        RichText::new("2").subscript(),
+        RichText::new("O"),
+    ] {
+        text.color(default_color).append_to(
+            &mut job,
+            ui.style(),
+            FontSelection::Default,
+            Align::Center,
+        );
+    }
+    ui.label(job);
 }
 
 // ----------------------------------------------------------------------------
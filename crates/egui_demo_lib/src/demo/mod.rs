@@ -9,6 +9,7 @@ pub mod code_editor;
 pub mod code_example;
 pub mod dancing_strings;
 pub mod demo_app_windows;
+pub mod donut_chart;
 pub mod drag_and_drop;
 pub mod extra_viewport;
 pub mod font_book;
@@ -69,6 +69,16 @@ impl PopupsDemo {
                 }
             },
         );
+        ui.menu_button("Icons line up", |ui| {
+            let icon = || Some(egui::Image::new(include_image!("../../data/icon.png")));
+            let _ = ui.add(Button::new("With icon").icon_gutter(icon()));
+            let _ = ui.add(Button::new("Without icon").icon_gutter(None));
+            let _ = ui.add(
+                Button::new("Themed icon")
+                    .icon_gutter(icon())
+                    .image_tint_follows_text_color(true),
+            );
+        });
         let _ = ui.button("Very long text for this item that should be wrapped");
         SubMenuButton::new("Always CloseOnClickOutside")
             .config(MenuConfig::new().close_behavior(PopupCloseBehavior::CloseOnClickOutside))
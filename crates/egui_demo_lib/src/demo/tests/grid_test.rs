@@ -74,6 +74,21 @@ impl crate::View for GridTest {
                 }
             });
 
+        ui.separator();
+        ui.label("Per-column widths: narrow first column, wide second, wrapped third:");
+        egui::Grid::new("per_column_grid")
+            .striped(true)
+            .min_col_widths(vec![0.0, 150.0])
+            .max_col_widths(vec![60.0, f32::INFINITY, 100.0])
+            .show(ui, |ui| {
+                for row in 0..3 {
+                    ui.label(format!("row {row}"));
+                    ui.label("At least 150 wide");
+                    ui.label("This text is wrapped at a width of 100");
+                    ui.end_row();
+                }
+            });
+
         ui.separator();
         ui.add(egui::Slider::new(&mut self.text_length, 1..=40).text("Text length"));
         egui::Grid::new("parent grid").striped(true).show(ui, |ui| {
@@ -4,12 +4,14 @@
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct TextEditDemo {
     pub text: String,
+    pub hex: String,
 }
 
 impl Default for TextEditDemo {
     fn default() -> Self {
         Self {
             text: "Edit this text".to_owned(),
+            hex: "c0ffee".to_owned(),
         }
     }
 }
@@ -36,7 +38,7 @@ impl crate::View for TextEditDemo {
             ui.add(crate::egui_github_link_file!());
         });
 
-        let Self { text } = self;
+        let Self { text, hex } = self;
 
         ui.horizontal(|ui| {
             ui.spacing_mut().item_spacing.x = 0.0;
@@ -108,6 +110,13 @@ impl crate::View for TextEditDemo {
                 }
             }
         });
+
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.label("Only hex digits:");
+            ui.add(egui::TextEdit::singleline(hex).char_filter(|c| c.is_ascii_hexdigit()));
+        });
     }
 }
 
@@ -148,4 +157,88 @@ mod tests {
         assert_eq!(text_edit.value().as_deref(), Some("Hi there!"));
         assert_eq!(harness.state(), "Hi there!");
     }
+
+    #[test]
+    pub fn char_filter_should_reject_chars() {
+        let mut harness = Harness::new_state(
+            move |ctx, text| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.add(egui::TextEdit::singleline(text).char_filter(|c| c.is_ascii_hexdigit()));
+                });
+            },
+            String::new(),
+        );
+
+        harness.run();
+        harness.get_by_role(accesskit::Role::TextInput).focus();
+        harness.run();
+        harness
+            .get_by_role(accesskit::Role::TextInput)
+            .type_text("12xy ab");
+
+        harness.run();
+        assert_eq!(harness.state(), "12ab");
+    }
+
+    #[test]
+    pub fn select_all_on_focus_should_replace_text() {
+        let mut harness = Harness::new_state(
+            move |ctx, text| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.add(egui::TextEdit::singleline(text).select_all_on_focus(true));
+                });
+            },
+            "Hello".to_owned(),
+        );
+
+        harness.run();
+        harness.key_press(Key::Tab);
+        harness.run();
+        harness
+            .get_by_role(accesskit::Role::TextInput)
+            .type_text("Bye");
+
+        harness.run();
+        assert_eq!(harness.state(), "Bye");
+    }
+
+    #[test]
+    pub fn on_change_should_be_called_once_per_edit() {
+        let mut harness = Harness::new_state(
+            move |ctx, (text, changes): &mut (String, Vec<String>)| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(text)
+                            .on_change(|text| changes.push(text.to_owned())),
+                    );
+                });
+            },
+            (String::new(), Vec::new()),
+        );
+
+        harness.run();
+        harness.get_by_role(accesskit::Role::TextInput).focus();
+        harness.run();
+        assert!(
+            harness.state().1.is_empty(),
+            "Focusing doesn't change the text"
+        );
+
+        let text_edit = harness.get_by_role(accesskit::Role::TextInput);
+        text_edit.type_text("ab");
+        text_edit.type_text("c");
+        harness.run();
+        assert_eq!(harness.state().1, ["ab", "abc"]);
+
+        // Moving the cursor doesn't change the text:
+        harness.key_press(Key::ArrowLeft);
+        harness.key_press(Key::Home);
+        harness.run();
+        assert_eq!(harness.state().1.len(), 2);
+
+        harness.key_press(Key::Delete);
+        harness.run();
+        assert_eq!(harness.state().1, ["ab", "abc", "bc"]);
+        assert_eq!(harness.state().0, "bc");
+    }
 }
@@ -25,6 +25,10 @@ pub struct WidgetGallery {
 
     #[cfg(feature = "chrono")]
     with_date_button: bool,
+
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    weeks_start_on_sunday: bool,
 }
 
 impl Default for WidgetGallery {
@@ -43,6 +47,8 @@ impl Default for WidgetGallery {
             date: None,
             #[cfg(feature = "chrono")]
             with_date_button: true,
+            #[cfg(feature = "chrono")]
+            weeks_start_on_sunday: false,
         }
     }
 }
@@ -143,6 +149,8 @@ impl WidgetGallery {
             date,
             #[cfg(feature = "chrono")]
             with_date_button,
+            #[cfg(feature = "chrono")]
+            weeks_start_on_sunday,
         } = self;
 
         ui.add(doc_link_label("Label", "label"));
@@ -252,7 +260,15 @@ impl WidgetGallery {
                 "DatePickerButton",
                 "DatePickerButton",
             ));
-            ui.add(egui_extras::DatePickerButton::new(date));
+            ui.horizontal(|ui| {
+                let first_weekday = if *weeks_start_on_sunday {
+                    chrono::Weekday::Sun
+                } else {
+                    chrono::Weekday::Mon
+                };
+                ui.add(egui_extras::DatePickerButton::new(date).first_weekday(first_weekday));
+                ui.checkbox(weeks_start_on_sunday, "Weeks start on Sunday");
+            });
             ui.end_row();
         }
 
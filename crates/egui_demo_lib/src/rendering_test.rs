@@ -19,6 +19,8 @@ const WHITE: Color32 = Color32::WHITE;
 pub struct ColorTest {
     #[cfg_attr(feature = "serde", serde(skip))]
     tex_mngr: TextureManager,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    wrap_texture: Option<TextureHandle>,
     vertex_gradients: bool,
     texture_gradients: bool,
 }
@@ -27,6 +29,7 @@ impl Default for ColorTest {
     fn default() -> Self {
         Self {
             tex_mngr: Default::default(),
+            wrap_texture: None,
             vertex_gradients: true,
             texture_gradients: true,
         }
@@ -55,6 +58,10 @@ impl ColorTest {
             self.color_test(ui);
         });
 
+        ui.collapsing("Texture wrapping", |ui| {
+            self.texture_wrap_test(ui);
+        });
+
         ui.separator();
 
         ui.heading("Text rendering");
@@ -282,6 +289,35 @@ impl ColorTest {
         });
     }
 
+    fn texture_wrap_test(&mut self, ui: &mut Ui) {
+        ui.label("The checkered middle should repeat four times horizontally.");
+        ui.label(
+            "Above and below it, the red top row and the green bottom row should be stretched out.",
+        );
+
+        let texture = self.wrap_texture.get_or_insert_with(|| {
+            let (r, g, b, w) = (RED, GREEN, BLACK, WHITE);
+            #[rustfmt::skip]
+            let pixels = vec![
+                r, r, r, r,
+                w, b, w, b,
+                b, w, b, w,
+                g, g, g, g,
+            ];
+            ui.ctx().load_texture(
+                "color_test_wrap",
+                epaint::ColorImage::new([4, 4], pixels),
+                TextureOptions::NEAREST
+                    .with_wrap_mode_x(egui::TextureWrapMode::Repeat)
+                    .with_wrap_mode_y(egui::TextureWrapMode::ClampToEdge),
+            )
+        });
+
+        let uv = Rect::from_min_max(pos2(0.0, -1.0), pos2(4.0, 2.0));
+        ui.add(Image::from_texture((texture.id(), vec2(256.0, 192.0))).uv(uv))
+            .on_hover_text("Repeat horizontally, clamp to edge vertically");
+    }
+
     fn vertex_gradient(&self, ui: &mut Ui, label: &str, bg_fill: Color32, gradient: &Gradient) {
         if !self.vertex_gradients {
             return;
@@ -380,7 +416,7 @@ impl Gradient {
             (0..=n)
                 .map(|i| {
                     let t = i as f32 / n as f32;
-                    left.lerp_to_gamma(right, t)
+                    left.lerp_gamma(right, t)
                 })
                 .collect(),
         )
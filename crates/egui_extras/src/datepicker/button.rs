@@ -1,5 +1,5 @@
 use super::popup::DatePickerPopup;
-use chrono::NaiveDate;
+use chrono::{NaiveDate, Weekday};
 use egui::{Area, Button, Frame, InnerResponse, Key, Order, RichText, Ui, Widget};
 use std::ops::RangeInclusive;
 
@@ -17,10 +17,13 @@ pub struct DatePickerButton<'a> {
     arrows: bool,
     calendar: bool,
     calendar_week: bool,
+    first_weekday: Weekday,
     show_icon: bool,
     format: String,
     highlight_weekends: bool,
     start_end_years: Option<RangeInclusive<i32>>,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
 }
 
 impl<'a> DatePickerButton<'a> {
@@ -32,10 +35,13 @@ impl<'a> DatePickerButton<'a> {
             arrows: true,
             calendar: true,
             calendar_week: true,
+            first_weekday: Weekday::Mon,
             show_icon: true,
             format: "%Y-%m-%d".to_owned(),
             highlight_weekends: true,
             start_end_years: None,
+            min_date: None,
+            max_date: None,
         }
     }
 
@@ -77,12 +83,24 @@ impl<'a> DatePickerButton<'a> {
     }
 
     /// Show calendar week in date picker popup. (Default: true)
+    ///
+    /// Each week is numbered by the ISO 8601 week of its Thursday,
+    /// so the numbers are the ISO week numbers when weeks start on Monday (see [`Self::first_weekday`]).
     #[inline]
     pub fn calendar_week(mut self, week: bool) -> Self {
         self.calendar_week = week;
         self
     }
 
+    /// The day each week starts on in the calendar. (Default: [`Weekday::Mon`])
+    ///
+    /// Use [`Weekday::Sun`] for calendars like in the US.
+    #[inline]
+    pub fn first_weekday(mut self, first_weekday: Weekday) -> Self {
+        self.first_weekday = first_weekday;
+        self
+    }
+
     /// Show the calendar icon on the button. (Default: true)
     #[inline]
     pub fn show_icon(mut self, show_icon: bool) -> Self {
@@ -115,6 +133,26 @@ impl<'a> DatePickerButton<'a> {
         self.start_end_years = Some(start_end_years);
         self
     }
+
+    /// Don't allow picking a date before this one. (Default: None)
+    ///
+    /// Earlier days are dimmed and can't be clicked in the calendar,
+    /// and the arrows and combo boxes won't go past this date.
+    #[inline]
+    pub fn min_date(mut self, min_date: NaiveDate) -> Self {
+        self.min_date = Some(min_date);
+        self
+    }
+
+    /// Don't allow picking a date after this one. (Default: None)
+    ///
+    /// Later days are dimmed and can't be clicked in the calendar,
+    /// and the arrows and combo boxes won't go past this date.
+    #[inline]
+    pub fn max_date(mut self, max_date: NaiveDate) -> Self {
+        self.max_date = Some(max_date);
+        self
+    }
 }
 
 impl Widget for DatePickerButton<'_> {
@@ -180,8 +218,11 @@ impl Widget for DatePickerButton<'_> {
                                 arrows: self.arrows,
                                 calendar: self.calendar,
                                 calendar_week: self.calendar_week,
+                                first_weekday: self.first_weekday,
                                 highlight_weekends: self.highlight_weekends,
                                 start_end_years: self.start_end_years,
+                                min_date: self.min_date,
+                                max_date: self.max_date,
                             }
                             .draw(ui)
                         })
@@ -10,20 +10,29 @@ struct Week {
     days: Vec<NaiveDate>,
 }
 
-fn month_data(year: i32, month: u32) -> Vec<Week> {
+/// The weeks of the given month, starting on `first_weekday`.
+///
+/// Each week is numbered by the ISO 8601 week of its Thursday,
+/// so that it matches the ISO week numbers when weeks start on Monday.
+fn month_data(year: i32, month: u32, first_weekday: Weekday) -> Vec<Week> {
     let first = NaiveDate::from_ymd_opt(year, month, 1).expect("Could not create NaiveDate");
+    let last_weekday = first_weekday.pred();
     let mut start = first;
-    while start.weekday() != Weekday::Mon {
+    while start.weekday() != first_weekday {
         start = start.checked_sub_signed(Duration::days(1)).unwrap();
     }
     let mut weeks = vec![];
     let mut week = vec![];
-    while start < first || start.month() == first.month() || start.weekday() != Weekday::Mon {
+    while start < first || start.month() == first.month() || start.weekday() != first_weekday {
         week.push(start);
 
-        if start.weekday() == Weekday::Sun {
+        if start.weekday() == last_weekday {
+            let thursday = week
+                .iter()
+                .find(|day| day.weekday() == Weekday::Thu)
+                .unwrap_or(&start);
             weeks.push(Week {
-                number: start.iso_week().week() as u8,
+                number: thursday.iso_week().week() as u8,
                 days: std::mem::take(&mut week),
             });
         }
@@ -32,3 +41,26 @@ fn month_data(year: i32, month: u32) -> Vec<Week> {
 
     weeks
 }
+
+#[test]
+fn test_month_data() {
+    // September 2024 starts on a Sunday:
+    let monday_weeks = month_data(2024, 9, Weekday::Mon);
+    assert_eq!(monday_weeks.len(), 6);
+    assert_eq!(
+        monday_weeks[0].days[0],
+        NaiveDate::from_ymd_opt(2024, 8, 26).unwrap()
+    );
+    assert_eq!(monday_weeks[0].number, 35);
+    assert_eq!(monday_weeks[1].number, 36);
+
+    let sunday_weeks = month_data(2024, 9, Weekday::Sun);
+    assert_eq!(sunday_weeks.len(), 5);
+    assert_eq!(
+        sunday_weeks[0].days[0],
+        NaiveDate::from_ymd_opt(2024, 9, 1).unwrap()
+    );
+    assert_eq!(sunday_weeks[0].days[6].weekday(), Weekday::Sat);
+    assert_eq!(sunday_weeks[0].number, 36);
+    assert!(sunday_weeks.iter().all(|week| week.days.len() == 7));
+}
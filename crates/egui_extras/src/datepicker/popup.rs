@@ -25,6 +25,17 @@ impl DatePickerPopupState {
             .or_else(|| date.with_day(29).map(|_| 29))
             .unwrap_or(28)
     }
+
+    fn date(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, self.month, self.day)
+            .expect("Could not create NaiveDate")
+    }
+
+    fn set_date(&mut self, date: NaiveDate) {
+        self.year = date.year();
+        self.month = date.month();
+        self.day = date.day();
+    }
 }
 
 pub(crate) struct DatePickerPopup<'a> {
@@ -34,11 +45,28 @@ pub(crate) struct DatePickerPopup<'a> {
     pub arrows: bool,
     pub calendar: bool,
     pub calendar_week: bool,
+    pub first_weekday: Weekday,
     pub highlight_weekends: bool,
     pub start_end_years: Option<std::ops::RangeInclusive<i32>>,
+    pub min_date: Option<NaiveDate>,
+    pub max_date: Option<NaiveDate>,
 }
 
 impl DatePickerPopup<'_> {
+    fn is_in_range(&self, date: NaiveDate) -> bool {
+        self.min_date.is_none_or(|min| min <= date) && self.max_date.is_none_or(|max| date <= max)
+    }
+
+    fn clamp_to_range(&self, mut date: NaiveDate) -> NaiveDate {
+        if let Some(min) = self.min_date {
+            date = date.max(min);
+        }
+        if let Some(max) = self.max_date {
+            date = date.min(max);
+        }
+        date
+    }
+
     /// Returns `true` if user pressed `Save` button.
     pub fn draw(&mut self, ui: &mut Ui) -> bool {
         let id = ui.make_persistent_id("date_picker");
@@ -47,14 +75,17 @@ impl DatePickerPopup<'_> {
             .data_mut(|data| data.get_persisted::<DatePickerPopupState>(id))
             .unwrap_or_default();
         if !popup_state.setup {
-            popup_state.year = self.selection.year();
-            popup_state.month = self.selection.month();
-            popup_state.day = self.selection.day();
+            popup_state.set_date(self.clamp_to_range(*self.selection));
             popup_state.setup = true;
             ui.data_mut(|data| data.insert_persisted(id, popup_state.clone()));
         }
 
-        let weeks = month_data(popup_state.year, popup_state.month);
+        // The arrows stop at the allowed range:
+        let current_date = popup_state.date();
+        let can_go_back = self.min_date.is_none_or(|min| min < current_date);
+        let can_go_forward = self.max_date.is_none_or(|max| current_date < max);
+
+        let weeks = month_data(popup_state.year, popup_state.month, self.first_weekday);
         let (mut close, mut saved) = (false, false);
         let height = 20.0;
         let spacing = 2.0;
@@ -85,10 +116,17 @@ impl DatePickerPopup<'_> {
                                 ComboBox::from_id_salt("date_picker_year")
                                     .selected_text(popup_state.year.to_string())
                                     .show_ui(ui, |ui| {
-                                        let (start_year, end_year) = match &self.start_end_years {
-                                            Some(range) => (*range.start(), *range.end()),
-                                            None => (today.year() - 100, today.year() + 10),
-                                        };
+                                        let (mut start_year, mut end_year) =
+                                            match &self.start_end_years {
+                                                Some(range) => (*range.start(), *range.end()),
+                                                None => (today.year() - 100, today.year() + 10),
+                                            };
+                                        if let Some(min) = self.min_date {
+                                            start_year = start_year.max(min.year());
+                                        }
+                                        if let Some(max) = self.max_date {
+                                            end_year = end_year.min(max.year());
+                                        }
                                         for year in start_year..=end_year {
                                             if ui
                                                 .selectable_value(
@@ -164,7 +202,7 @@ impl DatePickerPopup<'_> {
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                     if ui
-                                        .button("<<<")
+                                        .add_enabled(can_go_back, Button::new("<<<"))
                                         .on_hover_text("subtract one year")
                                         .clicked()
                                     {
@@ -180,7 +218,7 @@ impl DatePickerPopup<'_> {
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                     if ui
-                                        .button("<<")
+                                        .add_enabled(can_go_back, Button::new("<<"))
                                         .on_hover_text("subtract one month")
                                         .clicked()
                                     {
@@ -199,7 +237,11 @@ impl DatePickerPopup<'_> {
                             });
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                    if ui.button("<").on_hover_text("subtract one day").clicked() {
+                                    if ui
+                                        .add_enabled(can_go_back, Button::new("<"))
+                                        .on_hover_text("subtract one day")
+                                        .clicked()
+                                    {
                                         popup_state.day -= 1;
                                         if popup_state.day == 0 {
                                             popup_state.month -= 1;
@@ -217,7 +259,11 @@ impl DatePickerPopup<'_> {
                             });
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                    if ui.button(">").on_hover_text("add one day").clicked() {
+                                    if ui
+                                        .add_enabled(can_go_forward, Button::new(">"))
+                                        .on_hover_text("add one day")
+                                        .clicked()
+                                    {
                                         popup_state.day += 1;
                                         if popup_state.day > popup_state.last_day_of_month() {
                                             popup_state.day = 1;
@@ -235,7 +281,11 @@ impl DatePickerPopup<'_> {
                             });
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                    if ui.button(">>").on_hover_text("add one month").clicked() {
+                                    if ui
+                                        .add_enabled(can_go_forward, Button::new(">>"))
+                                        .on_hover_text("add one month")
+                                        .clicked()
+                                    {
                                         popup_state.month += 1;
                                         if popup_state.month > 12 {
                                             popup_state.month = 1;
@@ -251,7 +301,11 @@ impl DatePickerPopup<'_> {
                             });
                             strip.cell(|ui| {
                                 ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
-                                    if ui.button(">>>").on_hover_text("add one year").clicked() {
+                                    if ui
+                                        .add_enabled(can_go_forward, Button::new(">>>"))
+                                        .on_hover_text("add one year")
+                                        .clicked()
+                                    {
                                         popup_state.year += 1;
                                         popup_state.day =
                                             popup_state.day.min(popup_state.last_day_of_month());
@@ -284,15 +338,17 @@ impl DatePickerPopup<'_> {
                                 }
 
                                 //TODO(elwerene): Locale
-                                for name in ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"] {
+                                let mut weekday = self.first_weekday;
+                                for _ in 0..7 {
                                     header.col(|ui| {
                                         ui.with_layout(
                                             Layout::centered_and_justified(Direction::TopDown),
                                             |ui| {
-                                                ui.label(name);
+                                                ui.label(weekday_name(weekday));
                                             },
                                         );
                                     });
+                                    weekday = weekday.succ();
                                 }
                             })
                             .body(|mut body| {
@@ -338,7 +394,8 @@ impl DatePickerPopup<'_> {
                                                                 text_color.linear_multiply(0.5);
                                                         }
 
-                                                        let button_response = ui.add(
+                                                        let button_response = ui.add_enabled(
+                                                            self.is_in_range(day),
                                                             Button::new(
                                                                 RichText::new(
                                                                     day.day().to_string(),
@@ -396,12 +453,7 @@ impl DatePickerPopup<'_> {
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                 if ui.button("Save").clicked() {
-                                    *self.selection = NaiveDate::from_ymd_opt(
-                                        popup_state.year,
-                                        popup_state.month,
-                                        popup_state.day,
-                                    )
-                                    .expect("Could not create NaiveDate");
+                                    *self.selection = self.clamp_to_range(popup_state.date());
                                     saved = true;
                                     close = true;
                                 }
@@ -411,6 +463,14 @@ impl DatePickerPopup<'_> {
                 });
             });
 
+        // Jumping a month or a year, or picking in the combo boxes, may take us out of the allowed range:
+        let date = popup_state.date();
+        let clamped_date = self.clamp_to_range(date);
+        if clamped_date != date {
+            popup_state.set_date(clamped_date);
+            ui.data_mut(|data| data.insert_persisted(id, popup_state.clone()));
+        }
+
         if close {
             popup_state.setup = false;
             ui.data_mut(|data| {
@@ -424,6 +484,18 @@ impl DatePickerPopup<'_> {
     }
 }
 
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Mo",
+        Weekday::Tue => "Tu",
+        Weekday::Wed => "We",
+        Weekday::Thu => "Th",
+        Weekday::Fri => "Fr",
+        Weekday::Sat => "Sa",
+        Weekday::Sun => "Su",
+    }
+}
+
 fn month_name(i: u32) -> &'static str {
     match i {
         1 => "January",
@@ -29,6 +29,14 @@ pub(crate) enum CellDirection {
 /// Flags used by [`StripLayout::add`].
 #[derive(Clone, Copy, Default)]
 pub(crate) struct StripLayoutFlags {
+    /// Paint the cell this much to the right of where it is laid out.
+    ///
+    /// Used for the frozen columns of a [`Table`](crate::Table).
+    pub(crate) shift_x: f32,
+
+    /// Hide everything in the cell left of this, e.g. because it is covered by frozen columns.
+    pub(crate) clip_left: Option<f32>,
+
     pub(crate) clip: bool,
     pub(crate) striped: bool,
     pub(crate) hovered: bool,
@@ -120,14 +128,22 @@ impl<'l> StripLayout<'l> {
         child_ui_id_salt: Id,
         add_cell_contents: impl FnOnce(&mut Ui),
     ) -> (Rect, Response) {
-        let max_rect = self.cell_rect(&width, &height);
+        let laid_out_rect = self.cell_rect(&width, &height);
+        let max_rect = laid_out_rect.translate(egui::vec2(flags.shift_x, 0.0));
 
         // Make sure we don't have a gap in the stripe/frame/selection background:
         let item_spacing = self.ui.spacing().item_spacing;
         let gapless_rect = max_rect.expand2(0.5 * item_spacing).round_ui();
 
+        let mut painter = self.ui.painter().clone();
+        if let Some(clip_left) = flags.clip_left {
+            let mut clip_rect = painter.clip_rect();
+            clip_rect.min.x = clip_rect.min.x.max(clip_left);
+            painter.set_clip_rect(clip_rect);
+        }
+
         if flags.striped {
-            self.ui.painter().rect_filled(
+            painter.rect_filled(
                 gapless_rect,
                 egui::CornerRadius::ZERO,
                 self.ui.visuals().faint_bg_color,
@@ -135,7 +151,7 @@ impl<'l> StripLayout<'l> {
         }
 
         if flags.selected {
-            self.ui.painter().rect_filled(
+            painter.rect_filled(
                 gapless_rect,
                 egui::CornerRadius::ZERO,
                 self.ui.visuals().selection.bg_fill,
@@ -143,7 +159,7 @@ impl<'l> StripLayout<'l> {
         }
 
         if flags.hovered && !flags.selected && self.sense.interactive() {
-            self.ui.painter().rect_filled(
+            painter.rect_filled(
                 gapless_rect,
                 egui::CornerRadius::ZERO,
                 self.ui.visuals().widgets.hovered.bg_fill,
@@ -164,6 +180,8 @@ impl<'l> StripLayout<'l> {
         } else {
             max_rect | used_rect
         };
+        // The next cell should follow where this one was laid out, not where it was painted:
+        let allocation_rect = allocation_rect.translate(laid_out_rect.min - max_rect.min);
 
         self.set_pos(allocation_rect);
 
@@ -228,6 +246,12 @@ impl<'l> StripLayout<'l> {
             }
         }
 
+        if let Some(clip_left) = flags.clip_left {
+            let mut clip_rect = child_ui.clip_rect();
+            clip_rect.min.x = clip_rect.min.x.max(clip_left);
+            child_ui.shrink_clip_rect(clip_rect);
+        }
+
         if flags.selected {
             let stroke_color = child_ui.style().visuals.selection.stroke.color;
             child_ui.style_mut().visuals.override_text_color = Some(stroke_color);
@@ -1,6 +1,10 @@
 use egui::Rangef;
 
 /// Size hint for table column/strip cell.
+///
+/// When mixing different kinds of sizes, the absolute sizes are resolved first,
+/// then the relative sizes (which are shrunk if they don't fit next to the others),
+/// and finally the remainders share whatever space is left.
 #[derive(Clone, Debug, Copy)]
 pub enum Size {
     /// Absolute size in points, with a given range of allowed sizes to resize within.
@@ -112,13 +116,29 @@ impl Sizing {
         self.sizes.push(size);
     }
 
+    /// Turn the sizes into lengths, to fill `length` with `spacing` between each of them.
+    ///
+    /// The sizes are resolved in this order:
+    ///
+    /// 1. [`Size::Absolute`] sizes get their initial size.
+    /// 2. [`Size::Relative`] sizes get their fraction of the whole `length` (not of what is left after step 1),
+    ///    clamped to their range.
+    ///    If there isn't enough room for them next to the absolute sizes, the minimum sizes of the remainders,
+    ///    and the spacing, the relative sizes are shrunk to fit. Each gives up a share of the shortfall
+    ///    proportional to how much it can shrink before reaching its minimum size.
+    /// 3. [`Size::Remainder`] sizes share whatever space is left equally, clamped to their range.
+    ///
+    /// Minimum sizes are always respected, so if they don't fit the lengths add up to more than `length`.
     pub fn to_lengths(&self, length: f32, spacing: f32) -> Vec<f32> {
         if self.sizes.is_empty() {
             return vec![];
         }
 
-        let mut num_remainders = 0;
-        let sum_non_remainder = self
+        if cfg!(debug_assertions) {
+            self.check_sizes();
+        }
+
+        let mut lengths: Vec<f32> = self
             .sizes
             .iter()
             .map(|&size| match size {
@@ -130,13 +150,45 @@ impl Sizing {
                     );
                     range.clamp(length * fraction)
                 }
-                Size::Remainder { .. } => {
-                    num_remainders += 1;
-                    0.0
-                }
+                Size::Remainder { .. } => 0.0,
             })
-            .sum::<f32>()
-            + spacing * (self.sizes.len() - 1) as f32;
+            .collect();
+
+        let total_spacing = spacing * (self.sizes.len() - 1) as f32;
+
+        // Shrink the relative sizes if they don't fit:
+        let min_length_needed = total_spacing
+            + self
+                .sizes
+                .iter()
+                .zip(&lengths)
+                .map(|(size, &length)| match size {
+                    Size::Remainder { range } => range.min,
+                    Size::Absolute { .. } | Size::Relative { .. } => length,
+                })
+                .sum::<f32>();
+        let shortfall = min_length_needed - length;
+        if 0.0 < shortfall {
+            let shrinkable = |size: &Size, length: f32| match size {
+                Size::Relative { range, .. } => (length - range.min).max(0.0),
+                Size::Absolute { .. } | Size::Remainder { .. } => 0.0,
+            };
+            let total_shrinkable: f32 = self
+                .sizes
+                .iter()
+                .zip(&lengths)
+                .map(|(size, &length)| shrinkable(size, length))
+                .sum();
+            if 0.0 < total_shrinkable {
+                let shrinkage = shortfall.min(total_shrinkable);
+                for (size, length) in self.sizes.iter().zip(&mut lengths) {
+                    *length -= shrinkable(size, *length) * shrinkage / total_shrinkable;
+                }
+            }
+        }
+
+        let mut num_remainders = self.sizes.iter().filter(|size| size.is_remainder()).count();
+        let sum_non_remainder = lengths.iter().sum::<f32>() + total_spacing;
 
         let avg_remainder_length = if num_remainders == 0 {
             0.0
@@ -158,14 +210,30 @@ impl Sizing {
             }
         };
 
-        self.sizes
+        for (size, length) in self.sizes.iter().zip(&mut lengths) {
+            if let Size::Remainder { range } = size {
+                *length = range.clamp(avg_remainder_length);
+            }
+        }
+
+        lengths
+    }
+
+    /// Report relative sizes that can't be satisfied.
+    fn check_sizes(&self) {
+        let sum_fractions: f32 = self
+            .sizes
             .iter()
-            .map(|&size| match size {
-                Size::Absolute { initial, .. } => initial,
-                Size::Relative { fraction, range } => range.clamp(length * fraction),
-                Size::Remainder { range } => range.clamp(avg_remainder_length),
+            .map(|size| match size {
+                Size::Relative { fraction, .. } => *fraction,
+                Size::Absolute { .. } | Size::Remainder { .. } => 0.0,
             })
-            .collect()
+            .sum();
+        debug_assert!(
+            sum_fractions <= 1.0 + 1e-4,
+            "The relative sizes add up to {:.0}% of the available space, which can never fit",
+            100.0 * sum_fractions
+        );
     }
 }
 
@@ -196,3 +264,29 @@ fn test_sizing() {
     assert_eq!(sizing.to_lengths(20.0, 0.0), vec![10.0, 10.0]);
     assert_eq!(sizing.to_lengths(10.0, 0.0), vec![10.0, 10.0]);
 }
+
+#[test]
+fn test_sizing_shortfall() {
+    // The relative sizes give up the shortfall in proportion to their size:
+    let sizing: Sizing = vec![Size::exact(40.0), Size::relative(0.5), Size::relative(0.25)].into();
+    assert_eq!(sizing.to_lengths(100.0, 0.0), vec![40.0, 40.0, 20.0]);
+
+    // …or rather to how much they can shrink before reaching their minimum size:
+    let sizing: Sizing = vec![
+        Size::exact(40.0),
+        Size::relative(0.5).at_least(45.0),
+        Size::relative(0.25),
+    ]
+    .into();
+    assert_eq!(sizing.to_lengths(100.0, 0.0), vec![40.0, 47.5, 12.5]);
+
+    // Room is made for the minimum size of the remainder, and for the spacing:
+    let sizing: Sizing = vec![
+        Size::relative(0.75),
+        Size::exact(20.0),
+        Size::remainder().at_least(10.0),
+    ]
+    .into();
+    assert_eq!(sizing.to_lengths(100.0, 0.0), vec![70.0, 20.0, 10.0]);
+    assert_eq!(sizing.to_lengths(120.0, 5.0), vec![80.0, 20.0, 10.0]);
+}
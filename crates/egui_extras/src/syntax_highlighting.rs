@@ -19,6 +19,32 @@ pub fn code_view_ui(
     ui.add(egui::Label::new(layout_job).selectable(true))
 }
 
+/// Edit some code with syntax highlighting.
+///
+/// The highlighting is memoized, and recomputed when the code, the language or the theme changes.
+///
+/// If you need more control over the [`egui::TextEdit`], use [`highlight`] in a [`egui::TextEdit::layouter`].
+pub fn code_editor_ui(
+    ui: &mut egui::Ui,
+    theme: &CodeTheme,
+    code: &mut String,
+    language: &str,
+) -> egui::Response {
+    let mut layouter = |ui: &egui::Ui, buf: &dyn egui::TextBuffer, wrap_width: f32| {
+        let mut layout_job = highlight(ui.ctx(), ui.style(), theme, buf.as_str(), language);
+        layout_job.wrap.max_width = wrap_width;
+        ui.fonts_mut(|f| f.layout_job(layout_job))
+    };
+
+    ui.add(
+        egui::TextEdit::multiline(code)
+            .font(TextStyle::Monospace) // for cursor height
+            .code_editor()
+            .desired_width(f32::INFINITY)
+            .layouter(&mut layouter),
+    )
+}
+
 /// Add syntax highlighting to a code string.
 ///
 /// The results are memoized, so you can call this every frame without performance penalty.
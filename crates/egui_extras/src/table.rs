@@ -3,8 +3,10 @@
 //! | fixed size | all available space/minimum | 30% of available width | fixed size |
 //! Takes all available height, so if you want something below the table, put it in a strip.
 
+use std::collections::BTreeSet;
+
 use egui::{
-    Align, Id, NumExt as _, Rangef, Rect, Response, ScrollArea, Ui, Vec2, Vec2b,
+    Align, Id, Modifiers, NumExt as _, Rangef, Rect, Response, ScrollArea, Ui, Vec2, Vec2b,
     scroll_area::{ScrollAreaOutput, ScrollBarVisibility, ScrollSource},
 };
 
@@ -247,12 +249,15 @@ impl Default for TableScrollOptions {
 pub struct TableBuilder<'a> {
     ui: &'a mut Ui,
     id_salt: Id,
+    id: Option<Id>,
     columns: Vec<Column>,
     striped: Option<bool>,
     resizable: bool,
     cell_layout: egui::Layout,
     scroll_options: TableScrollOptions,
     sense: egui::Sense,
+    selection: Option<&'a mut TableSelection>,
+    frozen_columns: usize,
 }
 
 impl<'a> TableBuilder<'a> {
@@ -261,12 +266,15 @@ impl<'a> TableBuilder<'a> {
         Self {
             ui,
             id_salt: Id::new("__table_state"),
+            id: None,
             columns: Default::default(),
             striped: None,
             resizable: false,
             cell_layout,
             scroll_options: Default::default(),
             sense: egui::Sense::hover(),
+            selection: None,
+            frozen_columns: 0,
         }
     }
 
@@ -288,6 +296,24 @@ impl<'a> TableBuilder<'a> {
         self
     }
 
+    /// Use this globally unique id for the table, instead of combining [`Self::id_salt`] with the id of the parent [`Ui`].
+    ///
+    /// The column widths are stored in [`egui::Memory`] under this id.
+    /// With the `serde` feature (and `egui` persistence enabled in your integration),
+    /// they are persisted, so widths the user resized survive restarts of your app.
+    /// A fixed id makes sure this keeps working even if you move the table to somewhere else in your ui.
+    ///
+    /// Use [`Self::reset`] to go back to the initial widths.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    fn state_id(&self) -> Id {
+        self.id.unwrap_or_else(|| self.ui.id().with(self.id_salt))
+    }
+
     /// Enable striped row background for improved readability.
     ///
     /// Default is whatever is in [`egui::Visuals::striped`].
@@ -304,6 +330,85 @@ impl<'a> TableBuilder<'a> {
         self
     }
 
+    /// Let the user select rows by clicking them.
+    ///
+    /// Clicking a row selects only that row,
+    /// Ctrl-clicking (Cmd on Mac) toggles it,
+    /// and Shift-clicking extends the selection from the last clicked row.
+    ///
+    /// The selected rows are highlighted, and the [`TableSelection`] is updated in place,
+    /// so you can read the selected row indices from it after showing the table.
+    /// Since you own the [`TableSelection`], it is kept for rows that are scrolled out of view
+    /// by [`TableBody::rows`] and [`TableBody::heterogeneous_rows`].
+    ///
+    /// This adds [`egui::Sense::click`] to [`Self::sense`].
+    /// You can still override the highlight of a row with [`TableRow::set_selected`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{Column, TableBuilder, TableSelection};
+    /// # let mut selection = TableSelection::default();
+    /// TableBuilder::new(ui)
+    ///     .column(Column::remainder())
+    ///     .selection(&mut selection)
+    ///     .body(|body| {
+    ///         body.rows(18.0, 100, |mut row| {
+    ///             let row_index = row.index();
+    ///             row.col(|ui| {
+    ///                 ui.label(format!("Row {row_index}"));
+    ///             });
+    ///         });
+    ///     });
+    ///
+    /// for row_index in selection.selected() {
+    ///     // …
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn selection(mut self, selection: &'a mut TableSelection) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
+    /// Keep the first `num_columns` columns visible when the table is scrolled horizontally.
+    ///
+    /// [`TableBuilder`] doesn't scroll horizontally by itself,
+    /// so this is for when you put the table in a horizontal [`ScrollArea`].
+    /// The frozen columns (also in the header) then stick to the left edge of the scroll area,
+    /// covering the other columns as they scroll past, with a subtle shadow along their right edge.
+    ///
+    /// Default is `0`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_extras::{Column, TableBuilder};
+    /// egui::ScrollArea::horizontal().show(ui, |ui| {
+    ///     TableBuilder::new(ui)
+    ///         .column(Column::auto())
+    ///         .columns(Column::initial(200.0), 10)
+    ///         .freeze_columns(1)
+    ///         .body(|mut body| {
+    ///             body.row(18.0, |mut row| {
+    ///                 row.col(|ui| {
+    ///                     ui.label("Always visible");
+    ///                 });
+    ///                 for i in 0..10 {
+    ///                     row.col(|ui| {
+    ///                         ui.label(format!("Column {i}"));
+    ///                     });
+    ///                 }
+    ///             });
+    ///         });
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn freeze_columns(mut self, num_columns: usize) -> Self {
+        self.frozen_columns = num_columns;
+        self
+    }
+
     /// Make the columns resizable by dragging.
     ///
     /// You can set this for individual columns with [`Column::resizable`].
@@ -449,29 +554,38 @@ impl<'a> TableBuilder<'a> {
                 * self.ui.spacing().scroll.allocated_width()
     }
 
-    /// Reset all column widths.
+    /// Reset all column widths to their initial widths.
+    ///
+    /// This also forgets any widths persisted for this table, see [`Self::id`].
     pub fn reset(&self) {
-        let state_id = self.ui.id().with(self.id_salt);
-        TableState::reset(self.ui, state_id);
+        TableState::reset(self.ui, self.state_id());
     }
 
     /// Create a header row which always stays visible and at the top
     pub fn header(self, height: f32, add_header_row: impl FnOnce(TableRow<'_, '_>)) -> Table<'a> {
         let available_width = self.available_width();
+        let state_id = self.state_id();
 
         let Self {
             ui,
-            id_salt,
+            id_salt: _,
+            id: _,
             mut columns,
             striped,
             resizable,
             cell_layout,
             scroll_options,
-            sense,
+            mut sense,
+            selection,
+            frozen_columns,
         } = self;
 
+        if selection.is_some() {
+            sense |= egui::Sense::click();
+        }
+
         for (i, column) in columns.iter_mut().enumerate() {
-            let column_resize_id = ui.id().with("resize_column").with(i);
+            let column_resize_id = state_id.with("resize_column").with(i);
             if let Some(response) = ui.ctx().read_response(column_resize_id) {
                 if response.double_clicked() {
                     column.auto_size_this_frame = true;
@@ -481,8 +595,6 @@ impl<'a> TableBuilder<'a> {
 
         let striped = striped.unwrap_or(ui.visuals().striped);
 
-        let state_id = ui.id().with(id_salt);
-
         let (is_sizing_pass, state) =
             TableState::load(ui, state_id, resizable, &columns, available_width);
 
@@ -509,6 +621,8 @@ impl<'a> TableBuilder<'a> {
                 selected: false,
                 overline: false,
                 response: &mut response,
+                selection: None,
+                frozen_columns,
             });
             layout.allocate_rect();
         });
@@ -527,6 +641,8 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options,
             sense,
+            selection,
+            frozen_columns,
         }
     }
 
@@ -536,21 +652,27 @@ impl<'a> TableBuilder<'a> {
         F: for<'b> FnOnce(TableBody<'b>),
     {
         let available_width = self.available_width();
+        let state_id = self.state_id();
 
         let Self {
             ui,
-            id_salt,
+            id_salt: _,
+            id: _,
             columns,
             striped,
             resizable,
             cell_layout,
             scroll_options,
-            sense,
+            mut sense,
+            selection,
+            frozen_columns,
         } = self;
 
-        let striped = striped.unwrap_or(ui.visuals().striped);
+        if selection.is_some() {
+            sense |= egui::Sense::click();
+        }
 
-        let state_id = ui.id().with(id_salt);
+        let striped = striped.unwrap_or(ui.visuals().striped);
 
         let (is_sizing_pass, state) =
             TableState::load(ui, state_id, resizable, &columns, available_width);
@@ -572,6 +694,8 @@ impl<'a> TableBuilder<'a> {
             cell_layout,
             scroll_options,
             sense,
+            selection,
+            frozen_columns,
         }
         .body(add_body_contents)
     }
@@ -697,6 +821,10 @@ pub struct Table<'a> {
     scroll_options: TableScrollOptions,
 
     sense: egui::Sense,
+
+    selection: Option<&'a mut TableSelection>,
+
+    frozen_columns: usize,
 }
 
 impl Table<'_> {
@@ -726,6 +854,8 @@ impl Table<'_> {
             cell_layout,
             scroll_options,
             sense,
+            mut selection,
+            frozen_columns,
         } = self;
 
         let TableScrollOptions {
@@ -792,6 +922,8 @@ impl Table<'_> {
                     scroll_to_y_range: &mut scroll_to_y_range,
                     hovered_row_index,
                     hovered_row_index_id,
+                    selection: selection.as_deref_mut(),
+                    frozen_columns,
                 });
 
                 if scroll_to_row.is_some() && scroll_to_y_range.is_none() {
@@ -811,6 +943,12 @@ impl Table<'_> {
         let bottom = ui.min_rect().bottom();
 
         let spacing_x = ui.spacing().item_spacing.x;
+        let frozen_shift = if 0 < frozen_columns {
+            frozen_columns_shift(ui, cursor_position.x)
+        } else {
+            0.0
+        };
+        let mut frozen_right = f32::NEG_INFINITY;
         let mut x = cursor_position.x - spacing_x * 0.5;
         for (i, column_width) in state.column_widths.iter_mut().enumerate() {
             let column = &columns[i];
@@ -848,9 +986,22 @@ impl Table<'_> {
 
             x += *column_width + spacing_x;
 
+            if i + 1 == frozen_columns {
+                frozen_right = x + frozen_shift;
+            }
+            // Don't show the resize handles of columns scrolled under the frozen columns:
+            let covered_by_frozen_columns = frozen_columns <= i && x < frozen_right;
+
             if column.is_auto() && (is_sizing_pass || !column_is_resizable) {
                 *column_width = width_range.clamp(max_used_widths[i]);
-            } else if column_is_resizable {
+            } else if column_is_resizable && !covered_by_frozen_columns {
+                // The frozen columns are shifted to stay visible, and so are their handles:
+                let x = if i < frozen_columns {
+                    x + frozen_shift
+                } else {
+                    x
+                };
+
                 let column_resize_id = state_id.with("resize_column").with(i);
 
                 let mut p0 = egui::pos2(x, table_top);
@@ -892,7 +1043,10 @@ impl Table<'_> {
                 let resize_hover = resize_response.hovered() && !dragging_something_else;
 
                 if resize_hover || resize_response.dragged() {
-                    ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeColumn);
+                    ui.ctx().set_cursor_icon_with_priority(
+                        egui::CursorIcon::ResizeColumn,
+                        egui::CursorIconPriority::RESIZE,
+                    );
                 }
 
                 let stroke = if resize_response.dragged() {
@@ -946,6 +1100,10 @@ pub struct TableBody<'a> {
 
     /// Used to store the hovered row index between frames.
     hovered_row_index_id: egui::Id,
+
+    selection: Option<&'a mut TableSelection>,
+
+    frozen_columns: usize,
 }
 
 impl<'a> TableBody<'a> {
@@ -993,9 +1151,14 @@ impl<'a> TableBody<'a> {
             height,
             striped: self.striped && self.row_index % 2 == 0,
             hovered: self.hovered_row_index == Some(self.row_index),
-            selected: false,
+            selected: self
+                .selection
+                .as_ref()
+                .is_some_and(|s| s.is_selected(self.row_index)),
             overline: false,
             response: &mut response,
+            selection: self.selection.as_deref_mut(),
+            frozen_columns: self.frozen_columns,
         });
         self.capture_hover_state(&response, self.row_index);
         let bottom_y = self.layout.cursor.y;
@@ -1075,9 +1238,14 @@ impl<'a> TableBody<'a> {
                 height: row_height_sans_spacing,
                 striped: self.striped && (row_index + self.row_index) % 2 == 0,
                 hovered: self.hovered_row_index == Some(row_index),
-                selected: false,
+                selected: self
+                    .selection
+                    .as_ref()
+                    .is_some_and(|s| s.is_selected(row_index)),
                 overline: false,
                 response: &mut response,
+                selection: self.selection.as_deref_mut(),
+                frozen_columns: self.frozen_columns,
             });
             self.capture_hover_state(&response, row_index);
         }
@@ -1157,9 +1325,14 @@ impl<'a> TableBody<'a> {
                     height: row_height,
                     striped: self.striped && (row_index + self.row_index) % 2 == 0,
                     hovered: self.hovered_row_index == Some(row_index),
-                    selected: false,
+                    selected: self
+                        .selection
+                        .as_ref()
+                        .is_some_and(|s| s.is_selected(row_index)),
                     overline: false,
                     response: &mut response,
+                    selection: self.selection.as_deref_mut(),
+                    frozen_columns: self.frozen_columns,
                 });
                 self.capture_hover_state(&response, row_index);
                 break;
@@ -1181,8 +1354,13 @@ impl<'a> TableBody<'a> {
                 striped: self.striped && (row_index + self.row_index) % 2 == 0,
                 hovered: self.hovered_row_index == Some(row_index),
                 overline: false,
-                selected: false,
+                selected: self
+                    .selection
+                    .as_ref()
+                    .is_some_and(|s| s.is_selected(row_index)),
                 response: &mut response,
+                selection: self.selection.as_deref_mut(),
+                frozen_columns: self.frozen_columns,
             });
             self.capture_hover_state(&response, row_index);
             cursor_y += (row_height + spacing.y) as f64;
@@ -1271,6 +1449,12 @@ pub struct TableRow<'a, 'b> {
     overline: bool,
 
     response: &'b mut Option<Response>,
+
+    /// Updated when the row is clicked, see [`TableBuilder::selection`].
+    selection: Option<&'b mut TableSelection>,
+
+    /// See [`TableBuilder::freeze_columns`].
+    frozen_columns: usize,
 }
 
 impl TableRow<'_, '_> {
@@ -1301,15 +1485,24 @@ impl TableRow<'_, '_> {
         let width = CellSize::Absolute(width);
         let height = CellSize::Absolute(self.height);
 
-        let flags = StripLayoutFlags {
+        let mut flags = StripLayoutFlags {
             clip,
             striped: self.striped,
             hovered: self.hovered,
             selected: self.selected,
             overline: self.overline,
             sizing_pass: auto_size_this_frame || self.layout.ui.is_sizing_pass(),
+            ..Default::default()
         };
 
+        if let Some((frozen_right, shift_x)) = self.frozen_columns_edge() {
+            if col_index < self.frozen_columns {
+                flags.shift_x = shift_x;
+            } else {
+                flags.clip_left = Some(frozen_right);
+            }
+        }
+
         let (used_rect, response) = self.layout.add(
             flags,
             width,
@@ -1331,6 +1524,32 @@ impl TableRow<'_, '_> {
         (used_rect, response)
     }
 
+    /// If the frozen columns are shifted to stay visible,
+    /// returns where they end on screen, and how far they are shifted.
+    fn frozen_columns_edge(&self) -> Option<(f32, f32)> {
+        if self.frozen_columns == 0 {
+            return None;
+        }
+
+        let table_left = self.layout.rect.left();
+        let shift_x = frozen_columns_shift(self.layout.ui, table_left);
+        if shift_x <= 0.0 {
+            return None;
+        }
+
+        let spacing_x = self.layout.ui.spacing().item_spacing.x;
+        let frozen_width: f32 = self
+            .widths
+            .iter()
+            .take(self.frozen_columns)
+            .map(|width| width + spacing_x)
+            .sum();
+        Some((
+            table_left + frozen_width - 0.5 * spacing_x + shift_x,
+            shift_x,
+        ))
+    }
+
     /// Set the selection highlight state for cells added after a call to this function.
     #[inline]
     pub fn set_selected(&mut self, selected: bool) {
@@ -1375,6 +1594,157 @@ impl TableRow<'_, '_> {
 impl Drop for TableRow<'_, '_> {
     #[inline]
     fn drop(&mut self) {
+        if let Some((frozen_right, _)) = self.frozen_columns_edge() {
+            let spacing_y = self.layout.ui.spacing().item_spacing.y;
+            let top = self.layout.cursor.y - 0.5 * spacing_y;
+            let rect = Rect::from_x_y_ranges(
+                frozen_right..=frozen_right + FROZEN_COLUMNS_SHADOW_WIDTH,
+                top..=top + self.height + spacing_y,
+            );
+            paint_frozen_columns_shadow(self.layout.ui, rect);
+        }
+
         self.layout.end_line();
+
+        if let (Some(selection), Some(response)) = (&mut self.selection, &*self.response) {
+            if response.clicked() {
+                let modifiers = response.ctx.input(|i| i.modifiers);
+                selection.click(self.row_index, modifiers);
+            }
+        }
     }
 }
+
+const FROZEN_COLUMNS_SHADOW_WIDTH: f32 = 8.0;
+
+/// How far to move the frozen columns to the right to keep them visible,
+/// for a table starting at `table_left` inside a horizontally scrolled [`ScrollArea`].
+fn frozen_columns_shift(ui: &Ui, table_left: f32) -> f32 {
+    let visible_left = ui.clip_rect().left() + ui.visuals().clip_rect_margin;
+    (visible_left - table_left).at_least(0.0)
+}
+
+/// A shadow fading out to the right, cast by the frozen columns on the columns scrolling underneath.
+fn paint_frozen_columns_shadow(ui: &Ui, rect: Rect) {
+    let color = ui.visuals().window_shadow.color.gamma_multiply(0.5);
+    let mut mesh = egui::Mesh::default();
+    mesh.colored_vertex(rect.left_top(), color);
+    mesh.colored_vertex(rect.right_top(), egui::Color32::TRANSPARENT);
+    mesh.colored_vertex(rect.left_bottom(), color);
+    mesh.colored_vertex(rect.right_bottom(), egui::Color32::TRANSPARENT);
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(1, 2, 3);
+    ui.painter().add(mesh);
+}
+
+// ----------------------------------------------------------------------------
+
+/// Which rows of a table are selected.
+///
+/// Pass it to [`TableBuilder::selection`] to let the user select rows by clicking them.
+/// Rows are identified by their [`TableRow::index`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TableSelection {
+    selected: BTreeSet<usize>,
+
+    /// The row that was last clicked without Shift, used to extend the selection with Shift-click.
+    anchor: Option<usize>,
+}
+
+impl TableSelection {
+    /// Is the given row selected?
+    #[inline]
+    pub fn is_selected(&self, row_index: usize) -> bool {
+        self.selected.contains(&row_index)
+    }
+
+    /// The indices of the selected rows, in ascending order.
+    #[inline]
+    pub fn selected(&self) -> &BTreeSet<usize> {
+        &self.selected
+    }
+
+    /// Are no rows selected?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    /// Select only the given row.
+    pub fn select_only(&mut self, row_index: usize) {
+        self.selected.clear();
+        self.selected.insert(row_index);
+        self.anchor = Some(row_index);
+    }
+
+    /// Deselect all rows.
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// Update the selection as if the given row was clicked while holding `modifiers`.
+    ///
+    /// * No modifiers: select only this row.
+    /// * [`Modifiers::command`]: toggle this row.
+    /// * [`Modifiers::shift`]: select all rows between the previously clicked row and this one.
+    ///   If [`Modifiers::command`] is also held, the range is added to the current selection.
+    ///
+    /// This is called for you when using [`TableBuilder::selection`].
+    pub fn click(&mut self, row_index: usize, modifiers: Modifiers) {
+        if let (true, Some(anchor)) = (modifiers.shift, self.anchor) {
+            if !modifiers.command {
+                self.selected.clear();
+            }
+            let range = anchor.min(row_index)..=anchor.max(row_index);
+            self.selected.extend(range);
+        } else if modifiers.command {
+            if !self.selected.remove(&row_index) {
+                self.selected.insert(row_index);
+            }
+            self.anchor = Some(row_index);
+        } else {
+            self.select_only(row_index);
+        }
+    }
+}
+
+#[test]
+fn table_selection_click() {
+    let mut selection = TableSelection::default();
+
+    selection.click(3, Modifiers::NONE);
+    selection.click(5, Modifiers::SHIFT);
+    assert_eq!(
+        selection.selected().iter().copied().collect::<Vec<_>>(),
+        [3, 4, 5]
+    );
+
+    // Shift-click again extends from the same anchor:
+    selection.click(1, Modifiers::SHIFT);
+    assert_eq!(
+        selection.selected().iter().copied().collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+
+    selection.click(8, Modifiers::COMMAND);
+    selection.click(2, Modifiers::COMMAND);
+    assert_eq!(
+        selection.selected().iter().copied().collect::<Vec<_>>(),
+        [1, 3, 8]
+    );
+
+    // The last ctrl-click is the new anchor:
+    selection.click(4, Modifiers::COMMAND | Modifiers::SHIFT);
+    assert_eq!(
+        selection.selected().iter().copied().collect::<Vec<_>>(),
+        [1, 2, 3, 4, 8]
+    );
+
+    selection.click(6, Modifiers::NONE);
+    assert_eq!(
+        selection.selected().iter().copied().collect::<Vec<_>>(),
+        [6]
+    );
+}
@@ -568,12 +568,12 @@ impl Painter {
             self.gl.tex_parameter_i32(
                 glow::TEXTURE_2D,
                 glow::TEXTURE_WRAP_S,
-                options.wrap_mode.glow_code() as i32,
+                options.wrap_mode_x.glow_code() as i32,
             );
             self.gl.tex_parameter_i32(
                 glow::TEXTURE_2D,
                 glow::TEXTURE_WRAP_T,
-                options.wrap_mode.glow_code() as i32,
+                options.wrap_mode_y.glow_code() as i32,
             );
             check_for_gl_error!(&self.gl, "tex_parameter");
 
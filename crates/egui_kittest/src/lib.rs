@@ -584,6 +584,32 @@ impl<'a, State> Harness<'a, State> {
         self.key_combination_modifiers(modifiers, &[key]);
     }
 
+    /// Drag the pointer from `from` to `to` with the primary button held down.
+    ///
+    /// This presses the button at `from`, moves the pointer to `to` over a couple of frames,
+    /// and releases it there, calling [`Self::run`] after each step.
+    pub fn drag(&mut self, from: Pos2, to: Pos2) {
+        let button = |pos, pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::default(),
+        };
+
+        self.event(egui::Event::PointerMoved(from));
+        self.event(button(from, true));
+        self.run();
+
+        // Move a bit first, so that the drag starts before we reach `to`:
+        for pos in [from + Vec2::new(10.0, 0.0), to] {
+            self.event(egui::Event::PointerMoved(pos));
+            self.run();
+        }
+
+        self.event(button(to, false));
+        self.run();
+    }
+
     /// Mask something. Useful for snapshot tests.
     ///
     /// Call this _after_ [`Self::run`] and before [`Self::snapshot`].
@@ -2,7 +2,7 @@
 
 use egui::{
     CentralPanel, Context, RawInput, Window,
-    accesskit::{NodeId, Role, TreeUpdate},
+    accesskit::{NodeId, Role, Toggled, TreeUpdate},
 };
 
 /// Baseline test that asserts there are no spurious nodes in the
@@ -102,6 +102,29 @@ fn toggle_button_node() {
     assert!(!toggle.is_disabled());
 }
 
+#[test]
+fn indeterminate_checkbox_node() {
+    let output = accesskit_output_single_egui_frame(|ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            ui.add(egui::Checkbox::new(&mut true, "Checked"));
+            ui.add(egui::Checkbox::new(&mut false, "Mixed").indeterminate(true));
+        });
+    });
+
+    let toggled = |label: &str| {
+        output
+            .nodes
+            .iter()
+            .find(|(_, node)| node.role() == Role::CheckBox && node.label() == Some(label))
+            .expect("Checkbox should exist in the accesskit output")
+            .1
+            .toggled()
+    };
+
+    assert_eq!(toggled("Checked"), Some(Toggled::True));
+    assert_eq!(toggled("Mixed"), Some(Toggled::Mixed));
+}
+
 #[test]
 fn multiple_disabled_widgets() {
     let output = accesskit_output_single_egui_frame(|ctx| {
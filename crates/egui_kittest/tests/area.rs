@@ -0,0 +1,36 @@
+use egui::Vec2;
+use egui_kittest::Harness;
+
+#[test]
+pub fn area_constrain_to_should_clamp_dragging() {
+    let canvas = egui::Rect::from_min_size(egui::pos2(20.0, 20.0), Vec2::new(200.0, 150.0));
+    let mut harness = Harness::new_state(
+        |ctx, rect: &mut egui::Rect| {
+            *rect = egui::Area::new(egui::Id::new("area"))
+                .default_pos(egui::pos2(50.0, 50.0))
+                .constrain_to(canvas)
+                .show(ctx, |ui| {
+                    ui.allocate_space(Vec2::new(40.0, 30.0));
+                })
+                .response
+                .rect;
+        },
+        egui::Rect::NOTHING,
+    );
+    harness.run();
+
+    // Drag far past the bottom right corner:
+    let center = harness.state().center();
+    harness.drag(center, egui::pos2(600.0, 500.0));
+    let rect = *harness.state();
+    assert!(
+        canvas.contains_rect(rect),
+        "{rect:?} should be inside {canvas:?}"
+    );
+    assert_eq!(rect.right_bottom(), canvas.right_bottom());
+
+    // Moving back should not have to make up for the overshoot:
+    let center = rect.center();
+    harness.drag(center, center - Vec2::new(60.0, 0.0));
+    assert!(harness.state().right() < canvas.right() - 40.0);
+}
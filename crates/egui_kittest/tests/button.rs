@@ -0,0 +1,35 @@
+use egui::Button;
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[test]
+fn button_subtitle_should_be_below_the_title() {
+    let mut harness = Harness::new_ui_state(
+        |ui, selected: &mut usize| {
+            if ui
+                .add(Button::selectable(*selected == 0, "Inbox").subtitle("3 unread"))
+                .clicked()
+            {
+                *selected = 0;
+            }
+            if ui
+                .add(Button::selectable(*selected == 1, "Drafts"))
+                .clicked()
+            {
+                *selected = 1;
+            }
+        },
+        1,
+    );
+    harness.run();
+
+    let inbox = harness.get_by_label("Inbox\n3 unread");
+    let drafts = harness.get_by_label("Drafts");
+    assert!(
+        drafts.rect().height() < inbox.rect().height(),
+        "The subtitle should make the button taller"
+    );
+
+    inbox.click();
+    harness.run();
+    assert_eq!(*harness.state(), 0);
+}
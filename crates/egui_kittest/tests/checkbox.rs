@@ -0,0 +1,40 @@
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[test]
+fn clicking_indeterminate_checkbox_should_check_it() {
+    for initial in [false, true] {
+        let mut harness = Harness::new_ui_state(
+            |ui, checked: &mut bool| {
+                ui.add(egui::Checkbox::new(checked, "Select all").indeterminate(true));
+            },
+            initial,
+        );
+        harness.run();
+        harness.get_by_label("Select all").click();
+        harness.run();
+        assert!(*harness.state(), "Started as {initial}");
+    }
+}
+
+#[test]
+fn indeterminate_checkbox_should_only_report_actual_changes() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (checked, changes): &mut (bool, usize)| {
+            let checkbox = egui::Checkbox::new(checked, "Select all").indeterminate(true);
+            if ui.add(checkbox).changed() {
+                *changes += 1;
+            }
+        },
+        (false, 0),
+    );
+    harness.run();
+
+    harness.get_by_label("Select all").click();
+    harness.run();
+    assert_eq!(harness.state(), &(true, 1));
+
+    // Already checked, so checking it again is no change:
+    harness.get_by_label("Select all").click();
+    harness.run();
+    assert_eq!(harness.state(), &(true, 1));
+}
@@ -0,0 +1,82 @@
+use egui::accesskit::Role;
+use egui::{ComboBox, Vec2};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[test]
+fn combobox_type_ahead_should_jump_to_matching_item() {
+    let items = ["Apple", "Banana", "Blueberry", "Cherry"];
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_ui_state(
+            |ui, selected| {
+                ComboBox::new("combobox", "Fruit").show_index(ui, selected, items.len(), |idx| {
+                    *items.get(idx).expect("Invalid index")
+                });
+            },
+            0,
+        );
+
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Fruit")
+        .click();
+    harness.run();
+
+    // Case-insensitive:
+    harness.get_by_label("Apple").type_text("B");
+    harness.run();
+    assert!(harness.get_by_label("Banana").is_focused());
+
+    // Typing the same letter again cycles through the matches…
+    harness.get_by_label("Apple").type_text("b");
+    harness.run();
+    assert!(harness.get_by_label("Blueberry").is_focused());
+
+    // …and wraps around at the end:
+    harness.get_by_label("Apple").type_text("b");
+    harness.run();
+    assert!(harness.get_by_label("Banana").is_focused());
+
+    harness.key_press(egui::Key::Enter);
+    harness.run();
+    assert_eq!(harness.state(), &1);
+}
+
+#[test]
+fn combobox_search_should_filter_items() {
+    let items = ["Austria", "Belgium", "Croatia", "Denmark"];
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 200.0))
+        .build_ui_state(
+            |ui, selected| {
+                ComboBox::new("combobox", "Country").show_index_with_search(
+                    ui,
+                    selected,
+                    items.len(),
+                    |idx| *items.get(idx).expect("Invalid index"),
+                );
+            },
+            0,
+        );
+
+    harness
+        .get_by_role_and_label(Role::ComboBox, "Country")
+        .click();
+    harness.run();
+
+    let search = harness.get_by_role(Role::TextInput);
+    assert!(search.is_focused(), "The search field should be focused");
+    search.type_text("CRO");
+    harness.run();
+
+    assert!(harness.query_by_label("Austria").is_none());
+    assert!(harness.query_by_label("Croatia").is_some());
+
+    harness.key_press(egui::Key::Enter);
+    harness.run();
+
+    assert_eq!(harness.state(), &2);
+    assert!(
+        harness.query_by_label("Croatia").is_none(),
+        "Popup should be closed"
+    );
+}
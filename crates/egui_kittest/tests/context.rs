@@ -0,0 +1,23 @@
+use egui_kittest::Harness;
+
+#[test]
+pub fn all_text_should_list_painted_text() {
+    let mut harness = Harness::new_ui_state(
+        |ui, texts: &mut Vec<String>| {
+            ui.heading("Heading");
+            _ = ui.button("Button");
+            ui.label("Label");
+            ui.label("Label");
+            ui.text_edit_singleline(&mut "Edited".to_owned());
+            *texts = ui.ctx().all_text();
+        },
+        vec![],
+    );
+    harness.run();
+
+    assert_eq!(
+        harness.state(),
+        &["Heading", "Button", "Label", "Edited"],
+        "Duplicates should be removed"
+    );
+}
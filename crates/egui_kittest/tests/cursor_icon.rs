@@ -0,0 +1,78 @@
+use egui::CursorIcon;
+use egui::accesskit::Role;
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+fn cursor_icon<State>(harness: &Harness<'_, State>) -> CursorIcon {
+    harness.output().platform_output.cursor_icon
+}
+
+#[test]
+fn drag_value_cursor_should_win_over_links() {
+    let mut harness = Harness::new_ui_state(
+        |ui, value: &mut f32| {
+            ui.add(egui::DragValue::new(value));
+            ui.label("Elsewhere");
+
+            // Like a hovered link that is added after the drag value:
+            ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+        },
+        5.0,
+    );
+
+    harness.get_by_role(Role::SpinButton).hover();
+    harness.run();
+    assert_eq!(cursor_icon(&harness), CursorIcon::ResizeHorizontal);
+
+    harness.get_by_label("Elsewhere").hover();
+    harness.run();
+    assert_eq!(cursor_icon(&harness), CursorIcon::PointingHand);
+}
+
+#[test]
+fn drag_source_cursor_should_win_over_text_and_links() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.dnd_drag_source(egui::Id::new("source"), 1_u32, |ui| {
+            ui.label("Source");
+        });
+        ui.ctx().set_cursor_icon(CursorIcon::PointingHand);
+    });
+
+    harness.get_by_label("Source").hover();
+    harness.run();
+    assert_eq!(cursor_icon(&harness), CursorIcon::Grab);
+}
+
+#[test]
+fn drag_and_drop_should_keep_a_cursor_that_was_set_directly() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.dnd_drag_source(egui::Id::new("source"), 1_u32, |ui| {
+            ui.label("Source");
+        });
+
+        // Like a drop zone that rejects the payload:
+        if egui::DragAndDrop::has_any_payload(ui.ctx()) {
+            ui.ctx()
+                .output_mut(|o| o.cursor_icon = CursorIcon::NotAllowed);
+        }
+    });
+
+    let from = harness.get_by_label("Source").rect().center();
+    harness.input_mut().events.extend([
+        egui::Event::PointerMoved(from),
+        egui::Event::PointerButton {
+            pos: from,
+            button: egui::PointerButton::Primary,
+            pressed: true,
+            modifiers: egui::Modifiers::default(),
+        },
+    ]);
+    harness.run();
+
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(from + egui::vec2(20.0, 20.0)));
+    harness.run();
+    assert!(egui::DragAndDrop::has_any_payload(&harness.ctx));
+    assert_eq!(cursor_icon(&harness), CursorIcon::NotAllowed);
+}
@@ -0,0 +1,51 @@
+use egui::Vec2;
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[test]
+pub fn dnd_payload_should_only_be_delivered_to_drop_zones() {
+    let mut harness = Harness::new_ui_state(
+        |ui, dropped: &mut Vec<u32>| {
+            ui.dnd_drag_source(egui::Id::new("source"), 7_u32, |ui| ui.label("Source"));
+            ui.add_space(50.0);
+            let (_, payload) = ui.dnd_drop_zone::<u32, _>(egui::Frame::default(), |ui| {
+                ui.label("Zone");
+            });
+            dropped.extend(payload.map(|payload| *payload));
+        },
+        vec![],
+    );
+    harness.run();
+    let source = harness.get_by_label("Source").rect().center();
+    let zone = harness.get_by_label("Zone").rect().center();
+
+    harness.drag(source, zone);
+    assert_eq!(harness.state(), &[7]);
+
+    // Dropping outside of any zone discards the payload:
+    harness.drag(source, egui::pos2(source.x + 200.0, zone.y));
+    assert_eq!(harness.state(), &[7]);
+    assert!(!egui::DragAndDrop::has_any_payload(&harness.ctx));
+}
+
+#[test]
+pub fn reorder_list_should_move_items_to_the_ends() {
+    let mut harness = Harness::new_ui_state(
+        |ui, items: &mut Vec<&'static str>| {
+            egui::dnd::reorder_list(ui, "list", items, |ui, item| {
+                ui.label(*item);
+            });
+        },
+        vec!["A", "B", "C"],
+    );
+    harness.run();
+
+    let b = harness.get_by_label("B").rect().center();
+    let below_c = harness.get_by_label("C").rect().center_bottom() + Vec2::new(0.0, 4.0);
+    harness.drag(b, below_c);
+    assert_eq!(harness.state(), &["A", "C", "B"]);
+
+    let b = harness.get_by_label("B").rect().center();
+    let above_a = harness.get_by_label("A").rect().center_top() - Vec2::new(0.0, 4.0);
+    harness.drag(b, above_a);
+    assert_eq!(harness.state(), &["B", "A", "C"]);
+}
@@ -0,0 +1,100 @@
+use egui::accesskit::Role;
+use egui::{DragValue, Modifiers, Pos2, Vec2};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+fn drag_value_harness() -> Harness<'static, f64> {
+    Harness::new_ui_state(
+        |ui, value: &mut f64| {
+            ui.add(DragValue::new(value).speed(1.0).modifier_speed(0.5, 4.0));
+        },
+        0.0,
+    )
+}
+
+/// Press the pointer on the [`DragValue`], and move it a bit so that the drag starts.
+fn start_drag(harness: &mut Harness<'_, f64>) -> Pos2 {
+    let pos = harness.get_by_role(Role::SpinButton).rect().center();
+    harness.input_mut().events.extend([
+        egui::Event::PointerMoved(pos),
+        egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed: true,
+            modifiers: Modifiers::NONE,
+        },
+    ]);
+    harness.run();
+
+    let pos = pos + Vec2::new(10.0, 0.0);
+    harness
+        .input_mut()
+        .events
+        .push(egui::Event::PointerMoved(pos));
+    harness.run();
+    pos
+}
+
+/// Continue the drag `dx` points to the right while holding down `modifiers`.
+///
+/// Returns how much the value changed.
+fn drag_right(
+    harness: &mut Harness<'_, f64>,
+    pos: &mut Pos2,
+    dx: f32,
+    modifiers: Modifiers,
+) -> f64 {
+    let before = *harness.state();
+    *pos += Vec2::new(dx, 0.0);
+    let input = harness.input_mut();
+    input.modifiers = modifiers;
+    input.events.push(egui::Event::PointerMoved(*pos));
+    harness.run();
+    *harness.state() - before
+}
+
+#[test]
+fn modifiers_should_change_the_drag_speed() {
+    for (modifiers, speed) in [
+        (Modifiers::NONE, 1.0),
+        (Modifiers::SHIFT, 0.5),
+        (Modifiers::COMMAND, 4.0),
+    ] {
+        let mut harness = drag_value_harness();
+        let mut pos = start_drag(&mut harness);
+
+        let delta = drag_right(&mut harness, &mut pos, 100.0, modifiers);
+        let expected = 100.0 * speed;
+        assert!(
+            (delta - expected).abs() <= 0.02 * expected,
+            "{modifiers:?}: changed by {delta}, expected {expected}"
+        );
+    }
+}
+
+#[test]
+fn changing_modifiers_mid_drag_should_not_make_the_value_jump() {
+    let mut harness = drag_value_harness();
+    let mut pos = start_drag(&mut harness);
+    let normal = drag_right(&mut harness, &mut pos, 100.0, Modifiers::NONE);
+
+    // Pressing a modifier without moving:
+    assert_eq!(
+        drag_right(&mut harness, &mut pos, 0.0, Modifiers::COMMAND),
+        0.0
+    );
+
+    let fast = drag_right(&mut harness, &mut pos, 100.0, Modifiers::COMMAND);
+    assert!(
+        (fast - 4.0 * normal).abs() <= 0.02 * fast,
+        "{fast} vs. {normal}"
+    );
+
+    // Releasing it without moving:
+    assert_eq!(
+        drag_right(&mut harness, &mut pos, 0.0, Modifiers::NONE),
+        0.0
+    );
+
+    let normal_again = drag_right(&mut harness, &mut pos, 100.0, Modifiers::NONE);
+    assert!((normal_again - normal).abs() <= 0.02 * normal);
+}
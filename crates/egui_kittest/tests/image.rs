@@ -0,0 +1,30 @@
+use egui::{Image, Vec2, Widget as _};
+use egui_kittest::Harness;
+
+#[test]
+pub fn image_border_should_be_painted_with_the_rounded_texture() {
+    let texture = egui::load::SizedTexture::new(egui::TextureId::default(), Vec2::splat(16.0));
+    let border = egui::Stroke::new(2.0, egui::Color32::RED);
+    let mut harness = Harness::new_ui(|ui| {
+        Image::from_texture(texture)
+            .corner_radius(4)
+            .border(border)
+            .ui(ui);
+    });
+    harness.run();
+
+    let image_rects: Vec<_> = harness
+        .output()
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            egui::Shape::Rect(rect) if rect.brush.is_some() => Some(rect),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(image_rects.len(), 1);
+    let image_rect = image_rects[0];
+    assert_eq!(image_rect.corner_radius, egui::CornerRadius::same(4));
+    assert_eq!(image_rect.stroke, border);
+    assert_eq!(image_rect.stroke_kind, egui::StrokeKind::Inside);
+}
@@ -0,0 +1,55 @@
+use egui::Vec2;
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[test]
+pub fn clicked_elsewhere_should_ignore_drags() {
+    let mut harness = Harness::new_ui_state(
+        |ui, clicked_elsewhere: &mut bool| {
+            let response = ui.button("Target");
+            _ = ui.button("Elsewhere");
+            *clicked_elsewhere |= response.clicked_elsewhere();
+        },
+        false,
+    );
+
+    // Press on "Elsewhere", then drag away before releasing:
+    let pos = harness.get_by_label("Elsewhere").rect().center();
+    harness.drag(pos, pos + Vec2::new(100.0, 50.0));
+    assert!(!*harness.state(), "A drag is not a click");
+
+    harness.get_by_label("Elsewhere").click();
+    harness.run();
+    assert!(
+        *harness.state(),
+        "Clicking another widget is clicking elsewhere"
+    );
+}
+
+#[test]
+pub fn drag_passthrough_click_should_let_clicks_through() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (clicks, drags): &mut (usize, usize)| {
+            let button = ui.button("Below");
+            let overlay = ui.interact(
+                button.rect,
+                egui::Id::new("overlay"),
+                egui::Sense::drag_passthrough_click(),
+            );
+            if button.clicked() {
+                *clicks += 1;
+            }
+            if overlay.drag_started() {
+                *drags += 1;
+            }
+        },
+        (0, 0),
+    );
+
+    harness.get_by_label("Below").click();
+    harness.run();
+    assert_eq!(harness.state(), &(1, 0), "A click should pass through");
+
+    let pos = harness.get_by_label("Below").rect().center();
+    harness.drag(pos, pos + Vec2::new(20.0, 0.0));
+    assert_eq!(harness.state(), &(1, 1), "A drag should go to the overlay");
+}
@@ -160,6 +160,96 @@ fn clicking_submenu_button_should_never_close_menu() {
     assert!(harness.query_by_label("Button in Submenu B").is_none());
 }
 
+#[test]
+fn arrow_keys_should_open_and_close_submenus() {
+    let mut harness = TestMenu::new(MenuConfig::new()).into_harness();
+
+    harness.get_by_label("Menu A").click();
+    harness.run();
+
+    harness.get_by_label_contains("Submenu A").focus();
+    harness.run();
+    assert!(harness.query_by_label("Button 0 in Submenu A").is_none());
+
+    // The right arrow opens the submenu and focuses the first item:
+    harness.key_press(egui::Key::ArrowRight);
+    harness.run();
+    assert!(harness.get_by_label("Button 0 in Submenu A").is_focused());
+
+    // The left arrow closes it again and focuses the submenu button:
+    harness.key_press(egui::Key::ArrowLeft);
+    harness.run();
+    assert!(harness.query_by_label("Button 0 in Submenu A").is_none());
+    assert!(harness.get_by_label_contains("Submenu A").is_focused());
+}
+
+#[test]
+fn arrow_keys_should_work_for_submenus_flipped_to_the_left() {
+    let mut harness = Harness::builder()
+        .with_size(egui::Vec2::new(600.0, 300.0))
+        .build_ui(|ui| {
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                ui.menu_button("Menu", |ui| {
+                    ui.menu_button("Submenu", |ui| {
+                        _ = ui.button("Submenu item");
+                    });
+                });
+            });
+        });
+
+    harness.get_by_label("Menu").click();
+    harness.run();
+    harness.get_by_label_contains("Submenu").focus();
+    harness.run();
+
+    harness.key_press(egui::Key::ArrowRight);
+    harness.run();
+    let item = harness.get_by_label("Submenu item");
+    assert!(item.is_focused());
+    // There is no room on the right, so the submenu is shown on the left of its button:
+    let button_rect = harness.get_by_label_contains("Submenu").rect();
+    assert!(item.rect().center().x < button_rect.left());
+
+    harness.key_press(egui::Key::ArrowLeft);
+    harness.run();
+    assert!(harness.query_by_label("Submenu item").is_none());
+    assert!(harness.get_by_label_contains("Submenu").is_focused());
+}
+
+#[test]
+fn arrow_left_should_not_close_submenu_while_editing_text() {
+    let mut harness = Harness::new_ui_state(
+        |ui, text: &mut String| {
+            ui.menu_button("Menu", |ui| {
+                ui.menu_button("Submenu", |ui| {
+                    ui.text_edit_singleline(text);
+                });
+            });
+        },
+        "Hello".to_owned(),
+    );
+
+    harness.get_by_label("Menu").click();
+    harness.run();
+    harness.get_by_label_contains("Submenu").focus();
+    harness.run();
+
+    // Opening the submenu focuses the text edit:
+    harness.key_press(egui::Key::ArrowRight);
+    harness.run();
+    let text_edit = harness.get_by_role(egui::accesskit::Role::TextInput);
+    assert!(text_edit.is_focused());
+
+    // The left arrow moves the text cursor instead of closing the submenu:
+    harness.key_press(egui::Key::ArrowLeft);
+    harness.run();
+    assert!(
+        harness
+            .get_by_role(egui::accesskit::Role::TextInput)
+            .is_focused()
+    );
+}
+
 #[test]
 fn menu_snapshots() {
     let mut harness = TestMenu::new(MenuConfig::new()).into_harness();
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use kittest::Queryable as _;
 
 #[test]
@@ -29,3 +31,64 @@ fn test_interactive_tooltip() {
 
     assert!(harness.state().link_clicked);
 }
+
+#[test]
+fn test_tooltip_after_custom_delay() {
+    let mut harness = egui_kittest::Harness::new_ui(|ui| {
+        ui.label("Instant").on_hover_ui_after(Duration::ZERO, |ui| {
+            ui.label("Instant tooltip");
+        });
+        ui.label("Slow")
+            .on_hover_ui_after(Duration::from_secs(2), |ui| {
+                ui.label("Slow tooltip");
+            });
+    });
+
+    // Each step is a quarter of a second:
+    harness.get_by_label("Slow").hover();
+    harness.run_steps(4);
+    assert!(harness.query_by_label("Slow tooltip").is_none());
+
+    harness.run_steps(6);
+    assert!(harness.query_by_label("Slow tooltip").is_some());
+
+    harness.get_by_label("Instant").hover();
+    harness.run();
+    assert!(harness.query_by_label("Instant tooltip").is_some());
+}
+
+#[test]
+fn test_popup_prefer_flip() {
+    let popup_overlaps_button = |prefer_flip: bool| {
+        let mut harness = egui_kittest::Harness::builder()
+            .with_size(egui::vec2(300.0, 200.0))
+            .build_ui_state(
+                |ui, overlaps: &mut bool| {
+                    ui.add_space(140.0);
+                    let button = ui.button("Anchor");
+                    let popup = egui::Popup::from_response(&button)
+                        .prefer_flip(prefer_flip)
+                        .show(|ui| {
+                            // Too tall to fit above or below the button:
+                            ui.allocate_space(egui::vec2(80.0, 170.0));
+                        });
+                    if let Some(popup) = popup {
+                        let overlap = popup.response.rect.intersect(button.rect);
+                        *overlaps = 1.0 < overlap.width() && 1.0 < overlap.height();
+                    }
+                },
+                false,
+            );
+        harness.run();
+        *harness.state()
+    };
+
+    assert!(
+        popup_overlaps_button(false),
+        "By default the popup is clamped on top of the button"
+    );
+    assert!(
+        !popup_overlaps_button(true),
+        "The popup should flip to where there is room"
+    );
+}
@@ -0,0 +1,210 @@
+use egui::{Modifiers, MouseWheelUnit, Pos2, ScrollArea, Vec2};
+use egui_kittest::Harness;
+
+const SIZE: Vec2 = Vec2::new(200.0, 200.0);
+const CENTER: Pos2 = Pos2::new(100.0, 100.0);
+
+/// Scroll the mouse wheel over the middle of the harness, holding down `modifiers`.
+fn wheel<State>(harness: &mut Harness<'_, State>, delta: Vec2, modifiers: Modifiers) {
+    let input = harness.input_mut();
+    input.modifiers = modifiers;
+    input.events.push(egui::Event::PointerMoved(CENTER));
+    input.events.push(egui::Event::MouseWheel {
+        unit: MouseWheelUnit::Point,
+        delta,
+        modifiers,
+    });
+    harness.run();
+    harness.input_mut().modifiers = Modifiers::NONE;
+}
+
+/// A tall scroll area that records the offsets it reports to [`ScrollArea::on_scroll`].
+fn on_scroll_harness() -> Harness<'static, Vec<Vec2>> {
+    Harness::builder().with_size(SIZE).build_ui_state(
+        |ui, offsets: &mut Vec<Vec2>| {
+            ScrollArea::vertical()
+                .on_scroll(|offset, _content_size| offsets.push(offset))
+                .show(ui, |ui| {
+                    ui.allocate_space(Vec2::new(100.0, 2000.0));
+                });
+        },
+        Vec::new(),
+    )
+}
+
+#[test]
+fn on_scroll_should_be_called_when_scrolling_with_the_wheel() {
+    let mut harness = on_scroll_harness();
+    harness.run();
+    assert!(harness.state().is_empty(), "Nothing was scrolled yet");
+
+    wheel(&mut harness, Vec2::new(0.0, -100.0), Modifiers::NONE);
+    let offsets = harness.state();
+    assert!(!offsets.is_empty());
+    assert!(0.0 < offsets.last().unwrap().y);
+    assert!(
+        offsets.windows(2).all(|pair| pair[0] != pair[1]),
+        "Only called when the offset changes: {offsets:?}"
+    );
+}
+
+#[test]
+fn on_scroll_should_be_called_when_scrolling_by_dragging() {
+    let mut harness = on_scroll_harness();
+    harness.run();
+
+    harness.drag(CENTER, CENTER - Vec2::new(0.0, 60.0));
+    let offsets = harness.state();
+    assert!(!offsets.is_empty());
+    assert!(0.0 < offsets.last().unwrap().y);
+}
+
+#[test]
+fn on_scroll_should_not_be_called_when_the_offset_is_unchanged() {
+    let mut harness = on_scroll_harness();
+    wheel(&mut harness, Vec2::new(0.0, -100.0), Modifiers::NONE);
+    harness.state_mut().clear();
+
+    harness.run_steps(5);
+    assert!(harness.state().is_empty());
+
+    // Scrolling past the top doesn't change the offset either:
+    wheel(&mut harness, Vec2::new(0.0, 10_000.0), Modifiers::NONE);
+    harness.state_mut().clear();
+    wheel(&mut harness, Vec2::new(0.0, 100.0), Modifiers::NONE);
+    assert!(harness.state().is_empty());
+}
+
+#[derive(Default)]
+struct ScrollToFraction {
+    content_height: f32,
+    stick_to_bottom: bool,
+
+    /// Passed to [`ScrollArea::scroll_to_fraction`] for a single frame.
+    fraction: Option<f32>,
+
+    offset: f32,
+    max_offset: f32,
+}
+
+fn scroll_to_fraction_harness(stick_to_bottom: bool) -> Harness<'static, ScrollToFraction> {
+    Harness::builder().with_size(SIZE).build_ui_state(
+        |ui, state: &mut ScrollToFraction| {
+            let mut scroll_area = ScrollArea::vertical().stick_to_bottom(state.stick_to_bottom);
+            if let Some(fraction) = state.fraction.take() {
+                scroll_area = scroll_area.scroll_to_fraction(Vec2::new(0.0, fraction));
+            }
+            let output = scroll_area.show(ui, |ui| {
+                ui.allocate_space(Vec2::new(100.0, state.content_height));
+            });
+            state.offset = output.state.offset.y;
+            state.max_offset = output.content_size.y - output.inner_rect.height();
+        },
+        ScrollToFraction {
+            content_height: 2000.0,
+            stick_to_bottom,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn scroll_to_fraction_should_scroll_to_that_part_of_the_range() {
+    let mut harness = scroll_to_fraction_harness(false);
+    harness.run();
+    assert!(0.0 < harness.state().max_offset);
+
+    for fraction in [0.5, 1.0, 0.0] {
+        harness.state_mut().fraction = Some(fraction);
+        harness.run();
+        let state = harness.state();
+        assert_eq!(state.offset, fraction * state.max_offset, "{fraction}");
+    }
+
+    // Out of range fractions are clamped:
+    harness.state_mut().fraction = Some(2.0);
+    harness.run();
+    assert_eq!(harness.state().offset, harness.state().max_offset);
+}
+
+#[test]
+fn scroll_to_fraction_should_only_stick_to_the_bottom_at_the_end() {
+    let mut harness = scroll_to_fraction_harness(true);
+    harness.run();
+    assert_eq!(harness.state().offset, harness.state().max_offset);
+
+    // Less than 1.0 unsticks it, so growing content doesn't move the offset:
+    harness.state_mut().fraction = Some(0.5);
+    harness.run();
+    let offset = harness.state().offset;
+    assert_eq!(offset, 0.5 * harness.state().max_offset);
+    harness.state_mut().content_height = 3000.0;
+    harness.run();
+    assert_eq!(harness.state().offset, offset);
+
+    // 1.0 sticks it to the bottom again:
+    harness.state_mut().fraction = Some(1.0);
+    harness.run();
+    harness.state_mut().content_height = 4000.0;
+    harness.run();
+    let state = harness.state();
+    assert!(offset < state.offset);
+    assert_eq!(state.offset, state.max_offset);
+}
+
+fn shift_to_horizontal_harness(shift_to_horizontal: bool) -> Harness<'static, Vec2> {
+    Harness::builder().with_size(SIZE).build_ui_state(
+        move |ui, offset: &mut Vec2| {
+            *offset = ScrollArea::both()
+                .shift_to_horizontal(shift_to_horizontal)
+                .show(ui, |ui| {
+                    ui.allocate_space(Vec2::new(2000.0, 2000.0));
+                })
+                .state
+                .offset;
+        },
+        Vec2::ZERO,
+    )
+}
+
+#[test]
+fn shift_and_wheel_should_scroll_horizontally() {
+    let mut harness = shift_to_horizontal_harness(true);
+    harness.run();
+
+    wheel(&mut harness, Vec2::new(0.0, -100.0), Modifiers::SHIFT);
+    assert!(0.0 < harness.state().x);
+    assert_eq!(harness.state().y, 0.0);
+}
+
+#[test]
+fn shift_and_wheel_should_scroll_vertically_without_shift_to_horizontal() {
+    let mut harness = shift_to_horizontal_harness(false);
+    harness.run();
+
+    wheel(&mut harness, Vec2::new(0.0, -100.0), Modifiers::SHIFT);
+    assert_eq!(harness.state().x, 0.0);
+    assert!(0.0 < harness.state().y);
+}
+
+#[test]
+fn shift_to_horizontal_should_use_the_horizontal_scroll_modifier() {
+    for shift_to_horizontal in [true, false] {
+        let mut harness = shift_to_horizontal_harness(shift_to_horizontal);
+        harness.ctx.options_mut(|o| {
+            o.input_options.horizontal_scroll_modifier = Modifiers::ALT;
+            o.input_options.vertical_scroll_modifier = Modifiers::SHIFT;
+        });
+        harness.run();
+
+        wheel(&mut harness, Vec2::new(0.0, -100.0), Modifiers::ALT);
+        let offset = *harness.state();
+        if shift_to_horizontal {
+            assert!(0.0 < offset.x, "{offset:?}");
+            assert_eq!(offset.y, 0.0);
+        } else {
+            assert_eq!(offset.x, 0.0);
+            assert!(0.0 < offset.y, "{offset:?}");
+        }
+    }
+}
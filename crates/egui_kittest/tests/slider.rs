@@ -0,0 +1,27 @@
+use egui_kittest::Harness;
+
+#[test]
+pub fn slider_on_release_fires_once_per_key_press() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (value, released): &mut (i32, Vec<f64>)| {
+            ui.add(
+                egui::Slider::new(value, 0..=10)
+                    .step_by(1.0)
+                    .on_release(|value| released.push(value)),
+            );
+        },
+        (5, vec![]),
+    );
+
+    harness.key_press(egui::Key::Tab);
+    harness.run();
+    assert!(harness.state().1.is_empty(), "Focusing is not a commit");
+
+    harness.key_press(egui::Key::ArrowRight);
+    harness.run();
+    harness.key_press(egui::Key::ArrowRight);
+    harness.run();
+
+    assert_eq!(harness.state().0, 7);
+    assert_eq!(harness.state().1, vec![6.0, 7.0]);
+}
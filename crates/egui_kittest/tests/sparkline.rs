@@ -0,0 +1,20 @@
+use egui::Vec2;
+use egui_kittest::Harness;
+
+#[test]
+pub fn sparkline_should_allocate_exact_size() {
+    let size = Vec2::new(60.0, 16.0);
+    let mut harness = Harness::new_ui_state(
+        |ui, rect: &mut egui::Rect| {
+            *rect = ui
+                .add(egui::Sparkline::new(&[1.0, 3.0, 2.0]).size(size))
+                .rect;
+            ui.add(egui::Sparkline::new(&[]).size(size));
+            ui.add(egui::Sparkline::new(&[2.0, 2.0]).highlight_last(true));
+        },
+        egui::Rect::NOTHING,
+    );
+    harness.run();
+
+    assert_eq!(harness.state().size(), size);
+}
@@ -0,0 +1,95 @@
+use egui::{Button, Rect, Ui};
+use egui_extras::{Column, TableBuilder};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[derive(Default)]
+struct FrozenTable {
+    scroll_offset: f32,
+    frozen_clicks: usize,
+    scrolled_clicks: usize,
+
+    /// Where the first scrolled cell can be interacted with.
+    scrolled_interact_rect: Option<Rect>,
+}
+
+impl FrozenTable {
+    fn ui(&mut self, ui: &mut Ui) {
+        egui::ScrollArea::horizontal()
+            .horizontal_scroll_offset(self.scroll_offset)
+            .show(ui, |ui| {
+                TableBuilder::new(ui)
+                    .column(Column::exact(80.0))
+                    .columns(Column::exact(100.0), 10)
+                    .freeze_columns(1)
+                    .header(20.0, |mut header| {
+                        header.col(|ui| {
+                            ui.label("Frozen header");
+                        });
+                        for i in 0..10 {
+                            header.col(|ui| {
+                                ui.label(format!("Header {i}"));
+                            });
+                        }
+                    })
+                    .body(|mut body| {
+                        body.row(30.0, |mut row| {
+                            row.col(|ui| {
+                                let button = Button::new("Frozen");
+                                if ui.add_sized(ui.available_size(), button).clicked() {
+                                    self.frozen_clicks += 1;
+                                }
+                            });
+                            row.col(|ui| {
+                                let button = Button::new("Scrolled");
+                                let response = ui.add_sized(ui.available_size(), button);
+                                if response.clicked() {
+                                    self.scrolled_clicks += 1;
+                                }
+                                self.scrolled_interact_rect = Some(response.interact_rect);
+                            });
+                            for i in 1..10 {
+                                row.col(|ui| {
+                                    ui.label(format!("Cell {i}"));
+                                });
+                            }
+                        });
+                    });
+            });
+    }
+}
+
+#[test]
+fn frozen_columns_should_stay_in_place_when_scrolling() {
+    let mut harness = Harness::new_ui_state(
+        |ui, table: &mut FrozenTable| table.ui(ui),
+        FrozenTable::default(),
+    );
+    harness.run();
+
+    let header_rect = harness.get_by_label("Frozen header").rect();
+    let frozen_rect = harness.get_by_label("Frozen").rect();
+    let scrolled_rect = harness.get_by_label("Scrolled").rect();
+    assert!(frozen_rect.right() <= scrolled_rect.left());
+
+    // Scroll the first scrolled column halfway under the frozen column:
+    harness.state_mut().scroll_offset = 60.0;
+    harness.run();
+
+    assert_eq!(harness.get_by_label("Frozen header").rect(), header_rect);
+    assert_eq!(harness.get_by_label("Frozen").rect(), frozen_rect);
+    let scrolled_rect = harness.get_by_label("Scrolled").rect();
+    assert!(
+        scrolled_rect.left() < frozen_rect.center().x,
+        "The scrolled cell should be partly under the frozen cell"
+    );
+
+    // The part under the frozen column is clipped away:
+    let interact_rect = harness.state().scrolled_interact_rect.unwrap();
+    assert!(frozen_rect.right() <= interact_rect.left());
+
+    // …so clicks there reach the frozen cell:
+    harness.get_by_label("Frozen").click();
+    harness.run();
+    assert_eq!(harness.state().frozen_clicks, 1);
+    assert_eq!(harness.state().scrolled_clicks, 0);
+}
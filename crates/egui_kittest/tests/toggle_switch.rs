@@ -0,0 +1,38 @@
+use egui::accesskit::{self, Role};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[test]
+fn toggle_switch_should_toggle_on_click_and_space() {
+    use egui_kittest::kittest::NodeT as _;
+
+    let mut harness = Harness::new_ui_state(
+        |ui, on: &mut bool| {
+            ui.toggle_switch(on, "Dark mode");
+        },
+        false,
+    );
+    harness.run();
+
+    let switch = harness.get_by_role(Role::Switch);
+    assert_eq!(
+        switch.accesskit_node().label().as_deref(),
+        Some("Dark mode")
+    );
+    assert_eq!(
+        switch.accesskit_node().toggled(),
+        Some(accesskit::Toggled::False)
+    );
+    switch.click();
+    harness.run();
+    assert!(*harness.state());
+
+    harness.get_by_role(Role::Switch).focus();
+    harness.run();
+    harness.key_press(egui::Key::Space);
+    harness.run();
+    assert!(!*harness.state());
+    assert_eq!(
+        harness.get_by_role(Role::Switch).accesskit_node().toggled(),
+        Some(accesskit::Toggled::False)
+    );
+}
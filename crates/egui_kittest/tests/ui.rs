@@ -0,0 +1,134 @@
+use egui::Vec2;
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[test]
+pub fn add_enabled_ui_should_disable_nested_widgets() {
+    let mut harness = Harness::new_ui_state(
+        |ui, clicks: &mut usize| {
+            let response = ui
+                .add_enabled_ui(false, |ui| {
+                    ui.horizontal(|ui| ui.vertical(|ui| ui.button("Nested")).inner)
+                        .inner
+                })
+                .inner;
+            assert!(!response.enabled());
+            if response.clicked() {
+                *clicks += 1;
+            }
+        },
+        0,
+    );
+
+    let button = harness.get_by_label("Nested");
+    assert!(button.is_disabled());
+    button.click();
+    harness.run();
+
+    assert_eq!(
+        harness.state(),
+        &0,
+        "Disabled button should not be clickable"
+    );
+}
+
+#[test]
+pub fn auto_id_should_be_stable_across_frames() {
+    let mut harness = Harness::new_ui_state(
+        |ui, frames: &mut Vec<Vec<egui::Id>>| {
+            let mut ids = vec![ui.auto_id()];
+            if frames.len() == 1 {
+                // Other widgets coming and going should not affect the ids:
+                _ = ui.button("Only in the second frame");
+            }
+            for _ in 0..3 {
+                ids.push(ui.auto_id());
+            }
+            frames.push(ids);
+        },
+        vec![],
+    );
+    harness.step();
+
+    let frames = harness.state();
+    assert!(frames.len() >= 2);
+    assert!(frames.iter().all(|ids| ids == &frames[0]));
+
+    let mut unique = frames[0].clone();
+    unique.sort_by_key(|id| id.value());
+    unique.dedup();
+    assert_eq!(unique.len(), 4, "All ids should be different");
+}
+
+#[test]
+fn selectable_and_radio_value_should_only_report_actual_changes() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (value, num_changes): &mut (i32, usize)| {
+            for i in 0..2 {
+                if ui
+                    .selectable_value(value, i, format!("Selectable {i}"))
+                    .changed()
+                {
+                    *num_changes += 1;
+                }
+                if ui.radio_value(value, i, format!("Radio {i}")).changed() {
+                    *num_changes += 1;
+                }
+            }
+        },
+        (0, 0),
+    );
+    harness.run();
+
+    // Clicking the already selected options changes nothing:
+    harness.get_by_label("Selectable 0").click();
+    harness.run();
+    harness.get_by_label("Radio 0").click();
+    harness.run();
+    assert_eq!(harness.state(), &(0, 0));
+
+    harness.get_by_label("Selectable 1").click();
+    harness.run();
+    assert_eq!(harness.state(), &(1, 1));
+
+    harness.get_by_label("Radio 0").click();
+    harness.run();
+    assert_eq!(harness.state(), &(0, 2));
+}
+
+#[test]
+fn with_multiplied_opacity_should_fade_all_shapes_painted_in_it() {
+    use egui::{Color32, Rect, Shape, pos2};
+
+    let rect = Rect::from_min_size(pos2(10.0, 10.0), Vec2::splat(10.0));
+    let mut harness = Harness::new_ui(|ui| {
+        ui.with_multiplied_opacity(0.5, |ui| {
+            ui.painter().rect_filled(rect, 0.0, Color32::WHITE);
+
+            // Also painters that aren't the one of the ui:
+            let mut painter = ui.ctx().layer_painter(ui.layer_id());
+            painter.set_opacity(0.5);
+            painter.rect_filled(rect, 0.0, Color32::WHITE);
+        });
+        assert_eq!(ui.opacity(), 1.0, "Should only affect the contents");
+        ui.painter().rect_filled(rect, 0.0, Color32::RED);
+    });
+    harness.run();
+
+    let fills: Vec<Color32> = harness
+        .output()
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            Shape::Rect(shape) if shape.rect == rect => Some(shape.fill),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        fills,
+        vec![
+            Color32::WHITE.gamma_multiply(0.5),
+            Color32::WHITE.gamma_multiply(0.5).gamma_multiply(0.5),
+            Color32::RED,
+        ]
+    );
+}
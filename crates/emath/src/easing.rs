@@ -18,6 +18,43 @@ pub fn linear(t: f32) -> f32 {
     t
 }
 
+/// The classic smoothstep: `3t² - 2t³`.
+///
+/// Starts and ends with zero velocity, like a gentler [`cubic_in_out`].
+/// The input is clamped to `[0, 1]`.
+///
+/// ```
+/// # use emath::easing::smoothstep;
+/// assert_eq!(smoothstep(0.0), 0.0);
+/// assert_eq!(smoothstep(0.5), 0.5);
+/// assert_eq!(smoothstep(1.0), 1.0);
+/// assert_eq!(smoothstep(2.0), 1.0);
+/// assert!(smoothstep(0.25) < 0.25);
+/// ```
+#[inline]
+pub fn smoothstep(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Ken Perlin's smootherstep: `6t⁵ - 15t⁴ + 10t³`.
+///
+/// Like [`smoothstep`], but also starts and ends with zero acceleration.
+/// The input is clamped to `[0, 1]`.
+///
+/// ```
+/// # use emath::easing::{smootherstep, smoothstep};
+/// assert_eq!(smootherstep(0.0), 0.0);
+/// assert_eq!(smootherstep(0.5), 0.5);
+/// assert_eq!(smootherstep(1.0), 1.0);
+/// assert!(smootherstep(0.25) < smoothstep(0.25));
+/// ```
+#[inline]
+pub fn smootherstep(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    t * t * t * (t * (6.0 * t - 15.0) + 10.0)
+}
+
 /// <https://easings.net/#easeInQuad>
 ///
 /// Modeled after the parabola `y = x^2`
@@ -119,7 +119,19 @@ impl Rect {
         }
     }
 
-    /// Bounding-box around the points.
+    /// Bounding-box around the points, e.g. the corners of a rotated rectangle.
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Rect};
+    /// // Rotating a square by 45° makes its bounds larger:
+    /// let rect = Rect::from_center_size(pos2(0.0, 0.0), vec2(2.0, 2.0));
+    /// let quad = rect.rotate_around(rect.center(), std::f32::consts::FRAC_PI_4);
+    /// let bounds = Rect::from_points(&quad);
+    /// assert!((bounds.width() - 2.0 * 2.0_f32.sqrt()).abs() < 1e-5);
+    /// assert!((bounds.height() - 2.0 * 2.0_f32.sqrt()).abs() < 1e-5);
+    /// assert!(bounds.contains_rect(rect));
+    /// ```
+    #[doc(alias = "bounding_of_corners")]
     pub fn from_points(points: &[Pos2]) -> Self {
         let mut rect = Self::NOTHING;
         for &p in points {
@@ -245,6 +257,31 @@ impl Rect {
         )
     }
 
+    /// The four corners of the rectangle, rotated by `angle` radians around `center`.
+    ///
+    /// The corners are returned in the order `[min, right_top, max, left_bottom]`
+    /// (before rotation), like [`crate::TSTransform::mul_rect_to_quad`].
+    /// A positive angle rotates clockwise on screen (since y points down), like [`Rot2::from_angle`].
+    ///
+    /// Use [`point_in_quad`] to check if a point is inside the result,
+    /// or [`Self::rotate_bb`] if you only need the bounding box.
+    ///
+    /// ```
+    /// # use emath::{point_in_quad, pos2, vec2, Rect};
+    /// let rect = Rect::from_center_size(pos2(10.0, 10.0), vec2(8.0, 2.0));
+    /// let quad = rect.rotate_around(rect.center(), std::f32::consts::FRAC_PI_2);
+    ///
+    /// // The wide rectangle is now tall:
+    /// assert!(point_in_quad(quad, pos2(10.0, 13.0)));
+    /// assert!(!point_in_quad(quad, pos2(13.0, 10.0)));
+    /// ```
+    #[must_use]
+    pub fn rotate_around(self, center: Pos2, angle: f32) -> [Pos2; 4] {
+        let rot = Rot2::from_angle(angle);
+        [self.min, self.right_top(), self.max, self.left_bottom()]
+            .map(|corner| center + rot * (corner - center))
+    }
+
     #[must_use]
     #[inline]
     pub fn intersects(self, other: Self) -> bool {
@@ -712,6 +749,46 @@ impl Rect {
     }
 }
 
+/// Is the point inside the convex quadrilateral (or on its edge)?
+///
+/// The corners must be in order around the quad, but can be either clockwise or counter-clockwise,
+/// e.g. the output of [`Rect::rotate_around`] or [`crate::TSTransform::mul_rect_to_quad`].
+///
+/// This is useful for hit-testing rotated rectangles.
+///
+/// A degenerate quad (with no area, e.g. a rect scaled to zero width) contains no points.
+///
+/// ```
+/// # use emath::{point_in_quad, pos2};
+/// let diamond = [pos2(0.0, -1.0), pos2(1.0, 0.0), pos2(0.0, 1.0), pos2(-1.0, 0.0)];
+/// assert!(point_in_quad(diamond, pos2(0.0, 0.0)));
+/// assert!(point_in_quad(diamond, pos2(0.5, 0.5)));
+/// assert!(!point_in_quad(diamond, pos2(0.75, 0.75)));
+/// ```
+pub fn point_in_quad(quad: [Pos2; 4], p: Pos2) -> bool {
+    let twice_area: f32 = (0..4)
+        .map(|i| {
+            let (a, b) = (quad[i], quad[(i + 1) % 4]);
+            a.x * b.y - b.x * a.y
+        })
+        .sum();
+    let size = Rect::from_points(&quad).size();
+    if twice_area.is_nan() || twice_area.abs() <= f32::EPSILON * size.length_sq() {
+        return false; // degenerate
+    }
+
+    let mut any_positive = false;
+    let mut any_negative = false;
+    for i in 0..4 {
+        let edge = quad[(i + 1) % 4] - quad[i];
+        let to_p = p - quad[i];
+        let cross = edge.x * to_p.y - edge.y * to_p.x;
+        any_positive |= 0.0 < cross;
+        any_negative |= cross < 0.0;
+    }
+    !(any_positive && any_negative)
+}
+
 impl fmt::Debug for Rect {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(precision) = f.precision() {
@@ -854,6 +931,31 @@ mod tests {
         assert!(rect.intersects_ray(pos2(4.0, 2.0), Vec2::LEFT));
     }
 
+    #[test]
+    fn test_point_in_degenerate_quad() {
+        let line = [
+            pos2(0.0, 0.0),
+            pos2(10.0, 10.0),
+            pos2(10.0, 10.0),
+            pos2(0.0, 0.0),
+        ];
+        assert!(!point_in_quad(line, pos2(5.0, 5.0)));
+        assert!(!point_in_quad(line, pos2(0.0, 0.0)));
+
+        let flattened =
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(0.0, 10.0)).rotate_around(pos2(0.0, 5.0), 0.5);
+        assert!(!point_in_quad(flattened, pos2(0.0, 5.0)));
+
+        let nan = [Pos2::ZERO, Pos2::ZERO, pos2(f32::NAN, 1.0), pos2(0.0, 1.0)];
+        assert!(!point_in_quad(nan, pos2(0.0, 0.5)));
+
+        let square = Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0));
+        assert!(point_in_quad(
+            square.rotate_around(square.center(), 0.5),
+            pos2(5.0, 5.0)
+        ));
+    }
+
     #[test]
     fn test_ray_from_center_intersection() {
         let rect = Rect::from_min_max(pos2(1.0, 1.0), pos2(3.0, 3.0));
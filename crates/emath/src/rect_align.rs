@@ -265,4 +265,59 @@ impl RectAlign {
 
         first_choice
     }
+
+    /// Like [`RectAlign::find_best_align`], but if no alternative fits,
+    /// the one that leaves the largest part of the child rect inside the `screen_rect` is returned.
+    ///
+    /// This makes e.g. a popup near the bottom of the screen flip upwards if there is more room there,
+    /// instead of being clamped on top of its parent.
+    ///
+    /// If several alternatives have the same room, the first of them is returned.
+    /// If no alternatives are given, `None` is returned.
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Rect, RectAlign};
+    /// let screen_rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+    /// let parent_rect = Rect::from_min_size(pos2(0.0, 70.0), vec2(40.0, 10.0));
+    ///
+    /// // Too tall to fit both above and below:
+    /// let size = vec2(40.0, 80.0);
+    /// let aligns = [RectAlign::BOTTOM_START, RectAlign::TOP_START];
+    ///
+    /// let best = RectAlign::find_best_align(aligns.into_iter(), screen_rect, parent_rect, 0.0, size);
+    /// assert_eq!(best, Some(RectAlign::BOTTOM_START));
+    ///
+    /// let roomiest =
+    ///     RectAlign::find_roomiest_align(aligns.into_iter(), screen_rect, parent_rect, 0.0, size);
+    /// assert_eq!(roomiest, Some(RectAlign::TOP_START));
+    /// ```
+    pub fn find_roomiest_align(
+        values_to_try: impl Iterator<Item = Self>,
+        screen_rect: Rect,
+        parent_rect: Rect,
+        gap: f32,
+        expected_size: Vec2,
+    ) -> Option<Self> {
+        let mut roomiest: Option<(Self, f32)> = None;
+
+        for align in values_to_try {
+            let suggested_popup_rect = align.align_rect(&parent_rect, expected_size, gap);
+
+            if screen_rect.contains_rect(suggested_popup_rect) {
+                return Some(align);
+            }
+
+            let visible = screen_rect.intersect(suggested_popup_rect);
+            let room = if visible.is_positive() {
+                visible.area()
+            } else {
+                0.0
+            };
+            if roomiest.is_none_or(|(_, best_room)| best_room < room) {
+                roomiest = Some((align, room));
+            }
+        }
+
+        roomiest.map(|(align, _)| align)
+    }
 }
@@ -3,6 +3,17 @@ use crate::{Pos2, Rect, Vec2, pos2, remap, remap_clamp};
 /// Linearly transforms positions from one [`Rect`] to another.
 ///
 /// [`RectTransform`] stores the rectangles, and therefore supports clamping and culling.
+///
+/// Unlike [`crate::TSTransform`], this can scale the x and y axes independently:
+///
+/// ```
+/// # use emath::{pos2, vec2, Rect, RectTransform};
+/// let from = Rect::from_min_size(pos2(0.0, 0.0), vec2(1.0, 1.0));
+/// let to = Rect::from_min_size(pos2(10.0, 10.0), vec2(100.0, 20.0));
+/// let transform = RectTransform::from_to(from, to);
+/// assert_eq!(transform.scale(), vec2(100.0, 20.0));
+/// assert_eq!(transform * pos2(0.5, 0.5), pos2(60.0, 20.0));
+/// ```
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
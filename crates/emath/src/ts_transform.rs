@@ -1,9 +1,14 @@
-use crate::{Pos2, Rect, Vec2};
+use crate::{Pos2, Rect, Vec2, lerp};
 
 /// Linearly transforms positions via a translation, then a scaling.
 ///
 /// [`TSTransform`] first scales points with the scaling origin at `0, 0`
 /// (the top left corner), then translates them.
+///
+/// The scaling is always uniform, i.e. the same along both axes.
+/// This is what lets egui transform whole layers, including text and circles, without distorting them.
+/// If you need independent scaling of the x and y axes (e.g. for the axes of a plot),
+/// use a [`crate::RectTransform`] instead.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -89,8 +94,30 @@ impl TSTransform {
         self.scaling * pos + self.translation
     }
 
+    /// Transforms a length, e.g. a stroke width or a radius.
+    ///
+    /// Only the scaling affects lengths; the translation is ignored.
+    /// [`TSTransform`] always scales uniformly, so the result is the same regardless of direction.
+    /// The result is never negative, even if [`Self::scaling`] is.
+    ///
+    /// ```
+    /// # use emath::{vec2, TSTransform};
+    /// let ts = TSTransform::new(vec2(100.0, 0.0), 2.5);
+    /// assert_eq!(ts.scale_length(2.0), 5.0);
+    /// assert_eq!(TSTransform::from_scaling(-2.0).scale_length(2.0), 4.0);
+    /// ```
+    #[inline]
+    pub fn scale_length(&self, len: f32) -> f32 {
+        self.scaling.abs() * len
+    }
+
     /// Transforms the given rectangle.
     ///
+    /// This transforms `min` and `max` independently.
+    /// If [`Self::scaling`] is negative the resulting rectangle is flipped, with `min > max`,
+    /// and is not a valid axis-aligned rectangle.
+    /// Use [`Self::mul_rect_to_quad`] if you need the actual transformed corners.
+    ///
     /// ```
     /// # use emath::{pos2, vec2, Rect, TSTransform};
     /// let rect = Rect::from_min_max(pos2(5.0, 5.0), pos2(15.0, 10.0));
@@ -106,6 +133,129 @@ impl TSTransform {
             max: self.mul_pos(rect.max),
         }
     }
+
+    /// Transforms the four corners of the given rectangle.
+    ///
+    /// The corners are returned in the order `[min, right_top, max, left_bottom]`,
+    /// i.e. clockwise starting at [`Rect::min`], so the result can be used directly as a closed path.
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Rect, TSTransform};
+    /// let rect = Rect::from_min_max(pos2(1.0, 2.0), pos2(3.0, 4.0));
+    ///
+    /// // A negative scaling is a rotation of 180° around the origin:
+    /// let ts = TSTransform::new(vec2(10.0, 0.0), -2.0);
+    /// assert_eq!(
+    ///     ts.mul_rect_to_quad(rect),
+    ///     [pos2(8.0, -4.0), pos2(4.0, -4.0), pos2(4.0, -8.0), pos2(8.0, -8.0)]
+    /// );
+    /// ```
+    #[inline]
+    pub fn mul_rect_to_quad(&self, rect: Rect) -> [Pos2; 4] {
+        [
+            self.mul_pos(rect.min),
+            self.mul_pos(rect.right_top()),
+            self.mul_pos(rect.max),
+            self.mul_pos(rect.left_bottom()),
+        ]
+    }
+
+    /// The axis-aligned bounds of the transformed rectangle.
+    ///
+    /// Unlike [`Self::mul_rect`], the result is always a valid rectangle,
+    /// even if [`Self::scaling`] is negative.
+    ///
+    /// [`TSTransform`] has no rotation, so the bounds are never larger than the transformed rectangle.
+    /// For rotated rectangles, use [`Rect::rotate_around`] and [`Rect::from_points`].
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Rect, TSTransform};
+    /// let rect = Rect::from_min_max(pos2(1.0, 2.0), pos2(3.0, 4.0));
+    /// let ts = TSTransform::new(vec2(10.0, 0.0), -2.0);
+    /// assert_eq!(
+    ///     ts.transform_rect_bounds(rect),
+    ///     Rect::from_min_max(pos2(4.0, -8.0), pos2(8.0, -4.0))
+    /// );
+    /// ```
+    #[inline]
+    pub fn transform_rect_bounds(&self, rect: Rect) -> Rect {
+        Rect::from_points(&self.mul_rect_to_quad(rect))
+    }
+
+    /// Linearly interpolate towards another transform, so that `0.0 => self, 1.0 => other`.
+    ///
+    /// The scaling and translation are interpolated independently,
+    /// which is what you want when animating e.g. the pan and zoom of a camera.
+    ///
+    /// ```
+    /// # use emath::{vec2, TSTransform};
+    /// let a = TSTransform::new(vec2(0.0, 10.0), 1.0);
+    /// let b = TSTransform::new(vec2(20.0, 0.0), 3.0);
+    /// assert_eq!(a.lerp(&b, 0.0), a);
+    /// assert_eq!(a.lerp(&b, 0.5), TSTransform::new(vec2(10.0, 5.0), 2.0));
+    /// assert_eq!(a.lerp(&b, 1.0), b);
+    /// ```
+    #[inline]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            scaling: lerp(self.scaling..=other.scaling, t),
+            translation: lerp(self.translation..=other.translation, t),
+        }
+    }
+
+    /// The transform as a homogeneous 3x3 matrix, in column-major order.
+    ///
+    /// This is the layout expected by e.g. a `mat3x3<f32>` uniform in WGSL or a `mat3` in GLSL,
+    /// and maps column vectors `[x, y, 1]` the same way as [`Self::mul_pos`].
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, TSTransform};
+    /// let ts = TSTransform::new(vec2(2.0, 3.0), 4.0);
+    /// let m = ts.to_matrix3_array();
+    /// assert_eq!(m, [4.0, 0.0, 0.0, 0.0, 4.0, 0.0, 2.0, 3.0, 1.0]);
+    /// assert_eq!(TSTransform::from_matrix3_array(m), ts);
+    ///
+    /// for ts in [
+    ///     TSTransform::IDENTITY,
+    ///     TSTransform::from_scaling(0.25),
+    ///     TSTransform::from_translation(vec2(-7.0, 0.5)),
+    /// ] {
+    ///     assert_eq!(TSTransform::from_matrix3_array(ts.to_matrix3_array()), ts);
+    /// }
+    /// ```
+    #[inline]
+    pub fn to_matrix3_array(self) -> [f32; 9] {
+        let Self {
+            scaling,
+            translation,
+        } = self;
+        [
+            scaling,
+            0.0,
+            0.0,
+            0.0,
+            scaling,
+            0.0,
+            translation.x,
+            translation.y,
+            1.0,
+        ]
+    }
+
+    /// Inverse of [`Self::to_matrix3_array`].
+    ///
+    /// The matrix is expected to be column-major and of the form produced by [`Self::to_matrix3_array`],
+    /// i.e. a uniform scale followed by a translation.
+    /// Any other components (rotation, shear, non-uniform scale, projection) can not be represented
+    /// by a [`TSTransform`]. They trigger a debug assertion, and are ignored in release builds.
+    #[inline]
+    pub fn from_matrix3_array(m: [f32; 9]) -> Self {
+        debug_assert!(
+            m[1] == 0.0 && m[3] == 0.0 && m[0] == m[4],
+            "TSTransform only supports uniform scaling and translation, got {m:?}"
+        );
+        Self::new(Vec2::new(m[6], m[7]), m[0])
+    }
 }
 
 /// Transforms the position.
@@ -234,6 +234,50 @@ impl Vec2 {
         vec2(cos, sin)
     }
 
+    /// Rotates the vector by the given CW angle (in radians).
+    ///
+    /// This is the same as multiplying with [`crate::Rot2::from_angle`].
+    ///
+    /// ```
+    /// # use emath::{vec2, Vec2};
+    /// use std::f32::consts::TAU;
+    ///
+    /// assert!((Vec2::X.rotate(0.25 * TAU) - Vec2::Y).length() < 1e-5);
+    /// assert!((vec2(2.0, 0.0).rotate(0.5 * TAU) - vec2(-2.0, 0.0)).length() < 1e-5);
+    /// assert!((Vec2::angled(1.0).rotate(0.5).angle() - 1.5).abs() < 1e-5);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn rotate(self, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        vec2(cos * self.x - sin * self.y, sin * self.x + cos * self.y)
+    }
+
+    /// The signed CW angle (in radians) to rotate `self` by to point in the same direction as `other`.
+    ///
+    /// The result is in the range `-π..=π`.
+    /// The lengths of the vectors don't matter.
+    ///
+    /// ```
+    /// # use emath::{vec2, Vec2};
+    /// use std::f32::consts::{PI, TAU};
+    ///
+    /// assert_eq!(Vec2::X.angle_between(Vec2::Y), 0.25 * TAU);
+    /// assert_eq!(Vec2::Y.angle_between(Vec2::X), -0.25 * TAU);
+    /// assert_eq!(Vec2::X.angle_between(vec2(5.0, 0.0)), 0.0);
+    /// assert_eq!(Vec2::X.angle_between(-Vec2::X), PI);
+    ///
+    /// // Always takes the shortest way around:
+    /// let a = Vec2::angled(0.9 * PI);
+    /// let b = Vec2::angled(-0.9 * PI);
+    /// assert!((a.angle_between(b) - 0.2 * PI).abs() < 1e-5);
+    /// ```
+    #[inline]
+    pub fn angle_between(self, other: Self) -> f32 {
+        let cross = self.x * other.y - self.y * other.x;
+        cross.atan2(self.dot(other))
+    }
+
     #[must_use]
     #[inline(always)]
     pub fn floor(self) -> Self {
@@ -16,6 +16,18 @@ pub enum ColorMode {
     /// **This cannot be serialized**
     #[cfg_attr(feature = "serde", serde(skip))]
     UV(Arc<dyn Fn(Rect, Pos2) -> Color32 + Send + Sync>),
+
+    /// Provide a callback which takes in how far along the path a point is and converts it to a color.
+    ///
+    /// The argument goes from `0.0` at the start of the path to `1.0` at the end, by length.
+    /// For closed paths, the end is back at the first point.
+    ///
+    /// The callback is called once per point of the path, and the colors are interpolated in between.
+    /// See [`crate::PathStroke::new_gradient`].
+    ///
+    /// **This cannot be serialized**
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Gradient(Arc<dyn Fn(f32) -> Color32 + Send + Sync>),
 }
 
 impl Default for ColorMode {
@@ -29,6 +41,7 @@ impl Debug for ColorMode {
         match self {
             Self::Solid(arg0) => f.debug_tuple("Solid").field(arg0).finish(),
             Self::UV(_arg0) => f.debug_tuple("UV").field(&"<closure>").finish(),
+            Self::Gradient(_arg0) => f.debug_tuple("Gradient").field(&"<closure>").finish(),
         }
     }
 }
@@ -38,6 +51,7 @@ impl PartialEq for ColorMode {
         match (self, other) {
             (Self::Solid(l0), Self::Solid(r0)) => l0 == r0,
             (Self::UV(_l0), Self::UV(_r0)) => false,
+            (Self::Gradient(_l0), Self::Gradient(_r0)) => false,
             _ => false,
         }
     }
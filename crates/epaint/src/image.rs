@@ -124,6 +124,15 @@ impl ColorImage {
         Self::new(size, pixels)
     }
 
+    /// Create a [`ColorImage`] from flat RGBA data where the color channels
+    /// have already been multiplied by alpha.
+    ///
+    /// The bytes are stored as-is, without any conversion.
+    /// Use this when your decoder outputs premultiplied pixels:
+    /// passing those to [`Self::from_rgba_unmultiplied`] would multiply by alpha a second time,
+    /// darkening semi-transparent pixels (e.g. anti-aliased edges).
+    ///
+    /// Panics if `size[0] * size[1] * 4 != rgba.len()`.
     pub fn from_rgba_premultiplied(size: [usize; 2], rgba: &[u8]) -> Self {
         assert_eq!(
             size[0] * size[1] * 4,
@@ -448,3 +457,18 @@ impl ImageDelta {
         self.pos.is_none()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_rgba_premultiplied_is_not_remultiplied() {
+        let rgba = [100, 50, 25, 128];
+        let image = ColorImage::from_rgba_premultiplied([1, 1], &rgba);
+        assert_eq!(image.pixels[0].to_array(), rgba);
+
+        let unmultiplied = ColorImage::from_rgba_unmultiplied([1, 1], &rgba);
+        assert_ne!(unmultiplied.pixels[0], image.pixels[0]);
+    }
+}
@@ -56,11 +56,11 @@ pub use self::{
     mesh::{Mesh, Mesh16, Vertex},
     shadow::Shadow,
     shapes::{
-        CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PaintCallbackInfo, PathShape,
-        QuadraticBezierShape, RectShape, Shape, TextShape,
+        ArcShape, CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PaintCallbackInfo,
+        PathShape, QuadraticBezierShape, RectShape, Shape, TextShape,
     },
     stats::PaintStats,
-    stroke::{PathStroke, Stroke, StrokeKind},
+    stroke::{LineJoin, PathStroke, Stroke, StrokeKind},
     tessellator::{TessellationOptions, Tessellator},
     text::{FontFamily, FontId, Fonts, FontsView, Galley},
     texture_atlas::TextureAtlas,
@@ -1,4 +1,4 @@
-use crate::{Color32, TextureId, WHITE_UV, emath};
+use crate::{Color32, Rgba, TextureId, WHITE_UV, emath};
 use emath::{Pos2, Rect, Rot2, TSTransform, Vec2};
 
 /// The 2D vertex type.
@@ -300,6 +300,11 @@ impl Mesh {
     }
 
     /// Transform the mesh in-place with the given transform.
+    ///
+    /// Only the vertex positions are affected; the uv coordinates, colors and indices are left as-is.
+    /// This bakes the transform into the geometry, so the result can be cached.
+    ///
+    /// See also [`Self::rotate`].
     pub fn transform(&mut self, transform: TSTransform) {
         for v in &mut self.vertices {
             v.pos = transform * v.pos;
@@ -314,6 +319,22 @@ impl Mesh {
             v.pos = origin + rot * (v.pos - origin);
         }
     }
+
+    /// Multiply the color of every vertex by the given color, in-place.
+    ///
+    /// The multiplication is done in linear space.
+    /// Since both colors are premultiplied, the alpha of the result is the product of the two alphas.
+    ///
+    /// This is useful for tinting a cached mesh without rebuilding it.
+    pub fn tint(&mut self, color: Color32) {
+        if color == Color32::WHITE {
+            return;
+        }
+        let tint = Rgba::from(color);
+        for v in &mut self.vertices {
+            v.color = (Rgba::from(v.color) * tint).into();
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -344,3 +365,51 @@ impl Mesh16 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use emath::{pos2, vec2};
+
+    use super::*;
+
+    #[test]
+    fn test_mesh_transform() {
+        let mut mesh = Mesh::default();
+        mesh.add_rect_with_uv(
+            Rect::from_min_max(pos2(1.0, 2.0), pos2(3.0, 5.0)),
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+            Color32::RED,
+        );
+        let original = mesh.clone();
+
+        let transform = TSTransform::new(vec2(10.0, -4.0), 2.0);
+        mesh.transform(transform);
+
+        assert_eq!(mesh.indices, original.indices);
+        assert_eq!(mesh.vertices.len(), original.vertices.len());
+        for (transformed, original) in mesh.vertices.iter().zip(&original.vertices) {
+            let expected = pos2(2.0 * original.pos.x + 10.0, 2.0 * original.pos.y - 4.0);
+            assert_eq!(transformed.pos, expected);
+            assert_eq!(transformed.uv, original.uv);
+            assert_eq!(transformed.color, original.color);
+        }
+    }
+
+    #[test]
+    fn test_mesh_tint() {
+        let mut mesh = Mesh::default();
+        mesh.add_colored_rect(
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+            Color32::WHITE,
+        );
+
+        mesh.tint(Color32::RED);
+        assert!(mesh.vertices.iter().all(|v| v.color == Color32::RED));
+
+        // Tinting with a translucent color multiplies the alpha:
+        let half_white = Color32::from_white_alpha(128);
+        mesh.tint(half_white);
+        let expected = Color32::from_rgba_premultiplied(128, 0, 0, 128);
+        assert!(mesh.vertices.iter().all(|v| v.color == expected));
+    }
+}
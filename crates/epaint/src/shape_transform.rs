@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::{
-    CircleShape, Color32, ColorMode, CubicBezierShape, EllipseShape, Mesh, PathShape,
+    ArcShape, CircleShape, Color32, ColorMode, CubicBezierShape, EllipseShape, Mesh, PathShape,
     QuadraticBezierShape, RectShape, Shape, TextShape, color,
 };
 
@@ -58,6 +58,16 @@ pub fn adjust_colors(
             fill,
             stroke,
         })
+        | Shape::Arc(ArcShape {
+            center: _,
+            radius: _,
+            inner_radius: _,
+            start_angle: _,
+            end_angle: _,
+            closed: _,
+            fill,
+            stroke,
+        })
         | Shape::Rect(RectShape {
             rect: _,
             corner_radius: _,
@@ -130,5 +140,13 @@ fn adjust_color_mode(
                 color
             })));
         }
+        color::ColorMode::Gradient(callback) => {
+            let callback = callback.clone();
+            *color_mode = color::ColorMode::Gradient(Arc::new(move |t| {
+                let mut color = callback(t);
+                adjust_color(&mut color);
+                color
+            }));
+        }
     }
 }
@@ -0,0 +1,188 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use crate::*;
+
+/// How to paint a circular arc, a pie slice, or a segment of a donut.
+///
+/// Angles are in radians, measured clockwise from the positive x-axis
+/// (since y points down on screen), like [`Vec2::angled`].
+/// If the two angles are a full turn or more apart, the whole circle (or ring) is painted.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ArcShape {
+    pub center: Pos2,
+
+    /// The outer radius.
+    pub radius: f32,
+
+    /// If larger than zero, the shape is a segment of a donut instead of a pie slice.
+    ///
+    /// Only used for closed shapes.
+    pub inner_radius: f32,
+
+    pub start_angle: f32,
+    pub end_angle: f32,
+
+    /// If `false`, only the outer arc is stroked, and there is no fill.
+    ///
+    /// If `true`, the outline also includes the radial edges (and the inner arc, for donuts).
+    pub closed: bool,
+
+    pub fill: Color32,
+
+    /// The stroke is centered on the outline.
+    pub stroke: Stroke,
+}
+
+impl ArcShape {
+    /// A pie slice between the two angles.
+    ///
+    /// Use [`Self::with_inner_radius`] to turn it into a segment of a donut.
+    #[inline]
+    pub fn pie(
+        center: Pos2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        fill_color: impl Into<Color32>,
+        stroke: impl Into<Stroke>,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            inner_radius: 0.0,
+            start_angle,
+            end_angle,
+            closed: true,
+            fill: fill_color.into(),
+            stroke: stroke.into(),
+        }
+    }
+
+    /// An open arc between the two angles.
+    #[inline]
+    pub fn stroke(
+        center: Pos2,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        stroke: impl Into<Stroke>,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            inner_radius: 0.0,
+            start_angle,
+            end_angle,
+            closed: false,
+            fill: Color32::TRANSPARENT,
+            stroke: stroke.into(),
+        }
+    }
+
+    /// Cut out a hole of this radius in the middle, making a segment of a donut.
+    #[inline]
+    pub fn with_inner_radius(mut self, inner_radius: f32) -> Self {
+        self.inner_radius = inner_radius;
+        self
+    }
+
+    /// Is this a full circle (or ring)?
+    #[inline]
+    pub fn is_full_turn(&self) -> bool {
+        TAU <= (self.end_angle - self.start_angle).abs()
+    }
+
+    /// Is there a hole in the middle?
+    #[inline]
+    pub fn has_hole(&self) -> bool {
+        self.closed && 0.0 < self.inner_radius
+    }
+
+    /// The start and end angle, with the start being the smaller one.
+    #[inline]
+    pub fn angle_range(&self) -> (f32, f32) {
+        if self.start_angle <= self.end_angle {
+            (self.start_angle, self.end_angle)
+        } else {
+            (self.end_angle, self.start_angle)
+        }
+    }
+
+    /// The visual bounding rectangle (includes stroke width)
+    pub fn visual_bounding_rect(&self) -> Rect {
+        if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
+            Rect::NOTHING
+        } else {
+            self.logical_bounding_rect().expand(self.stroke.width / 2.0)
+        }
+    }
+
+    /// Logical bounding rectangle (ignoring stroke width)
+    pub fn logical_bounding_rect(&self) -> Rect {
+        if self.is_full_turn() {
+            return Rect::from_center_size(self.center, Vec2::splat(2.0 * self.radius));
+        }
+
+        let (start, end) = self.angle_range();
+        let mut rect = Rect::from_two_pos(
+            self.center + self.radius * Vec2::angled(start),
+            self.center + self.radius * Vec2::angled(end),
+        );
+
+        // The arc reaches out the furthest where it crosses the axes:
+        let mut axis_angle = (start / FRAC_PI_2).ceil() * FRAC_PI_2;
+        while axis_angle <= end {
+            rect.extend_with(self.center + self.radius * Vec2::angled(axis_angle));
+            axis_angle += FRAC_PI_2;
+        }
+
+        if self.has_hole() {
+            rect.extend_with(self.center + self.inner_radius * Vec2::angled(start));
+            rect.extend_with(self.center + self.inner_radius * Vec2::angled(end));
+        } else if self.closed {
+            rect.extend_with(self.center);
+        }
+
+        rect
+    }
+}
+
+impl From<ArcShape> for Shape {
+    #[inline(always)]
+    fn from(shape: ArcShape) -> Self {
+        Self::Arc(shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arc_bounding_rect() {
+        let quarter = ArcShape::pie(Pos2::ZERO, 10.0, 0.0, FRAC_PI_2, Color32::RED, Stroke::NONE);
+        let rect = quarter.logical_bounding_rect();
+        assert!((rect.min - Pos2::ZERO).length() < 1e-4);
+        assert!((rect.max - pos2(10.0, 10.0)).length() < 1e-4);
+
+        // Crossing the negative x-axis:
+        let arc = ArcShape::stroke(Pos2::ZERO, 10.0, 3.0, 3.5, Stroke::new(1.0, Color32::RED));
+        let rect = arc.logical_bounding_rect();
+        assert!((rect.left() + 10.0).abs() < 1e-4);
+
+        // A donut segment doesn't reach the center:
+        let segment = ArcShape::pie(
+            Pos2::ZERO,
+            10.0,
+            0.25 * std::f32::consts::PI,
+            0.75 * std::f32::consts::PI,
+            Color32::RED,
+            Stroke::NONE,
+        )
+        .with_inner_radius(5.0);
+        let rect = segment.logical_bounding_rect();
+        assert!((rect.top() - 5.0 * 0.5_f32.sqrt()).abs() < 1e-4);
+        assert!((rect.bottom() - 10.0).abs() < 1e-4);
+    }
+}
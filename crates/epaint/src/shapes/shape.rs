@@ -11,8 +11,8 @@ use crate::{
 };
 
 use super::{
-    CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PathShape, QuadraticBezierShape,
-    RectShape, TextShape,
+    ArcShape, CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PathShape,
+    QuadraticBezierShape, RectShape, TextShape,
 };
 
 /// A paint primitive such as a circle or a piece of text.
@@ -38,6 +38,9 @@ pub enum Shape {
     /// Ellipse with optional outline and fill.
     Ellipse(EllipseShape),
 
+    /// Circular arc, pie slice, or donut segment, with optional outline and fill.
+    Arc(ArcShape),
+
     /// A line between two points.
     LineSegment { points: [Pos2; 2], stroke: Stroke },
 
@@ -244,6 +247,52 @@ impl Shape {
         );
     }
 
+    /// Turn a polyline into dashes, with a phase for animating the dashes along the line.
+    ///
+    /// The dash pattern continues across the vertices of the path,
+    /// so dashes have consistent lengths, and a dash going around a corner is joined.
+    ///
+    /// The `phase` moves the pattern forward along the line, in points.
+    /// Increasing it over time makes the dashes march along the line ("marching ants").
+    /// A non-finite `phase` is treated as zero.
+    ///
+    /// If `round_caps` is set, each dash gets a round cap at both ends.
+    pub fn dashed_line_with_phase(
+        path: &[Pos2],
+        stroke: impl Into<Stroke>,
+        dash_length: f32,
+        gap_length: f32,
+        phase: f32,
+        round_caps: bool,
+    ) -> Vec<Self> {
+        let stroke = stroke.into();
+        let mut shapes = Vec::new();
+        dashes_from_polyline(
+            path,
+            stroke,
+            dash_length,
+            gap_length,
+            phase,
+            &mut |dash: Vec<Pos2>| {
+                if round_caps {
+                    let radius = 0.5 * stroke.width;
+                    shapes.push(Self::circle_filled(dash[0], radius, stroke.color));
+                    shapes.push(Self::circle_filled(
+                        dash[dash.len() - 1],
+                        radius,
+                        stroke.color,
+                    ));
+                }
+                if dash.len() == 2 {
+                    shapes.push(Self::line_segment([dash[0], dash[1]], stroke));
+                } else {
+                    shapes.push(Self::line(dash, stroke));
+                }
+            },
+        );
+        shapes
+    }
+
     /// A convex polygon with a fill and optional stroke.
     ///
     /// The most performant winding order is clockwise.
@@ -369,6 +418,7 @@ impl Shape {
             }
             Self::Circle(circle_shape) => circle_shape.visual_bounding_rect(),
             Self::Ellipse(ellipse_shape) => ellipse_shape.visual_bounding_rect(),
+            Self::Arc(arc_shape) => arc_shape.visual_bounding_rect(),
             Self::LineSegment { points, stroke } => {
                 if stroke.is_empty() {
                     Rect::NOTHING
@@ -418,8 +468,16 @@ impl Shape {
 
     /// Transform (move/scale) the shape in-place.
     ///
+    /// A [`TSTransform`] only scales uniformly and translates, so every shape keeps its kind:
+    /// circles stay circles, rectangles stay axis-aligned rectangles, etc.
+    /// Stroke widths, corner radii and text are scaled along with the geometry.
+    ///
     /// If using a [`PaintCallback`], note that only the rect is scaled as opposed
     /// to other shapes where the stroke is also scaled.
+    ///
+    /// There is no rotation in a [`TSTransform`].
+    /// To rotate, use [`Mesh::rotate`], [`TextShape::with_angle`],
+    /// or build the rotated geometry yourself, e.g. as a [`PathShape`].
     pub fn transform(&mut self, transform: TSTransform) {
         match self {
             Self::Noop => {}
@@ -430,31 +488,42 @@ impl Shape {
             }
             Self::Circle(circle_shape) => {
                 circle_shape.center = transform * circle_shape.center;
-                circle_shape.radius *= transform.scaling;
-                circle_shape.stroke.width *= transform.scaling;
+                circle_shape.radius = transform.scale_length(circle_shape.radius);
+                circle_shape.stroke.width = transform.scale_length(circle_shape.stroke.width);
             }
             Self::Ellipse(ellipse_shape) => {
                 ellipse_shape.center = transform * ellipse_shape.center;
-                ellipse_shape.radius *= transform.scaling;
-                ellipse_shape.stroke.width *= transform.scaling;
+                ellipse_shape.radius *= transform.scaling.abs();
+                ellipse_shape.stroke.width = transform.scale_length(ellipse_shape.stroke.width);
+            }
+            Self::Arc(arc_shape) => {
+                arc_shape.center = transform * arc_shape.center;
+                arc_shape.radius = transform.scale_length(arc_shape.radius);
+                arc_shape.inner_radius = transform.scale_length(arc_shape.inner_radius);
+                if transform.scaling < 0.0 {
+                    // Scaling by a negative factor is the same as rotating half a turn:
+                    arc_shape.start_angle += std::f32::consts::PI;
+                    arc_shape.end_angle += std::f32::consts::PI;
+                }
+                arc_shape.stroke.width = transform.scale_length(arc_shape.stroke.width);
             }
             Self::LineSegment { points, stroke } => {
                 for p in points {
                     *p = transform * *p;
                 }
-                stroke.width *= transform.scaling;
+                stroke.width = transform.scale_length(stroke.width);
             }
             Self::Path(path_shape) => {
                 for p in &mut path_shape.points {
                     *p = transform * *p;
                 }
-                path_shape.stroke.width *= transform.scaling;
+                path_shape.stroke.width = transform.scale_length(path_shape.stroke.width);
             }
             Self::Rect(rect_shape) => {
                 rect_shape.rect = transform * rect_shape.rect;
-                rect_shape.corner_radius *= transform.scaling;
-                rect_shape.stroke.width *= transform.scaling;
-                rect_shape.blur_width *= transform.scaling;
+                rect_shape.corner_radius *= transform.scaling.abs();
+                rect_shape.stroke.width = transform.scale_length(rect_shape.stroke.width);
+                rect_shape.blur_width = transform.scale_length(rect_shape.blur_width);
             }
             Self::Text(text_shape) => {
                 text_shape.transform(transform);
@@ -466,13 +535,13 @@ impl Shape {
                 for p in &mut bezier.points {
                     *p = transform * *p;
                 }
-                bezier.stroke.width *= transform.scaling;
+                bezier.stroke.width = transform.scale_length(bezier.stroke.width);
             }
             Self::CubicBezier(bezier) => {
                 for p in &mut bezier.points {
                     *p = transform * *p;
                 }
-                bezier.stroke.width *= transform.scaling;
+                bezier.stroke.width = transform.scale_length(bezier.stroke.width);
             }
             Self::Callback(shape) => {
                 shape.rect = transform * shape.rect;
@@ -558,3 +627,166 @@ fn dashes_from_line(
         position_on_segment -= segment_length;
     }
 }
+
+/// Calls `add_dash` with the points of each dash along the path.
+///
+/// Unlike [`dashes_from_line`], the dashes continue across the vertices of the path.
+fn dashes_from_polyline(
+    path: &[Pos2],
+    stroke: Stroke,
+    dash_length: f32,
+    gap_length: f32,
+    phase: f32,
+    add_dash: &mut dyn FnMut(Vec<Pos2>),
+) {
+    if path.len() < 2 || stroke.is_empty() || dash_length.is_nan() || dash_length <= 0.0 {
+        return;
+    }
+    let gap_length = gap_length.max(0.0);
+    if gap_length == 0.0 || dash_length == f32::INFINITY {
+        add_dash(path.to_vec());
+        return;
+    }
+    let period = dash_length + gap_length;
+
+    // A non-finite phase would make the position NaN, and we would never reach the next dash:
+    let phase = if phase.is_finite() { phase } else { 0.0 };
+
+    // Position within the dash pattern, in `0..period`:
+    let mut pattern_pos = (-phase).rem_euclid(period);
+    let mut dash = (pattern_pos < dash_length).then(|| vec![path[0]]);
+
+    for window in path.windows(2) {
+        let (start, end) = (window[0], window[1]);
+        let segment_length = start.distance(end);
+        if segment_length <= 0.0 {
+            continue;
+        }
+
+        let mut position_on_segment = 0.0;
+        loop {
+            let to_next_change = if dash.is_some() {
+                dash_length - pattern_pos
+            } else {
+                period - pattern_pos
+            };
+            if segment_length < position_on_segment + to_next_change {
+                pattern_pos += segment_length - position_on_segment;
+                if let Some(dash) = &mut dash {
+                    dash.push(end);
+                }
+                break;
+            }
+
+            position_on_segment += to_next_change;
+            let point = start.lerp(end, position_on_segment / segment_length);
+            if let Some(mut finished) = dash.take() {
+                finished.push(point);
+                add_dash(finished);
+                pattern_pos = dash_length;
+            } else {
+                dash = Some(vec![point]);
+                pattern_pos = 0.0;
+            }
+        }
+    }
+
+    if let Some(dash) = dash {
+        if 2 <= dash.len() {
+            add_dash(dash);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dash_lengths(shapes: &[Shape]) -> Vec<f32> {
+        shapes
+            .iter()
+            .map(|shape| {
+                let points: &[Pos2] = match shape {
+                    Shape::LineSegment { points, .. } => points,
+                    Shape::Path(path) => &path.points,
+                    _ => panic!("Unexpected shape: {shape:?}"),
+                };
+                points.windows(2).map(|w| w[0].distance(w[1])).sum()
+            })
+            .collect()
+    }
+
+    fn assert_lengths_eq(shapes: &[Shape], expected: &[f32]) {
+        let lengths = dash_lengths(shapes);
+        assert_eq!(lengths.len(), expected.len(), "{lengths:?} != {expected:?}");
+        for (length, expected_length) in lengths.iter().zip(expected) {
+            assert!(
+                (length - expected_length).abs() < 1e-4,
+                "{lengths:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_dashed_line_with_phase() {
+        // An L-shaped polyline, 10 + 10 points long:
+        let path = [pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(10.0, 10.0)];
+        let stroke = Stroke::new(1.0, Color32::WHITE);
+
+        let shapes = Shape::dashed_line_with_phase(&path, stroke, 4.0, 2.0, 0.0, false);
+        // Dashes at 0..4, 6..10, 12..16, 18..20:
+        assert_lengths_eq(&shapes, &[4.0, 4.0, 4.0, 2.0]);
+
+        // Shifting the pattern by 3 points: dashes at 0..1, 3..7, 9..13 (around the corner), 15..19.
+        let shapes = Shape::dashed_line_with_phase(&path, stroke, 4.0, 2.0, 3.0, false);
+        assert_lengths_eq(&shapes, &[1.0, 4.0, 4.0, 4.0]);
+        assert!(
+            matches!(&shapes[2], Shape::Path(path) if path.points.len() == 3),
+            "The dash around the corner should be joined"
+        );
+
+        let with_caps = Shape::dashed_line_with_phase(&path, stroke, 4.0, 2.0, 3.0, true);
+        assert_eq!(with_caps.len(), 3 * shapes.len());
+    }
+
+    #[test]
+    fn test_dashed_line_with_non_finite_phase() {
+        let path = [pos2(0.0, 0.0), pos2(10.0, 0.0)];
+        let stroke = Stroke::new(1.0, Color32::WHITE);
+
+        // Should not hang, and act as if there was no phase:
+        for phase in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            let shapes = Shape::dashed_line_with_phase(&path, stroke, 4.0, 2.0, phase, false);
+            assert_lengths_eq(&shapes, &[4.0, 4.0]);
+        }
+
+        // An infinitely long dash is a solid line:
+        let shapes = Shape::dashed_line_with_phase(&path, stroke, f32::INFINITY, 2.0, 1.0, false);
+        assert_lengths_eq(&shapes, &[10.0]);
+    }
+
+    #[test]
+    fn test_transform_with_negative_scaling() {
+        let transform = TSTransform::from_scaling(-2.0);
+
+        let mut circle = Shape::circle_stroke(pos2(1.0, 0.0), 3.0, Stroke::new(1.0, Color32::RED));
+        circle.transform(transform);
+        let Shape::Circle(circle) = circle else {
+            panic!("Expected a circle");
+        };
+        assert_eq!(circle.center, pos2(-2.0, 0.0));
+        assert_eq!(circle.radius, 6.0);
+        assert_eq!(circle.stroke.width, 2.0);
+
+        let mut rect = Shape::rect_filled(
+            Rect::from_min_size(Pos2::ZERO, Vec2::splat(10.0)),
+            4.0,
+            Color32::RED,
+        );
+        rect.transform(transform);
+        let Shape::Rect(rect) = rect else {
+            panic!("Expected a rect");
+        };
+        assert_eq!(rect.corner_radius, CornerRadius::same(8));
+    }
+}
@@ -106,7 +106,9 @@ impl TextShape {
         self
     }
 
-    /// Move the shape by this many points, in-place.
+    /// Transform (move/scale) the text in-place.
+    ///
+    /// The [`Self::angle`] is left unchanged.
     pub fn transform(&mut self, transform: emath::TSTransform) {
         let Self {
             pos,
@@ -119,7 +121,7 @@ impl TextShape {
         } = self;
 
         *pos = transform * *pos;
-        underline.width *= transform.scaling;
+        underline.width = transform.scale_length(underline.width);
 
         let Galley {
             job: _,
@@ -111,6 +111,32 @@ pub enum StrokeKind {
     Outside,
 }
 
+/// How to join the segments of a thick path where they meet at a corner.
+///
+/// See [`PathStroke::with_join`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LineJoin {
+    /// Sharp corners, except that corners sharper than a right angle are cut off on open paths.
+    ///
+    /// This is the cheapest option.
+    #[default]
+    Auto,
+
+    /// Extend the outer edges of the segments until they meet in a sharp corner.
+    ///
+    /// Corners where the miter would be longer than
+    /// [`TessellationOptions::miter_limit`](crate::TessellationOptions::miter_limit)
+    /// times the stroke width are beveled instead.
+    Miter,
+
+    /// Cut off the corner with a straight line.
+    Bevel,
+
+    /// Round off the corner, as if drawn with a round pen.
+    Round,
+}
+
 /// Describes the width and color of paths. The color can either be solid or provided by a callback. For more information, see [`ColorMode`]
 ///
 /// The default stroke is the same as [`Stroke::NONE`].
@@ -120,6 +146,10 @@ pub struct PathStroke {
     pub width: f32,
     pub color: ColorMode,
     pub kind: StrokeKind,
+
+    /// How the segments of the path are joined at the corners.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub join: LineJoin,
 }
 
 impl Default for PathStroke {
@@ -135,6 +165,7 @@ impl PathStroke {
         width: 0.0,
         color: ColorMode::TRANSPARENT,
         kind: StrokeKind::Middle,
+        join: LineJoin::Auto,
     };
 
     #[inline]
@@ -143,6 +174,7 @@ impl PathStroke {
             width: width.into(),
             color: ColorMode::Solid(color.into()),
             kind: StrokeKind::Middle,
+            join: LineJoin::Auto,
         }
     }
 
@@ -158,6 +190,53 @@ impl PathStroke {
             width: width.into(),
             color: ColorMode::UV(Arc::new(callback)),
             kind: StrokeKind::Middle,
+            join: LineJoin::Auto,
+        }
+    }
+
+    /// Create a new `PathStroke` that fades from `start_color` at the start of the path
+    /// to `end_color` at the end, e.g. for a wire between two nodes in a graph.
+    ///
+    /// The colors are interpolated in gamma space, see [`Color32::lerp_gamma`].
+    ///
+    /// ```
+    /// # use epaint::{Color32, CubicBezierShape, PathStroke, pos2};
+    /// let (from, to) = (pos2(0.0, 0.0), pos2(200.0, 100.0));
+    /// let stroke = PathStroke::new_gradient(3.0, Color32::RED, Color32::BLUE);
+    /// let wire = CubicBezierShape::from_points_stroke(
+    ///     [from, pos2(100.0, 0.0), pos2(100.0, 100.0), to],
+    ///     false,
+    ///     Color32::TRANSPARENT,
+    ///     stroke,
+    /// );
+    /// ```
+    #[inline]
+    pub fn new_gradient(
+        width: impl Into<f32>,
+        start_color: impl Into<Color32>,
+        end_color: impl Into<Color32>,
+    ) -> Self {
+        let (start_color, end_color) = (start_color.into(), end_color.into());
+        Self::new_gradient_fn(width, move |t| start_color.lerp_gamma(end_color, t))
+    }
+
+    /// Create a new `PathStroke` with a color that depends on how far along the path a point is.
+    ///
+    /// The callback gets `0.0` at the start of the path and `1.0` at the end, by length.
+    /// It is called once per point of the path, and the colors are interpolated in between,
+    /// so for a straight line you only get to pick the colors of the two ends.
+    ///
+    /// See also [`Self::new_gradient`] and [`ColorMode::Gradient`].
+    #[inline]
+    pub fn new_gradient_fn(
+        width: impl Into<f32>,
+        callback: impl Fn(f32) -> Color32 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            width: width.into(),
+            color: ColorMode::Gradient(Arc::new(callback)),
+            kind: StrokeKind::Middle,
+            join: LineJoin::Auto,
         }
     }
 
@@ -166,6 +245,19 @@ impl PathStroke {
         Self { kind, ..self }
     }
 
+    /// How the segments of the path are joined at the corners.
+    ///
+    /// ```
+    /// # use epaint::{Color32, LineJoin, PathStroke, Shape, pos2};
+    /// let points = vec![pos2(0.0, 0.0), pos2(50.0, 0.0), pos2(0.0, 20.0)];
+    /// let stroke = PathStroke::new(8.0, Color32::WHITE).with_join(LineJoin::Round);
+    /// let shape = Shape::line(points, stroke);
+    /// ```
+    #[inline]
+    pub fn with_join(self, join: LineJoin) -> Self {
+        Self { join, ..self }
+    }
+
     /// Set the stroke to be painted right on the edge of the shape, half inside and half outside.
     #[inline]
     pub fn middle(self) -> Self {
@@ -220,6 +312,7 @@ impl From<Stroke> for PathStroke {
                 width: value.width,
                 color: ColorMode::Solid(value.color),
                 kind: StrokeKind::Middle,
+                join: LineJoin::Auto,
             }
         }
     }
@@ -8,10 +8,13 @@
 use emath::{GuiRounding as _, NumExt as _, Pos2, Rect, Rot2, Vec2, pos2, remap, vec2};
 
 use crate::{
-    CircleShape, ClippedPrimitive, ClippedShape, Color32, CornerRadiusF32, CubicBezierShape,
-    EllipseShape, Mesh, PathShape, Primitive, QuadraticBezierShape, RectShape, Shape, Stroke,
-    StrokeKind, TextShape, TextureId, Vertex, WHITE_UV, color::ColorMode, emath,
-    stroke::PathStroke, texture_atlas::PreparedDisc,
+    ArcShape, CircleShape, ClippedPrimitive, ClippedShape, Color32, CornerRadiusF32,
+    CubicBezierShape, EllipseShape, Mesh, PathShape, Primitive, QuadraticBezierShape, RectShape,
+    Shape, Stroke, StrokeKind, TextShape, TextureId, Vertex, WHITE_UV,
+    color::ColorMode,
+    emath,
+    stroke::{LineJoin, PathStroke},
+    texture_atlas::PreparedDisc,
 };
 
 // ----------------------------------------------------------------------------
@@ -381,6 +384,13 @@ impl Path {
     }
 
     pub fn add_open_points(&mut self, points: &[Pos2]) {
+        self.add_open_points_with_join(points, LineJoin::Auto, f32::INFINITY);
+    }
+
+    /// Like [`Self::add_open_points`], but with the given way of joining the segments.
+    ///
+    /// `miter_limit` is only used for [`LineJoin::Miter`].
+    pub fn add_open_points_with_join(&mut self, points: &[Pos2], join: LineJoin, miter_limit: f32) {
         let n = points.len();
         assert!(n >= 2, "A path needs at least two points, but got {n}");
 
@@ -401,21 +411,7 @@ impl Path {
                     n1 = n0;
                 }
 
-                let normal = (n0 + n1) / 2.0;
-                let length_sq = normal.length_sq();
-                let right_angle_length_sq = 0.5;
-                let sharper_than_a_right_angle = length_sq < right_angle_length_sq;
-                if sharper_than_a_right_angle {
-                    // cut off the sharp corner
-                    let center_normal = normal.normalized();
-                    let n0c = (n0 + center_normal) / 2.0;
-                    let n1c = (n1 + center_normal) / 2.0;
-                    self.add_point(points[i], n0c / n0c.length_sq());
-                    self.add_point(points[i], n1c / n1c.length_sq());
-                } else {
-                    // miter join
-                    self.add_point(points[i], normal / length_sq);
-                }
+                self.add_corner(points[i], n0, n1, join, miter_limit, true);
 
                 n0 = n1;
             }
@@ -427,6 +423,13 @@ impl Path {
     }
 
     pub fn add_line_loop(&mut self, points: &[Pos2]) {
+        self.add_line_loop_with_join(points, LineJoin::Auto, f32::INFINITY);
+    }
+
+    /// Like [`Self::add_line_loop`], but with the given way of joining the segments.
+    ///
+    /// `miter_limit` is only used for [`LineJoin::Miter`].
+    pub fn add_line_loop_with_join(&mut self, points: &[Pos2], join: LineJoin, miter_limit: f32) {
         let n = points.len();
         assert!(n >= 2, "A path needs at least two points, but got {n}");
         self.reserve(n);
@@ -444,9 +447,6 @@ impl Path {
                 n1 = n0;
             }
 
-            let normal = (n0 + n1) / 2.0;
-            let length_sq = normal.length_sq();
-
             // We can't just cut off corners for filled shapes like this,
             // because the feather will both expand and contract the corner along the provided normals
             // to make sure it doesn't grow, and the shrinking will make the inner points cross each other.
@@ -457,21 +457,69 @@ impl Path {
             // See https://github.com/emilk/egui/issues/1226
             const CUT_OFF_SHARP_CORNERS: bool = false;
 
+            self.add_corner(points[i], n0, n1, join, miter_limit, CUT_OFF_SHARP_CORNERS);
+
+            n0 = n1;
+        }
+    }
+
+    /// Add the point(s) for a corner between two segments with the unit normals `n0` and `n1`.
+    ///
+    /// `cut_off_sharp_corners` is only used for [`LineJoin::Auto`].
+    fn add_corner(
+        &mut self,
+        pos: Pos2,
+        n0: Vec2,
+        n1: Vec2,
+        join: LineJoin,
+        miter_limit: f32,
+        cut_off_sharp_corners: bool,
+    ) {
+        // The resolution of round joins. Turns gentler than this are always mitered,
+        // since it makes no visual difference, and saves vertices on flattened curves.
+        const ROUND_JOIN_STEP: f32 = std::f32::consts::PI / 8.0;
+
+        let normal = (n0 + n1) / 2.0;
+        let length_sq = normal.length_sq();
+
+        if join == LineJoin::Auto {
             let right_angle_length_sq = 0.5;
             let sharper_than_a_right_angle = length_sq < right_angle_length_sq;
-            if CUT_OFF_SHARP_CORNERS && sharper_than_a_right_angle {
+            if cut_off_sharp_corners && sharper_than_a_right_angle {
                 // cut off the sharp corner
                 let center_normal = normal.normalized();
                 let n0c = (n0 + center_normal) / 2.0;
                 let n1c = (n1 + center_normal) / 2.0;
-                self.add_point(points[i], n0c / n0c.length_sq());
-                self.add_point(points[i], n1c / n1c.length_sq());
+                self.add_point(pos, n0c / n0c.length_sq());
+                self.add_point(pos, n1c / n1c.length_sq());
             } else {
                 // miter join
-                self.add_point(points[i], normal / length_sq);
+                self.add_point(pos, normal / length_sq);
             }
+            return;
+        }
 
-            n0 = n1;
+        // The miter is `1 / length_sq.sqrt()` times as long as the width of the stroke:
+        if join == LineJoin::Miter && 1.0 <= miter_limit * miter_limit * length_sq {
+            self.add_point(pos, normal / length_sq);
+            return;
+        }
+
+        let turn = (n0.x * n1.y - n0.y * n1.x).atan2(n0.dot(n1));
+
+        if turn.abs() <= ROUND_JOIN_STEP {
+            self.add_point(pos, normal / length_sq);
+        } else if join == LineJoin::Round {
+            let num_steps = (turn.abs() / ROUND_JOIN_STEP).ceil() as usize;
+            let start_angle = n0.angle();
+            for i in 0..=num_steps {
+                let angle = start_angle + turn * i as f32 / num_steps as f32;
+                self.add_point(pos, Vec2::angled(angle));
+            }
+        } else {
+            // Bevel, with each segment ending square at the corner:
+            self.add_point(pos, n0);
+            self.add_point(pos, n1);
         }
     }
 
@@ -712,6 +760,14 @@ pub struct TessellationOptions {
     /// The default value will be 1.0e-5, it will be used during float compare.
     pub epsilon: f32,
 
+    /// For paths stroked with [`LineJoin::Miter`]: corners where the miter
+    /// would be longer than this many times the stroke width are beveled instead.
+    ///
+    /// This avoids long spikes at very sharp corners.
+    ///
+    /// Default: `4.0`.
+    pub miter_limit: f32,
+
     /// If `rayon` feature is activated, should we parallelize tessellation?
     pub parallel_tessellation: bool,
 
@@ -737,6 +793,7 @@ impl Default for TessellationOptions {
             debug_ignore_clip_rects: false,
             bezier_tolerance: 0.1,
             epsilon: 1.0e-5,
+            miter_limit: 4.0,
             parallel_tessellation: true,
             validate_meshes: false,
         }
@@ -820,6 +877,50 @@ fn fill_closed_path(feathering: f32, path: &mut [PathPoint], fill_color: Color32
     }
 }
 
+/// Add the vertices for filling the inside of a closed path, with feathering along the path.
+///
+/// Unlike [`fill_closed_path`], this does not triangulate the inside,
+/// so the caller can do that for shapes that aren't convex.
+/// The path must be in clockwise winding order.
+///
+/// Returns the index of the vertex of the first point of the path,
+/// and the stride to the vertex of the next point.
+fn add_feathered_loop(
+    feathering: f32,
+    path: &[PathPoint],
+    fill_color: Color32,
+    out: &mut Mesh,
+) -> (u32, u32) {
+    let n = path.len() as u32;
+    let idx = out.vertices.len() as u32;
+
+    if 0.0 < feathering {
+        out.reserve_triangles(2 * n as usize);
+        out.reserve_vertices(2 * n as usize);
+        let idx_inner = idx;
+        let idx_outer = idx + 1;
+
+        let mut i0 = n - 1;
+        for i1 in 0..n {
+            let p1 = &path[i1 as usize];
+            let dm = 0.5 * feathering * p1.normal;
+            out.colored_vertex(p1.pos - dm, fill_color);
+            out.colored_vertex(p1.pos + dm, Color32::TRANSPARENT);
+            out.add_triangle(idx_inner + i1 * 2, idx_inner + i0 * 2, idx_outer + 2 * i0);
+            out.add_triangle(idx_outer + i0 * 2, idx_outer + i1 * 2, idx_inner + 2 * i1);
+            i0 = i1;
+        }
+        (idx, 2)
+    } else {
+        out.vertices.extend(path.iter().map(|p| Vertex {
+            pos: p.pos,
+            uv: WHITE_UV,
+            color: fill_color,
+        }));
+        (idx, 1)
+    }
+}
+
 /// Like [`fill_closed_path`] but with texturing.
 ///
 /// The `uv_from_pos` is called for each vertex position.
@@ -927,6 +1028,28 @@ fn stroke_and_fill_path(
     stroke: &PathStroke,
     color_fill: Color32,
     out: &mut Mesh,
+) {
+    let fix_winding = color_fill != Color32::TRANSPARENT;
+    stroke_and_fill_path_with_winding(
+        feathering,
+        path,
+        path_type,
+        stroke,
+        color_fill,
+        fix_winding,
+        out,
+    );
+}
+
+/// Like [`stroke_and_fill_path`], but only fixes the winding order if `fix_winding` is set.
+fn stroke_and_fill_path_with_winding(
+    feathering: f32,
+    path: &mut [PathPoint],
+    path_type: PathType,
+    stroke: &PathStroke,
+    color_fill: Color32,
+    fix_winding: bool,
+    out: &mut Mesh,
 ) {
     let n = path.len() as u32;
 
@@ -939,9 +1062,17 @@ fn stroke_and_fill_path(
         return fill_closed_path(feathering, path, color_fill, out);
     }
 
-    if color_fill != Color32::TRANSPARENT && cw_signed_area(path) < 0.0 {
+    // Before fixing the winding order, so that the gradient follows the path as given:
+    let mut path_params = if matches!(stroke.color, ColorMode::Gradient(_)) {
+        path_params(path, path_type)
+    } else {
+        Vec::new()
+    };
+
+    if fix_winding && cw_signed_area(path) < 0.0 {
         // Wrong winding order - fix:
         path.reverse();
+        path_params.reverse();
         for point in &mut *path {
             point.normal = -point.normal;
         }
@@ -993,9 +1124,10 @@ fn stroke_and_fill_path(
     } else {
         Rect::NAN
     };
-    let get_color = |col: &ColorMode, pos: Pos2| match col {
+    let get_color = |col: &ColorMode, i: u32, pos: Pos2| match col {
         ColorMode::Solid(col) => *col,
         ColorMode::UV(fun) => fun(uv_bbox, pos),
+        ColorMode::Gradient(fun) => fun(path_params[i as usize]),
     };
 
     if 0.0 < feathering {
@@ -1042,7 +1174,7 @@ fn stroke_and_fill_path(
                 let p = p1.pos;
                 let n = p1.normal;
                 out.colored_vertex(p + n * feathering, color_outer);
-                out.colored_vertex(p, mul_color(get_color(color_middle, p), opacity));
+                out.colored_vertex(p, mul_color(get_color(color_middle, i1, p), opacity));
                 out.colored_vertex(p - n * feathering, color_fill);
 
                 if connect_with_previous {
@@ -1092,11 +1224,11 @@ fn stroke_and_fill_path(
                         out.colored_vertex(p + n * outer_rad, color_outer);
                         out.colored_vertex(
                             p + n * inner_rad,
-                            get_color(color_middle, p + n * inner_rad),
+                            get_color(color_middle, i1, p + n * inner_rad),
                         );
                         out.colored_vertex(
                             p - n * inner_rad,
-                            get_color(color_middle, p - n * inner_rad),
+                            get_color(color_middle, i1, p - n * inner_rad),
                         );
                         out.colored_vertex(p - n * outer_rad, color_fill);
 
@@ -1150,11 +1282,11 @@ fn stroke_and_fill_path(
                         out.colored_vertex(p + n * outer_rad + back_extrude, color_outer);
                         out.colored_vertex(
                             p + n * inner_rad,
-                            get_color(color_middle, p + n * inner_rad),
+                            get_color(color_middle, 0, p + n * inner_rad),
                         );
                         out.colored_vertex(
                             p - n * inner_rad,
-                            get_color(color_middle, p - n * inner_rad),
+                            get_color(color_middle, 0, p - n * inner_rad),
                         );
                         out.colored_vertex(p - n * outer_rad + back_extrude, color_outer);
 
@@ -1170,11 +1302,11 @@ fn stroke_and_fill_path(
                         out.colored_vertex(p + n * outer_rad, color_outer);
                         out.colored_vertex(
                             p + n * inner_rad,
-                            get_color(color_middle, p + n * inner_rad),
+                            get_color(color_middle, i1, p + n * inner_rad),
                         );
                         out.colored_vertex(
                             p - n * inner_rad,
-                            get_color(color_middle, p - n * inner_rad),
+                            get_color(color_middle, i1, p - n * inner_rad),
                         );
                         out.colored_vertex(p - n * outer_rad, color_outer);
 
@@ -1199,11 +1331,11 @@ fn stroke_and_fill_path(
                         out.colored_vertex(p + n * outer_rad + back_extrude, color_outer);
                         out.colored_vertex(
                             p + n * inner_rad,
-                            get_color(color_middle, p + n * inner_rad),
+                            get_color(color_middle, i1, p + n * inner_rad),
                         );
                         out.colored_vertex(
                             p - n * inner_rad,
-                            get_color(color_middle, p - n * inner_rad),
+                            get_color(color_middle, i1, p - n * inner_rad),
                         );
                         out.colored_vertex(p - n * outer_rad + back_extrude, color_outer);
 
@@ -1251,26 +1383,32 @@ fn stroke_and_fill_path(
             // Fade out thin lines rather than making them thinner
             let opacity = stroke.width / feathering;
             let radius = feathering / 2.0;
-            for p in path.iter_mut() {
+            for (i, p) in path.iter_mut().enumerate() {
                 out.colored_vertex(
                     p.pos + radius * p.normal,
-                    mul_color(get_color(&stroke.color, p.pos + radius * p.normal), opacity),
+                    mul_color(
+                        get_color(&stroke.color, i as u32, p.pos + radius * p.normal),
+                        opacity,
+                    ),
                 );
                 out.colored_vertex(
                     p.pos - radius * p.normal,
-                    mul_color(get_color(&stroke.color, p.pos - radius * p.normal), opacity),
+                    mul_color(
+                        get_color(&stroke.color, i as u32, p.pos - radius * p.normal),
+                        opacity,
+                    ),
                 );
             }
         } else {
             let radius = stroke.width / 2.0;
-            for p in path.iter_mut() {
+            for (i, p) in path.iter_mut().enumerate() {
                 out.colored_vertex(
                     p.pos + radius * p.normal,
-                    get_color(&stroke.color, p.pos + radius * p.normal),
+                    get_color(&stroke.color, i as u32, p.pos + radius * p.normal),
                 );
                 out.colored_vertex(
                     p.pos - radius * p.normal,
-                    get_color(&stroke.color, p.pos - radius * p.normal),
+                    get_color(&stroke.color, i as u32, p.pos - radius * p.normal),
                 );
             }
         }
@@ -1289,6 +1427,35 @@ fn stroke_and_fill_path(
     }
 }
 
+/// How far along the path each point is, by length,
+/// from `0.0` at the first point to `1.0` at the end of the path.
+///
+/// For closed paths, the end is back at the first point.
+fn path_params(path: &[PathPoint], path_type: PathType) -> Vec<f32> {
+    let mut params = Vec::with_capacity(path.len());
+    let Some(first) = path.first() else {
+        return params;
+    };
+
+    let mut length = 0.0;
+    let mut previous = first.pos;
+    for point in path {
+        length += previous.distance(point.pos);
+        params.push(length);
+        previous = point.pos;
+    }
+    if path_type == PathType::Closed {
+        length += previous.distance(first.pos);
+    }
+
+    if 0.0 < length {
+        for param in &mut params {
+            *param /= length;
+        }
+    }
+    params
+}
+
 fn mul_color(color: Color32, factor: f32) -> Color32 {
     // The fast gamma-space multiply also happens to be perceptually better.
     // Win-win!
@@ -1433,6 +1600,9 @@ impl Tessellator {
             Shape::Ellipse(ellipse) => {
                 self.tessellate_ellipse(ellipse, out);
             }
+            Shape::Arc(arc) => {
+                self.tessellate_arc(arc, out);
+            }
             Shape::Mesh(mesh) => {
                 profiling::scope!("mesh");
 
@@ -1602,6 +1772,144 @@ impl Tessellator {
             .fill_and_stroke(self.feathering, fill, &path_stroke, out);
     }
 
+    /// Tessellate a single [`ArcShape`] into a [`Mesh`].
+    ///
+    /// * `shape`: the arc, pie slice or donut segment to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_arc(&mut self, shape: ArcShape, out: &mut Mesh) {
+        let ArcShape {
+            center,
+            radius,
+            inner_radius,
+            start_angle: _,
+            end_angle: _,
+            closed,
+            fill,
+            stroke,
+        } = shape;
+
+        if radius <= 0.0 {
+            return;
+        }
+
+        if self.options.coarse_tessellation_culling
+            && !self
+                .clip_rect
+                .expand(radius + stroke.width)
+                .contains(center)
+        {
+            return;
+        }
+
+        let (start_angle, end_angle) = shape.angle_range();
+        let full_turn = shape.is_full_turn();
+        let span = if full_turn {
+            std::f32::consts::TAU
+        } else {
+            end_angle - start_angle
+        };
+
+        // Use enough segments to stay within `bezier_tolerance` of the true arc:
+        let tolerance = self.options.bezier_tolerance;
+        let max_segment_angle = (2.0 * (1.0 - (tolerance / radius).min(1.0)).acos())
+            .at_most(std::f32::consts::FRAC_PI_4);
+        let num_segments = ((span / max_segment_angle).ceil() as usize).clamp(1, 1024);
+
+        // A full turn doesn't repeat the first point at the end:
+        let num_points = if full_turn {
+            num_segments
+        } else {
+            num_segments + 1
+        };
+        let outer: Vec<Pos2> = (0..num_points)
+            .map(|i| {
+                let angle = start_angle + span * i as f32 / num_segments as f32;
+                center + radius * Vec2::angled(angle)
+            })
+            .collect();
+        let to_inner = |p: &Pos2| center + (inner_radius / radius) * (*p - center);
+
+        let path_stroke = PathStroke::from(stroke);
+        self.scratchpad_path.clear();
+
+        if !closed {
+            if full_turn {
+                self.scratchpad_path.add_line_loop(&outer);
+                self.scratchpad_path
+                    .stroke_closed(self.feathering, &path_stroke, out);
+            } else {
+                self.scratchpad_path.add_open_points(&outer);
+                self.scratchpad_path
+                    .stroke_open(self.feathering, &path_stroke, out);
+            }
+            return;
+        }
+
+        let n = num_segments as u32;
+
+        if full_turn {
+            self.scratchpad_path.add_line_loop(&outer);
+            if !shape.has_hole() {
+                self.scratchpad_path
+                    .fill_and_stroke(self.feathering, fill, &path_stroke, out);
+                return;
+            }
+
+            // A ring: the inner loop goes counter-clockwise, so its normals point into the hole.
+            let inner: Vec<Pos2> = outer.iter().rev().map(to_inner).collect();
+            let mut inner_path = Path::default();
+            inner_path.add_line_loop(&inner);
+
+            if fill != Color32::TRANSPARENT {
+                let (outer_idx, outer_stride) =
+                    add_feathered_loop(self.feathering, &self.scratchpad_path.0, fill, out);
+                let (inner_idx, inner_stride) =
+                    add_feathered_loop(self.feathering, &inner_path.0, fill, out);
+                let outer_vertex = |k: u32| outer_idx + outer_stride * (k % n);
+                let inner_vertex = |k: u32| inner_idx + inner_stride * ((n - 1) - (k % n));
+                for k in 0..n {
+                    out.add_triangle(outer_vertex(k), outer_vertex(k + 1), inner_vertex(k));
+                    out.add_triangle(outer_vertex(k + 1), inner_vertex(k + 1), inner_vertex(k));
+                }
+            }
+
+            self.scratchpad_path
+                .stroke_closed(self.feathering, &path_stroke, out);
+            inner_path.stroke_closed(self.feathering, &path_stroke, out);
+            return;
+        }
+
+        // A pie slice or donut segment, as a single clockwise loop.
+        // The outer arc comes first, followed by either the center or the inner arc.
+        let mut outline = outer;
+        if shape.has_hole() {
+            let inner: Vec<Pos2> = outline.iter().rev().map(to_inner).collect();
+            outline.extend(inner);
+        } else {
+            outline.push(center);
+        }
+        self.scratchpad_path.add_line_loop(&outline);
+
+        // The outline isn't necessarily convex, so we can't use `fill_closed_path`.
+        if fill != Color32::TRANSPARENT {
+            let (idx, stride) =
+                add_feathered_loop(self.feathering, &self.scratchpad_path.0, fill, out);
+            let v = |i: u32| idx + stride * i;
+            for k in 0..n {
+                if shape.has_hole() {
+                    // The inner point across from outer point `k` is at `2n + 1 - k`:
+                    out.add_triangle(v(k), v(k + 1), v(2 * n + 1 - k));
+                    out.add_triangle(v(k + 1), v(2 * n - k), v(2 * n + 1 - k));
+                } else {
+                    out.add_triangle(v(n + 1), v(k), v(k + 1));
+                }
+            }
+        }
+
+        self.scratchpad_path
+            .stroke_closed(self.feathering, &path_stroke, out);
+    }
+
     /// Tessellate a single [`Mesh`] into a [`Mesh`].
     ///
     /// * `mesh`: the mesh to tessellate.
@@ -1706,6 +2014,52 @@ impl Tessellator {
         self.tessellate_line_segment(points, stroke, out);
     }
 
+    /// Fill and stroke a closed path through the given points.
+    fn fill_and_stroke_line_loop(
+        &mut self,
+        points: &[Pos2],
+        fill: Color32,
+        stroke: &PathStroke,
+        out: &mut Mesh,
+    ) {
+        let miter_limit = self.options.miter_limit;
+        self.scratchpad_path.clear();
+
+        if stroke.join == LineJoin::Auto || fill == Color32::TRANSPARENT {
+            self.scratchpad_path
+                .add_line_loop_with_join(points, stroke.join, miter_limit);
+            self.scratchpad_path
+                .fill_and_stroke(self.feathering, fill, stroke, out);
+            return;
+        }
+
+        // The other joins can add several points per corner, which the feathering
+        // of the fill can't handle (see `CUT_OFF_SHARP_CORNERS`),
+        // so we fill with the normal corners, and only use the join for the stroke:
+        self.scratchpad_path.add_line_loop(points);
+        let fill_stroke = PathStroke {
+            color: ColorMode::TRANSPARENT,
+            ..stroke.clone()
+        };
+        self.scratchpad_path
+            .fill_and_stroke(self.feathering, fill, &fill_stroke, out);
+
+        self.scratchpad_path.clear();
+        self.scratchpad_path
+            .add_line_loop_with_join(points, stroke.join, miter_limit);
+        // Same winding order fix as for the fill, so that the stroke ends up on the same side:
+        let fix_winding = true;
+        stroke_and_fill_path_with_winding(
+            self.feathering,
+            &mut self.scratchpad_path.0,
+            PathType::Closed,
+            stroke,
+            Color32::TRANSPARENT,
+            fix_winding,
+            out,
+        );
+    }
+
     /// Tessellate a single [`PathShape`] into a [`Mesh`].
     ///
     /// * `path_shape`: the path to tessellate.
@@ -1730,13 +2084,11 @@ impl Tessellator {
             stroke,
         } = path_shape;
 
+        let miter_limit = self.options.miter_limit;
         self.scratchpad_path.clear();
 
         if *closed {
-            self.scratchpad_path.add_line_loop(points);
-
-            self.scratchpad_path
-                .fill_and_stroke(self.feathering, *fill, stroke, out);
+            self.fill_and_stroke_line_loop(points, *fill, stroke, out);
         } else {
             debug_assert_eq!(
                 *fill,
@@ -1744,7 +2096,8 @@ impl Tessellator {
                 "You asked to fill a path that is not closed. That makes no sense."
             );
 
-            self.scratchpad_path.add_open_points(points);
+            self.scratchpad_path
+                .add_open_points_with_join(points, stroke.join, miter_limit);
 
             self.scratchpad_path
                 .stroke(self.feathering, PathType::Open, stroke, out);
@@ -2171,12 +2524,10 @@ impl Tessellator {
             return;
         }
 
+        let miter_limit = self.options.miter_limit;
         self.scratchpad_path.clear();
         if closed {
-            self.scratchpad_path.add_line_loop(points);
-
-            self.scratchpad_path
-                .fill_and_stroke(self.feathering, fill, stroke, out);
+            self.fill_and_stroke_line_loop(points, fill, stroke, out);
         } else {
             debug_assert_eq!(
                 fill,
@@ -2184,7 +2535,8 @@ impl Tessellator {
                 "You asked to fill a bezier path that is not closed. That makes no sense."
             );
 
-            self.scratchpad_path.add_open_points(points);
+            self.scratchpad_path
+                .add_open_points_with_join(points, stroke.join, miter_limit);
 
             self.scratchpad_path
                 .stroke(self.feathering, PathType::Open, stroke, out);
@@ -2270,7 +2622,10 @@ impl Tessellator {
 
                 Shape::Path(path_shape) => 32 < path_shape.points.len(),
 
-                Shape::QuadraticBezier(_) | Shape::CubicBezier(_) | Shape::Ellipse(_) => true,
+                Shape::QuadraticBezier(_)
+                | Shape::CubicBezier(_)
+                | Shape::Ellipse(_)
+                | Shape::Arc(_) => true,
 
                 Shape::Noop
                 | Shape::Text(_)
@@ -2411,3 +2766,227 @@ fn path_bounding_box() {
         );
     }
 }
+
+/// Is the point inside any of the triangles of the mesh?
+#[cfg(test)]
+fn is_covered(mesh: &Mesh, p: Pos2) -> bool {
+    mesh.indices.chunks_exact(3).any(|triangle| {
+        let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize].pos);
+        let side = |p0: Pos2, p1: Pos2| (p1 - p0).x * (p - p0).y - (p1 - p0).y * (p - p0).x;
+        let (s0, s1, s2) = (side(a, b), side(b, c), side(c, a));
+        (0.0 <= s0 && 0.0 <= s1 && 0.0 <= s2) || (s0 <= 0.0 && s1 <= 0.0 && s2 <= 0.0)
+    })
+}
+
+#[test]
+fn test_arc_fill_is_not_convex() {
+    use crate::*;
+
+    let options = TessellationOptions {
+        feathering: false,
+        ..Default::default()
+    };
+    let clip_rect = Rect::EVERYTHING;
+    let tessellate = |arc: ArcShape| {
+        let mut tessellator = Tessellator::new(1.0, options, [1024, 1024], vec![]);
+        tessellator.set_clip_rect(clip_rect);
+        let mut mesh = Mesh::default();
+        tessellator.tessellate_arc(arc, &mut mesh);
+        mesh
+    };
+
+    // Three quarters of a pie, missing the top right quarter:
+    let tau = std::f32::consts::TAU;
+    let pie = ArcShape::pie(
+        Pos2::ZERO,
+        10.0,
+        0.0,
+        0.75 * tau,
+        Color32::RED,
+        Stroke::NONE,
+    );
+    let mesh = tessellate(pie);
+    assert!(is_covered(&mesh, pos2(5.0, 5.0)));
+    assert!(is_covered(&mesh, pos2(-5.0, -5.0)));
+    assert!(!is_covered(&mesh, pos2(5.0, -5.0)));
+
+    // A donut segment doesn't cover its hole:
+    let mesh = tessellate(pie.with_inner_radius(6.0));
+    assert!(is_covered(&mesh, pos2(0.0, 8.0)));
+    assert!(!is_covered(&mesh, pos2(0.0, 3.0)));
+    assert!(!is_covered(&mesh, pos2(5.0, -5.0)));
+
+    // Neither does a full ring:
+    let ring = ArcShape::pie(Pos2::ZERO, 10.0, 0.0, tau, Color32::RED, Stroke::NONE)
+        .with_inner_radius(6.0);
+    let mesh = tessellate(ring);
+    assert!(is_covered(&mesh, pos2(0.0, -8.0)));
+    assert!(is_covered(&mesh, pos2(8.0, 0.0)));
+    assert!(!is_covered(&mesh, Pos2::ZERO));
+}
+
+#[test]
+fn test_line_joins() {
+    use crate::*;
+
+    let tessellate = |points: Vec<Pos2>, join: LineJoin, miter_limit: f32| {
+        let options = TessellationOptions {
+            feathering: false,
+            miter_limit,
+            ..Default::default()
+        };
+        let mut tessellator = Tessellator::new(1.0, options, [1024, 1024], vec![]);
+        let stroke = PathStroke::new(10.0, Color32::WHITE).with_join(join);
+        let mut mesh = Mesh::default();
+        tessellator.tessellate_path(&PathShape::line(points, stroke), &mut mesh);
+        mesh
+    };
+
+    // A right angle, turning from going right to going down.
+    // The outside of the corner is towards the top right of (20, 0).
+    let right_angle = || vec![pos2(0.0, 0.0), pos2(20.0, 0.0), pos2(20.0, 20.0)];
+    let miter = tessellate(right_angle(), LineJoin::Miter, 4.0);
+    let bevel = tessellate(right_angle(), LineJoin::Bevel, 4.0);
+    let round = tessellate(right_angle(), LineJoin::Round, 4.0);
+
+    for mesh in [&miter, &bevel, &round] {
+        // No gap in the corner:
+        assert!(is_covered(mesh, pos2(22.0, -1.5)));
+        assert!(is_covered(mesh, pos2(24.0, 0.0)));
+        assert!(is_covered(mesh, pos2(21.0, -3.5)));
+    }
+
+    // Sharp corner:
+    assert!(is_covered(&miter, pos2(24.0, -3.5)));
+    // Cut off by a straight line from (20, -5) to (25, 0):
+    assert!(!is_covered(&bevel, pos2(23.0, -3.0)));
+    // Rounded with the radius of half the stroke width:
+    assert!(is_covered(&round, pos2(23.0, -3.0)));
+    assert!(!is_covered(&round, pos2(24.0, -4.0)));
+
+    // An acute angle, turning back towards the left, with the corner at (40, 0):
+    let acute_angle = || vec![pos2(0.0, 0.0), pos2(40.0, 0.0), pos2(0.0, 10.0)];
+    let far_beyond_the_corner = pos2(46.0, -1.0);
+
+    // The miter would be about eight times the stroke width:
+    let limited_miter = tessellate(acute_angle(), LineJoin::Miter, 4.0);
+    assert!(!is_covered(&limited_miter, far_beyond_the_corner));
+    let unlimited_miter = tessellate(acute_angle(), LineJoin::Miter, 100.0);
+    assert!(is_covered(&unlimited_miter, far_beyond_the_corner));
+
+    for join in [LineJoin::Miter, LineJoin::Bevel, LineJoin::Round] {
+        let mesh = tessellate(acute_angle(), join, 4.0);
+        // No gap in the corner:
+        assert!(is_covered(&mesh, pos2(40.5, 0.0)), "{join:?}");
+        assert!(is_covered(&mesh, pos2(40.2, 4.0)), "{join:?}");
+    }
+}
+
+#[test]
+fn test_line_joins_keep_the_fill_unchanged() {
+    use crate::*;
+
+    let tessellate = |stroke: PathStroke| {
+        let mut tessellator =
+            Tessellator::new(1.0, TessellationOptions::default(), [1024, 1024], vec![]);
+        // A thin triangle with a very sharp corner at (40, 0):
+        let points = vec![pos2(0.0, 0.0), pos2(40.0, 0.0), pos2(0.0, 10.0)];
+        let mut mesh = Mesh::default();
+        tessellator.tessellate_path(
+            &PathShape::convex_polygon(points, Color32::RED, stroke),
+            &mut mesh,
+        );
+        mesh
+    };
+
+    let fill_only = tessellate(PathStroke {
+        color: ColorMode::TRANSPARENT,
+        ..PathStroke::new(4.0, Color32::WHITE)
+    });
+
+    for join in [LineJoin::Miter, LineJoin::Bevel, LineJoin::Round] {
+        let mesh = tessellate(PathStroke::new(4.0, Color32::WHITE).with_join(join));
+        // The feathered fill comes first, and uses the normal corners:
+        let n = fill_only.vertices.len();
+        assert_eq!(mesh.vertices[..n], fill_only.vertices, "{join:?}");
+        assert_eq!(
+            mesh.indices[..fill_only.indices.len()],
+            fill_only.indices,
+            "{join:?}"
+        );
+        assert!(is_covered(&mesh, pos2(10.0, 3.0)), "{join:?}");
+    }
+}
+
+#[test]
+fn test_gradient_along_path() {
+    use crate::*;
+
+    let options = TessellationOptions {
+        feathering: false,
+        ..Default::default()
+    };
+    let mut tessellator = Tessellator::new(1.0, options, [1024, 1024], vec![]);
+    let stroke = PathStroke::new_gradient(4.0, Color32::RED, Color32::BLUE);
+    let points = vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(30.0, 0.0)];
+    let mut mesh = Mesh::default();
+    tessellator.tessellate_path(&PathShape::line(points, stroke), &mut mesh);
+
+    // Two vertices per point, colored by how far along the path (by length) the point is:
+    let colors: Vec<Color32> = mesh.vertices.iter().map(|v| v.color).collect();
+    let one_third = Color32::RED.lerp_gamma(Color32::BLUE, 1.0 / 3.0);
+    assert_eq!(
+        colors,
+        vec![
+            Color32::RED,
+            Color32::RED,
+            one_third,
+            one_third,
+            Color32::BLUE,
+            Color32::BLUE
+        ]
+    );
+}
+
+#[test]
+fn test_gradient_along_filled_counter_clockwise_path() {
+    use crate::*;
+
+    let options = TessellationOptions {
+        feathering: false,
+        ..Default::default()
+    };
+    let gray = |t: f32| Color32::from_gray((200.0 * t).round() as u8);
+
+    // Counter-clockwise, so the tessellator reverses the path to fill it:
+    let corners = [
+        pos2(0.0, 0.0),
+        pos2(0.0, 100.0),
+        pos2(100.0, 100.0),
+        pos2(100.0, 0.0),
+    ];
+
+    for join in [LineJoin::Auto, LineJoin::Bevel] {
+        let mut tessellator = Tessellator::new(1.0, options, [1024, 1024], vec![]);
+        let stroke = PathStroke::new_gradient_fn(2.0, gray).with_join(join);
+        let path = PathShape::convex_polygon(corners.to_vec(), Color32::RED, stroke);
+        let mut mesh = Mesh::default();
+        tessellator.tessellate_path(&path, &mut mesh);
+
+        // The gradient should still start at the first point, and follow the path as given:
+        for (i, corner) in corners.into_iter().enumerate() {
+            let expected = gray(i as f32 / 4.0);
+            let stroke_colors: Vec<Color32> = mesh
+                .vertices
+                .iter()
+                .filter(|v| v.pos.distance(corner) < 2.0 && v.color != Color32::RED)
+                .map(|v| v.color)
+                .collect();
+            assert!(!stroke_colors.is_empty(), "{join:?}");
+            assert!(
+                stroke_colors.iter().all(|&color| color == expected),
+                "{join:?}, corner {i}: {stroke_colors:?} != {expected:?}"
+            );
+        }
+    }
+}
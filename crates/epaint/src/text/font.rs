@@ -486,6 +486,45 @@ impl Font<'_> {
         glyph_info.advance_width_unscaled.0 * font.px_scale_factor(font_size)
     }
 
+    /// Width of the text when laid out on a single line, in points.
+    ///
+    /// This uses the same advance widths and pair kerning as the text layout,
+    /// but without allocating any glyphs in the atlas.
+    /// `\n` is not treated as a line break.
+    pub fn text_width(&mut self, text: &str, pixels_per_point: f32, font_size: f32) -> f32 {
+        let mut cursor_x_px = 0.0;
+        let mut last_glyph_id = None;
+
+        // Only recompute `ScaledMetrics` when the concrete `FontImpl` changes:
+        let mut current_font = FontFaceKey::INVALID;
+        let mut metrics = ScaledMetrics::default();
+
+        for chr in text.chars() {
+            let (font_key, glyph_info) = self.glyph_info(chr);
+            let font_impl = self.fonts_by_id.get(&font_key);
+            if current_font != font_key {
+                current_font = font_key;
+                metrics = font_impl
+                    .map(|font_impl| font_impl.scaled_metrics(pixels_per_point, font_size))
+                    .unwrap_or_default();
+            }
+
+            if let (Some(font_impl), Some(last_glyph_id), Some(glyph_id)) =
+                (font_impl, last_glyph_id, glyph_info.id)
+            {
+                cursor_x_px += font_impl.pair_kerning_pixels(&metrics, last_glyph_id, glyph_id);
+            }
+
+            // Invisible glyphs have no width, just like in `FontImpl::allocate_glyph`:
+            if font_impl.is_some() && glyph_info.id.is_some() {
+                cursor_x_px += glyph_info.advance_width_unscaled.0 * metrics.px_scale_factor;
+            }
+            last_glyph_id = Some(glyph_info.id.unwrap_or_default());
+        }
+
+        cursor_x_px / pixels_per_point
+    }
+
     /// Can we display this glyph?
     pub fn has_glyph(&mut self, c: char) -> bool {
         self.glyph_info(c) != self.cached_family.replacement_glyph // TODO(emilk): this is a false negative if the user asks about the replacement character itself 🤦‍♂️
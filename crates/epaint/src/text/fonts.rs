@@ -13,7 +13,7 @@ use crate::{
         font::{Font, FontImpl, GlyphInfo},
     },
 };
-use emath::{NumExt as _, OrderedFloat};
+use emath::{NumExt as _, OrderedFloat, Vec2};
 
 #[cfg(feature = "default_fonts")]
 use epaint_default_fonts::{EMOJI_ICON, HACK_REGULAR, NOTO_EMOJI_REGULAR, UBUNTU_LIGHT};
@@ -257,6 +257,11 @@ pub struct FontDefinitions {
     /// When looking for a character glyph `epaint` will start with
     /// the first font and then move to the second, and so on.
     /// So the first font is the primary, and then comes a list of fallbacks in order of priority.
+    ///
+    /// Each family has its own chain, so you can e.g. add a CJK font as a fallback
+    /// to [`FontFamily::Proportional`] before the emoji fonts,
+    /// without making it a separate family.
+    /// The font a character resolves to is cached per family and character.
     pub families: BTreeMap<FontFamily, Vec<String>>,
 }
 
@@ -568,6 +573,19 @@ impl Fonts {
         &self.fonts.definitions
     }
 
+    /// Switch to new font definitions, without recreating everything like [`Self::new`] does.
+    ///
+    /// Fonts whose [`FontData`] is unchanged (e.g. when adding a font for another language)
+    /// are kept, together with their glyphs in the font atlas.
+    /// So the cost is that of loading the new or changed fonts, and of rasterizing their glyphs when they are first used.
+    /// It doesn't matter if the new definitions change the order of the fonts in the families.
+    ///
+    /// All text needs to be laid out again, since any character could now resolve to another font.
+    pub fn set_definitions(&mut self, definitions: FontDefinitions) {
+        self.fonts.set_definitions(definitions);
+        self.galley_cache = Default::default();
+    }
+
     /// The font atlas.
     /// Pass this to [`crate::Tessellator`].
     pub fn texture_atlas(&self) -> &TextureAtlas {
@@ -657,6 +675,20 @@ impl FontsView<'_> {
             .glyph_width(c, font_id.size)
     }
 
+    /// The size of this text when laid out on a single line, in points.
+    ///
+    /// The width matches that of the [`Galley`] you would get from [`Self::layout_no_wrap`]
+    /// (within a pixel), and the height is the [`Self::row_height`].
+    ///
+    /// This is cheaper than laying out the text, as no [`Galley`] or mesh is created.
+    /// The result is cached until the end of the next frame, just like galleys are.
+    ///
+    /// `\n` is not treated as a line break.
+    pub fn measure_singleline(&mut self, text: &str, font_id: &FontId) -> Vec2 {
+        self.galley_cache
+            .measure_singleline(self.fonts, text, font_id, self.pixels_per_point)
+    }
+
     /// Can we display this glyph?
     pub fn has_glyph(&mut self, font_id: &FontId, c: char) -> bool {
         self.fonts.font(&font_id.family).has_glyph(c)
@@ -778,15 +810,10 @@ impl FontsImpl {
         let initial_height = 32; // Keep initial font atlas small, so it is fast to upload to GPU. This will expand as needed anyways.
         let atlas = TextureAtlas::new([texture_width, initial_height], text_alpha_from_coverage);
 
-        let mut fonts_by_id: nohash_hasher::IntMap<FontFaceKey, FontImpl> = Default::default();
-        let mut font_impls: ahash::HashMap<String, FontFaceKey> = Default::default();
+        let mut fonts_by_id = Default::default();
+        let mut fonts_by_name = Default::default();
         for (name, font_data) in &definitions.font_data {
-            let tweak = font_data.tweak;
-            let ab_glyph = ab_glyph_font_from_font_data(name, font_data);
-            let font_impl = FontImpl::new(name.clone(), ab_glyph, tweak);
-            let key = FontFaceKey::new();
-            fonts_by_id.insert(key, font_impl);
-            font_impls.insert(name.clone(), key);
+            Self::load_font(&mut fonts_by_id, &mut fonts_by_name, name, font_data);
         }
 
         Self {
@@ -794,11 +821,61 @@ impl FontsImpl {
             definitions,
             atlas,
             fonts_by_id,
-            fonts_by_name: font_impls,
+            fonts_by_name,
             family_cache: Default::default(),
         }
     }
 
+    fn load_font(
+        fonts_by_id: &mut nohash_hasher::IntMap<FontFaceKey, FontImpl>,
+        fonts_by_name: &mut ahash::HashMap<String, FontFaceKey>,
+        name: &str,
+        font_data: &FontData,
+    ) {
+        let tweak = font_data.tweak;
+        let ab_glyph = ab_glyph_font_from_font_data(name, font_data);
+        let font_impl = FontImpl::new(name.to_owned(), ab_glyph, tweak);
+        let key = FontFaceKey::new();
+        fonts_by_id.insert(key, font_impl);
+        fonts_by_name.insert(name.to_owned(), key);
+    }
+
+    /// Switch to new font definitions, keeping the fonts whose [`FontData`] didn't change.
+    ///
+    /// The kept fonts keep their glyphs in the font atlas,
+    /// so only the new or changed fonts need to be parsed, and have their glyphs rasterized.
+    fn set_definitions(&mut self, definitions: FontDefinitions) {
+        let old_font_data = &self.definitions.font_data;
+        let fonts_by_id = &mut self.fonts_by_id;
+        self.fonts_by_name.retain(|name, key| {
+            let unchanged = old_font_data
+                .get(name)
+                .zip(definitions.font_data.get(name))
+                .is_some_and(|(old, new)| Arc::ptr_eq(old, new) || old == new);
+            if !unchanged {
+                // Its glyphs stay in the atlas until it is recreated in `Fonts::begin_pass`.
+                fonts_by_id.remove(key);
+            }
+            unchanged
+        });
+
+        for (name, font_data) in &definitions.font_data {
+            if !self.fonts_by_name.contains_key(name) {
+                Self::load_font(
+                    &mut self.fonts_by_id,
+                    &mut self.fonts_by_name,
+                    name,
+                    font_data,
+                );
+            }
+        }
+
+        self.definitions = definitions;
+
+        // The families may have changed, and so which font each character resolves to:
+        self.family_cache.clear();
+    }
+
     /// Get the right font implementation from [`FontFamily`].
     pub fn font(&mut self, family: &FontFamily) -> Font<'_> {
         let cached_family = self.family_cache.entry(family.clone()).or_insert_with(|| {
@@ -840,11 +917,21 @@ struct CachedGalley {
     galley: Arc<Galley>,
 }
 
+struct CachedSize {
+    /// When it was last used
+    last_used: u32,
+
+    size: Vec2,
+}
+
 #[derive(Default)]
 struct GalleyCache {
     /// Frame counter used to do garbage collection on the cache
     generation: u32,
     cache: nohash_hasher::IntMap<u64, CachedGalley>,
+
+    /// For [`FontsView::measure_singleline`].
+    sizes: nohash_hasher::IntMap<u64, CachedSize>,
 }
 
 impl GalleyCache {
@@ -1058,6 +1145,30 @@ impl GalleyCache {
         (child_galleys, child_hashes)
     }
 
+    fn measure_singleline(
+        &mut self,
+        fonts: &mut FontsImpl,
+        text: &str,
+        font_id: &FontId,
+        pixels_per_point: f32,
+    ) -> Vec2 {
+        let hash = crate::util::hash((text, font_id, OrderedFloat(pixels_per_point)));
+        let generation = self.generation;
+        let cached = self.sizes.entry(hash).or_insert_with(|| {
+            let mut font = fonts.font(&font_id.family);
+            let width = font.text_width(text, pixels_per_point, font_id.size);
+            let height = font
+                .scaled_metrics(pixels_per_point, font_id.size)
+                .row_height;
+            CachedSize {
+                last_used: generation,
+                size: Vec2::new(width, height),
+            }
+        });
+        cached.last_used = generation;
+        cached.size
+    }
+
     pub fn num_galleys_in_cache(&self) -> usize {
         self.cache.len()
     }
@@ -1068,6 +1179,8 @@ impl GalleyCache {
         self.cache.retain(|_key, cached| {
             cached.last_used == current_generation // only keep those that were used this frame
         });
+        self.sizes
+            .retain(|_key, cached| cached.last_used == current_generation);
         self.generation = self.generation.wrapping_add(1);
     }
 }
@@ -1247,6 +1360,101 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fallback_chain_per_family() {
+        let mut definitions = FontDefinitions::default();
+        let family = FontFamily::Name("emoji_first".into());
+        definitions.families.insert(
+            family.clone(),
+            vec!["NotoEmoji-Regular".to_owned(), "Hack".to_owned()],
+        );
+        let mut fonts = FontsImpl::new(1024, AlphaFromCoverage::default(), definitions);
+        let ubuntu = fonts.fonts_by_name["Ubuntu-Light"];
+        let hack = fonts.fonts_by_name["Hack"];
+        let noto_emoji = fonts.fonts_by_name["NotoEmoji-Regular"];
+
+        let mut proportional = fonts.font(&FontFamily::Proportional);
+        assert_eq!(proportional.glyph_info('a').0, ubuntu);
+        assert_eq!(proportional.glyph_info('😊').0, noto_emoji);
+        assert!(
+            proportional
+                .cached_family
+                .glyph_info_cache
+                .contains_key(&'😊')
+        );
+
+        // The same characters resolve differently in a family with another chain:
+        let mut emoji_first = fonts.font(&family);
+        assert_eq!(emoji_first.glyph_info('😊').0, noto_emoji);
+        assert_eq!(emoji_first.glyph_info('a').0, hack);
+    }
+
+    #[test]
+    fn test_set_definitions_keeps_unchanged_fonts() {
+        let mut fonts = FontsImpl::new(1024, AlphaFromCoverage::default(), Default::default());
+        let ubuntu = fonts.fonts_by_name["Ubuntu-Light"];
+        let hack = fonts.fonts_by_name["Hack"];
+
+        let mut definitions = FontDefinitions::default();
+        definitions
+            .families
+            .get_mut(&FontFamily::Proportional)
+            .unwrap()
+            .insert(0, "Hack".to_owned());
+        let mut tweaked = (*definitions.font_data["Ubuntu-Light"]).clone();
+        tweaked.tweak.scale = 1.5;
+        definitions
+            .font_data
+            .insert("Ubuntu-Light".to_owned(), Arc::new(tweaked));
+        fonts.set_definitions(definitions);
+
+        assert_eq!(
+            fonts.fonts_by_name["Hack"], hack,
+            "Unchanged font should be kept"
+        );
+        assert_ne!(
+            fonts.fonts_by_name["Ubuntu-Light"], ubuntu,
+            "Changed font should be reloaded"
+        );
+        assert!(!fonts.fonts_by_id.contains_key(&ubuntu));
+        assert_eq!(
+            fonts.font(&FontFamily::Proportional).glyph_info('a').0,
+            hack
+        );
+    }
+
+    #[test]
+    fn test_measure_singleline() {
+        for pixels_per_point in [1.0, 1.3, 2.0] {
+            let mut fonts = Fonts::new(
+                1024,
+                AlphaFromCoverage::default(),
+                FontDefinitions::default(),
+            );
+            let mut fonts = fonts.with_pixels_per_point(pixels_per_point);
+
+            for font_id in [
+                FontId::proportional(14.0),
+                FontId::monospace(10.0),
+                FontId::proportional(31.0),
+            ] {
+                for text in ["", "Hello World!", "AVAVAV kerning", "emoji 😊 fallback"] {
+                    let measured = fonts.measure_singleline(text, &font_id);
+                    let galley =
+                        fonts.layout_no_wrap(text.to_owned(), font_id.clone(), Color32::WHITE);
+                    // Within a pixel, plus the rounding of the galley size:
+                    let tolerance = 1.0 / pixels_per_point + emath::GUI_ROUNDING;
+                    let difference = (measured - galley.size()).abs();
+                    assert!(
+                        difference.x <= tolerance && difference.y <= tolerance,
+                        "{text:?} {font_id:?} @ {pixels_per_point}: measured {measured:?}, galley {:?}",
+                        galley.size()
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_intrinsic_size() {
         let pixels_per_point = [1.0, 1.3, 2.0, 0.867];
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use emath::{Align, GuiRounding as _, NumExt as _, Pos2, Rect, Vec2, pos2, vec2};
+use emath::{Align, GuiRounding as _, NumExt as _, Pos2, Rangef, Rect, Vec2, pos2, vec2};
 
 use crate::{
     Color32, Mesh, Stroke, Vertex,
@@ -11,7 +11,9 @@ use crate::{
     },
 };
 
-use super::{FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, PlacedRow, Row, RowVisuals};
+use super::{
+    FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, PlacedRow, Row, RowVisuals, UnderlineStyle,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -140,6 +142,9 @@ pub fn layout(fonts: &mut FontsImpl, pixels_per_point: f32, job: Arc<LayoutJob>)
         }
     }
 
+    #[cfg(feature = "bidi")]
+    reorder_rows_for_bidi(&job, &mut rows, elided);
+
     // Calculate the Y positions and tessellate the text:
     galley_from_rows(point_scale, job, rows, elided, intrinsic_size)
 }
@@ -644,6 +649,107 @@ fn halign_and_justify_row(
     row.size.x = target_max_x - target_min_x;
 }
 
+/// Move the glyphs of each row so that right-to-left text (e.g. Arabic and Hebrew)
+/// is displayed in the right order, following the Unicode Bidirectional Algorithm.
+///
+/// The glyphs stay in logical order in [`Row::glyphs`]; only their x positions change.
+///
+/// Ignores the Y coordinate.
+#[cfg(feature = "bidi")]
+fn reorder_rows_for_bidi(job: &LayoutJob, rows: &mut [PlacedRow], elided: bool) {
+    profiling::function_scope!();
+
+    let text = job.text.as_str();
+    let bidi_info = unicode_bidi::BidiInfo::new(text, None);
+    if !bidi_info.has_rtl() {
+        return;
+    }
+
+    let has_overflow_glyph = elided && job.wrap.overflow_character.is_some();
+    let num_rows = rows.len();
+    let mut row_start = 0;
+
+    for (row_index, placed_row) in rows.iter_mut().enumerate() {
+        let start = row_start;
+        row_start = text[start..]
+            .char_indices()
+            .nth(placed_row.char_count_including_newline())
+            .map_or(text.len(), |(i, _)| start + i);
+
+        // The overflow character is not part of the text, and stays at the end:
+        let mut num_text_glyphs = placed_row.glyphs.len();
+        if has_overflow_glyph && row_index + 1 == num_rows {
+            num_text_glyphs = num_text_glyphs.saturating_sub(1);
+        }
+
+        let glyph_offsets: Vec<usize> = text[start..]
+            .char_indices()
+            .take(num_text_glyphs)
+            .map(|(i, _)| start + i)
+            .collect();
+        let row_end = text[start..]
+            .char_indices()
+            .nth(num_text_glyphs)
+            .map_or(text.len(), |(i, _)| start + i);
+
+        let order = bidi_visual_order(&bidi_info, start..row_end, &glyph_offsets);
+        if order.len() != num_text_glyphs || order.iter().copied().eq(0..num_text_glyphs) {
+            continue;
+        }
+
+        let row = Arc::make_mut(&mut placed_row.row);
+        let glyphs = &mut row.glyphs[..num_text_glyphs];
+
+        // How much space each glyph takes up, including kerning, letter spacing and justification:
+        let widths: Vec<f32> = (0..glyphs.len())
+            .map(|i| {
+                glyphs
+                    .get(i + 1)
+                    .map_or(glyphs[i].advance_width, |next| next.pos.x - glyphs[i].pos.x)
+            })
+            .collect();
+
+        let mut x = glyphs[0].pos.x;
+        for i in order {
+            glyphs[i].pos.x = x;
+            x += widths[i];
+        }
+    }
+}
+
+/// The indices of the glyphs on a line, in the order they should be displayed (left to right).
+///
+/// `glyph_offsets` is the byte offset of each glyph in the text, in logical order.
+#[cfg(feature = "bidi")]
+fn bidi_visual_order(
+    bidi_info: &unicode_bidi::BidiInfo<'_>,
+    line: std::ops::Range<usize>,
+    glyph_offsets: &[usize],
+) -> Vec<usize> {
+    let mut order = Vec::with_capacity(glyph_offsets.len());
+
+    // A row can span several paragraphs if `LayoutJob::break_on_newline` is `false`:
+    for paragraph in &bidi_info.paragraphs {
+        let range = paragraph.range.start.max(line.start)..paragraph.range.end.min(line.end);
+        if range.is_empty() {
+            continue;
+        }
+
+        let (levels, runs) = bidi_info.visual_runs(paragraph, range);
+        for run in runs {
+            let first = glyph_offsets.partition_point(|&i| i < run.start);
+            let last = glyph_offsets.partition_point(|&i| i < run.end);
+            if levels[run.start].is_rtl() {
+                order.extend((first..last).rev());
+            } else {
+                order.extend(first..last);
+            }
+        }
+    }
+
+    order
+}
+
 /// Calculate the Y positions and tessellate the text.
 fn galley_from_rows(
     point_scale: PointScale,
@@ -665,26 +771,43 @@ fn galley_from_rows(
         }
         max_row_height = point_scale.round_to_pixel(max_row_height);
 
+        // Make room for glyphs that are moved up or down with `TextFormat::baseline_shift`:
+        let mut extra_top = 0.0_f32;
+        let mut extra_bottom = 0.0_f32;
+        for glyph in &row.glyphs {
+            let format = &job.sections[glyph.section_index as usize].format;
+            if format.baseline_shift != 0.0 {
+                let free_space = max_row_height - glyph.line_height;
+                let valign = format.valign.to_factor();
+                extra_top = extra_top.at_least(format.baseline_shift - valign * free_space);
+                extra_bottom =
+                    extra_bottom.at_least(-format.baseline_shift - (1.0 - valign) * free_space);
+            }
+        }
+        let extra_top = point_scale.round_to_pixel(extra_top);
+        let extra_bottom = point_scale.round_to_pixel(extra_bottom);
+
         // Now position each glyph vertically:
         for glyph in &mut row.glyphs {
             let format = &job.sections[glyph.section_index as usize].format;
 
-            glyph.pos.y = glyph.font_impl_ascent
+            glyph.pos.y = extra_top + glyph.font_impl_ascent
 
                 // Apply valign to the different in height of the entire row, and the height of this `Font`:
                 + format.valign.to_factor() * (max_row_height - glyph.line_height)
 
                 // When mixing different `FontImpl` (e.g. latin and emojis),
                 // we always center the difference:
-                + 0.5 * (glyph.font_height - glyph.font_impl_height);
+                + 0.5 * (glyph.font_height - glyph.font_impl_height)
+                - format.baseline_shift;
 
             glyph.pos.y = point_scale.round_to_pixel(glyph.pos.y);
         }
 
         placed_row.pos.y = cursor_y;
-        row.size.y = max_row_height;
+        row.size.y = extra_top + max_row_height + extra_bottom;
 
-        cursor_y += max_row_height;
+        cursor_y += row.size.y;
         cursor_y = point_scale.round_to_pixel(cursor_y); // TODO(emilk): it would be better to do the calculations in pixels instead.
     }
 
@@ -776,7 +899,7 @@ fn tessellate_row(
             let format = &job.sections[glyph.section_index as usize].format;
             let stroke = format.underline;
             let y = glyph.logical_rect().bottom();
-            (stroke, y)
+            (stroke, format.underline_style, y)
         });
     }
 
@@ -785,7 +908,7 @@ fn tessellate_row(
             let format = &job.sections[glyph.section_index as usize].format;
             let stroke = format.strikethrough;
             let y = glyph.logical_rect().center().y;
-            (stroke, y)
+            (stroke, UnderlineStyle::Solid, y)
         });
     }
 
@@ -801,14 +924,20 @@ fn tessellate_row(
 
 /// Create background for glyphs that have them.
 /// Creates as few rectangular regions as possible.
+///
+/// The backgrounds cover the full height of the row (including any extra [`TextFormat::line_height`]),
+/// so that the backgrounds of consecutive rows line up without gaps,
+/// even when fonts of different sizes are mixed.
 fn add_row_backgrounds(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh: &mut Mesh) {
     if row.glyphs.is_empty() {
         return;
     }
 
-    let mut end_run = |start: Option<(Color32, Rect, f32)>, stop_x: f32| {
-        if let Some((color, start_rect, expand)) = start {
-            let rect = Rect::from_min_max(start_rect.left_top(), pos2(stop_x, start_rect.bottom()));
+    let y_range = Rangef::new(0.0, row.size.y);
+
+    let mut end_run = |start: Option<(Color32, f32, f32)>, stop_x: f32| {
+        if let Some((color, start_x, expand)) = start {
+            let rect = Rect::from_x_y_ranges(start_x..=stop_x, y_range);
             let rect = rect.expand(expand);
             let rect = rect.round_to_pixels(point_scale.pixels_per_point());
             mesh.add_colored_rect(rect, color);
@@ -816,34 +945,29 @@ fn add_row_backgrounds(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh
     };
 
     let mut run_start = None;
-    let mut last_rect = Rect::NAN;
+    let mut last_max_x = f32::NAN;
 
-    for glyph in &row.glyphs {
+    for glyph in glyphs_in_visual_order(row).iter() {
         let format = &job.sections[glyph.section_index as usize].format;
         let color = format.background;
-        let rect = glyph.logical_rect();
 
         if color == Color32::TRANSPARENT {
-            end_run(run_start.take(), last_rect.right());
-        } else if let Some((existing_color, start, expand)) = run_start {
-            if existing_color == color
-                && start.top() == rect.top()
-                && start.bottom() == rect.bottom()
-                && format.expand_bg == expand
-            {
+            end_run(run_start.take(), last_max_x);
+        } else if let Some((existing_color, _, expand)) = run_start {
+            if existing_color == color && format.expand_bg == expand {
                 // continue the same background rectangle
             } else {
-                end_run(run_start.take(), last_rect.right());
-                run_start = Some((color, rect, format.expand_bg));
+                end_run(run_start.take(), last_max_x);
+                run_start = Some((color, glyph.pos.x, format.expand_bg));
             }
         } else {
-            run_start = Some((color, rect, format.expand_bg));
+            run_start = Some((color, glyph.pos.x, format.expand_bg));
         }
 
-        last_rect = rect;
+        last_max_x = glyph.max_x();
     }
 
-    end_run(run_start.take(), last_rect.right());
+    end_run(run_start.take(), last_max_x);
 }
 
 fn tessellate_glyphs(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh: &mut Mesh) {
@@ -898,43 +1022,104 @@ fn tessellate_glyphs(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh:
     }
 }
 
-/// Add a horizontal line over a row of glyphs with a stroke and y decided by a callback.
+/// The glyphs of the row from left to right.
+///
+/// This is the same as the logical order, unless right-to-left text has been reordered.
+fn glyphs_in_visual_order(row: &Row) -> std::borrow::Cow<'_, [Glyph]> {
+    if row.glyphs.is_sorted_by(|a, b| a.pos.x <= b.pos.x) {
+        std::borrow::Cow::Borrowed(&row.glyphs)
+    } else {
+        let mut glyphs = row.glyphs.clone();
+        glyphs.sort_by(|a, b| a.pos.x.total_cmp(&b.pos.x));
+        std::borrow::Cow::Owned(glyphs)
+    }
+}
+
+/// Add a horizontal line over a row of glyphs with a stroke, style and y decided by a callback.
+///
+/// The y is the bottom of wavy and dotted lines, so that they stay clear of the glyphs above them.
 fn add_row_hline(
     point_scale: PointScale,
     row: &Row,
     mesh: &mut Mesh,
-    stroke_and_y: impl Fn(&Glyph) -> (Stroke, f32),
+    stroke_style_and_y: impl Fn(&Glyph) -> (Stroke, UnderlineStyle, f32),
 ) {
     let mut path = crate::tessellator::Path::default(); // reusing path to avoid re-allocations.
+    let mut points = vec![]; // reusing points to avoid re-allocations.
+    let feathering = 1.0 / point_scale.pixels_per_point();
 
-    let mut end_line = |start: Option<(Stroke, Pos2)>, stop_x: f32| {
-        if let Some((stroke, start)) = start {
-            let stop = pos2(stop_x, start.y);
-            path.clear();
-            path.add_line_segment([start, stop]);
-            let feathering = 1.0 / point_scale.pixels_per_point();
-            path.stroke_open(feathering, &PathStroke::from(stroke), mesh);
+    let mut end_line = |start: Option<(Stroke, UnderlineStyle, Pos2)>, stop_x: f32| {
+        let Some((stroke, style, start)) = start else {
+            return;
+        };
+        match style {
+            UnderlineStyle::Solid => {
+                let stop = pos2(stop_x, start.y);
+                path.clear();
+                path.add_line_segment([start, stop]);
+                path.stroke_open(feathering, &PathStroke::from(stroke), mesh);
+            }
+            UnderlineStyle::Wavy => {
+                // Zig-zag between `start.y` and one amplitude above it:
+                let amplitude = 1.5 * stroke.width.at_least(1.0);
+                let half_wavelength = 2.0 * amplitude;
+                let y = start.y - 0.5 * stroke.width;
+                let wave_y = |x: f32| {
+                    let t = (x - start.x) / half_wavelength;
+                    let is_rising = (t as usize) % 2 == 0;
+                    let height = if is_rising {
+                        t.fract()
+                    } else {
+                        1.0 - t.fract()
+                    };
+                    y - amplitude * height
+                };
+                points.clear();
+                let mut x = start.x;
+                while x < stop_x {
+                    points.push(pos2(x, wave_y(x)));
+                    x += half_wavelength;
+                }
+                points.push(pos2(stop_x, wave_y(stop_x)));
+                if 2 <= points.len() {
+                    path.clear();
+                    path.add_open_points(&points);
+                    path.stroke_open(feathering, &PathStroke::from(stroke), mesh);
+                }
+            }
+            UnderlineStyle::Dotted => {
+                let radius = 0.5 * stroke.width.at_least(1.0);
+                let spacing = 4.0 * radius;
+                let y = start.y - radius;
+                let mut x = start.x + radius;
+                while x <= stop_x - radius {
+                    path.clear();
+                    path.add_circle(pos2(x, y), radius);
+                    path.fill(feathering, stroke.color, mesh);
+                    x += spacing;
+                }
+            }
         }
     };
 
     let mut line_start = None;
     let mut last_right_x = f32::NAN;
 
-    for glyph in &row.glyphs {
-        let (stroke, mut y) = stroke_and_y(glyph);
+    for glyph in glyphs_in_visual_order(row).iter() {
+        let (stroke, style, mut y) = stroke_style_and_y(glyph);
         stroke.round_center_to_pixel(point_scale.pixels_per_point, &mut y);
 
         if stroke.is_empty() {
             end_line(line_start.take(), last_right_x);
-        } else if let Some((existing_stroke, start)) = line_start {
-            if existing_stroke == stroke && start.y == y {
+        } else if let Some((existing_stroke, existing_style, start)) = line_start {
+            if existing_stroke == stroke && existing_style == style && start.y == y {
                 // continue the same line
             } else {
                 end_line(line_start.take(), last_right_x);
-                line_start = Some((stroke, pos2(glyph.pos.x, y)));
+                line_start = Some((stroke, style, pos2(glyph.pos.x, y)));
             }
         } else {
-            line_start = Some((stroke, pos2(glyph.pos.x, y)));
+            line_start = Some((stroke, style, pos2(glyph.pos.x, y)));
         }
 
         last_right_x = glyph.max_x();
@@ -1068,6 +1253,62 @@ mod tests {
         assert_eq!(galley.rows.len(), 1);
     }
 
+    #[test]
+    fn test_background_covers_wrapped_rows() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let line_height = 24.0;
+        let plain = TextFormat {
+            line_height: Some(line_height),
+            ..Default::default()
+        };
+        let highlighted = TextFormat {
+            background: Color32::YELLOW,
+            expand_bg: 0.0,
+            ..plain.clone()
+        };
+        let mut job = LayoutJob::default();
+        job.append("Some ", 0.0, plain);
+        job.append("highlighted words that wrap", 0.0, highlighted);
+        job.wrap.max_width = 60.0;
+        let galley = layout(&mut fonts, pixels_per_point, job.into());
+        assert!(2 <= galley.rows.len(), "The text should wrap");
+
+        let mut num_highlighted_rows = 0;
+        for row in &galley.rows {
+            assert_eq!(row.size.y, line_height);
+
+            // The background is painted before the glyphs:
+            let visuals = &row.visuals;
+            let background = &visuals.mesh.vertices[..visuals.glyph_vertex_range.start];
+            if background.is_empty() {
+                continue;
+            }
+            num_highlighted_rows += 1;
+            let min_y = background
+                .iter()
+                .map(|v| v.pos.y)
+                .fold(f32::INFINITY, f32::min);
+            let max_y = background
+                .iter()
+                .map(|v| v.pos.y)
+                .fold(f32::NEG_INFINITY, f32::max);
+            assert_eq!(
+                (min_y, max_y),
+                (0.0, line_height),
+                "The background should cover the whole row, including the line gap"
+            );
+        }
+        assert!(
+            2 <= num_highlighted_rows,
+            "The background should wrap with the text"
+        );
+    }
+
     #[test]
     fn test_truncate_with_newline() {
         // No matter where we wrap, we should be appending the newline character.
@@ -1258,4 +1499,161 @@ mod tests {
             "Unexpected intrinsic size"
         );
     }
+
+    #[test]
+    fn test_galley_glyphs() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+
+        let text = "aö\n\n日本";
+        let job = LayoutJob::single_section(text.into(), TextFormat::default());
+        let galley = layout(&mut fonts, pixels_per_point, job.into());
+        let glyphs: Vec<_> = galley.glyphs().collect();
+        assert_eq!(
+            glyphs
+                .iter()
+                .map(|g| (&text[g.byte_range.clone()], g.row_index))
+                .collect::<Vec<_>>(),
+            vec![("a", 0), ("ö", 0), ("日", 2), ("本", 2)]
+        );
+        assert!(glyphs[0].rect.right() <= glyphs[1].rect.left() + 1e-3);
+        assert!(glyphs[1].rect.bottom() <= glyphs[2].rect.top() + 1e-3);
+
+        // The overflow character covers the elided text:
+        let text = "ab\ncd";
+        let mut job = LayoutJob::single_section(text.into(), TextFormat::default());
+        job.wrap.max_rows = 1;
+        let galley = layout(&mut fonts, pixels_per_point, job.into());
+        assert!(galley.elided);
+        let ranges: Vec<_> = galley.glyphs().map(|g| g.byte_range).collect();
+        assert_eq!(ranges.last(), Some(&(2..text.len())));
+    }
+
+    #[test]
+    fn test_baseline_shift() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+
+        let mut layout_with_shift = |baseline_shift: f32| {
+            let mut job = LayoutJob::default();
+            job.append("H", 0.0, TextFormat::default());
+            job.append(
+                "2",
+                0.0,
+                TextFormat {
+                    baseline_shift,
+                    ..Default::default()
+                },
+            );
+            layout(&mut fonts, pixels_per_point, job.into())
+        };
+
+        let plain = layout_with_shift(0.0);
+        let raised = layout_with_shift(4.0);
+        let lowered = layout_with_shift(-4.0);
+
+        // The row grows to fit the moved glyph:
+        assert_eq!(raised.rows[0].height(), plain.rows[0].height() + 4.0);
+        assert_eq!(lowered.rows[0].height(), plain.rows[0].height() + 4.0);
+
+        let [plain_h, plain_2] = [0, 1].map(|i| plain.rows[0].glyphs[i].pos.y);
+        let [raised_h, raised_2] = [0, 1].map(|i| raised.rows[0].glyphs[i].pos.y);
+        let [lowered_h, lowered_2] = [0, 1].map(|i| lowered.rows[0].glyphs[i].pos.y);
+        assert_eq!(plain_h, plain_2);
+        assert_eq!(raised_2, raised_h - 4.0);
+        assert_eq!(lowered_2, lowered_h + 4.0);
+        assert_eq!(lowered_h, plain_h);
+    }
+
+    #[test]
+    fn test_underline_styles() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+
+        let mut num_vertices = vec![];
+        for underline_style in [
+            UnderlineStyle::Solid,
+            UnderlineStyle::Wavy,
+            UnderlineStyle::Dotted,
+        ] {
+            let format = TextFormat {
+                underline: Stroke::new(1.0, Color32::RED),
+                underline_style,
+                ..Default::default()
+            };
+            let mut job = LayoutJob::single_section("Wavy underline".into(), format);
+            job.wrap.max_width = 40.0;
+            let galley = layout(&mut fonts, pixels_per_point, job.into());
+            assert!(1 < galley.rows.len(), "Expected the text to wrap");
+
+            // The underlines should not reach into the next row:
+            for row in &galley.rows {
+                let max_y = row.visuals.mesh_bounds.max.y;
+                assert!(
+                    max_y <= row.size.y + 1.0,
+                    "{underline_style:?} underline at {max_y} is below the row of height {}",
+                    row.size.y
+                );
+            }
+            num_vertices.push(galley.num_vertices);
+        }
+
+        // Each style should produce different geometry:
+        assert_ne!(num_vertices[0], num_vertices[1]);
+        assert_ne!(num_vertices[0], num_vertices[2]);
+    }
+
+    #[cfg(feature = "bidi")]
+    #[test]
+    fn test_bidi() {
+        let pixels_per_point = 1.0;
+        let mut fonts = FontsImpl::new(
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+
+        let x_positions = |fonts: &mut FontsImpl, text: &str| -> Vec<(char, f32)> {
+            let job = LayoutJob::single_section(text.into(), TextFormat::default());
+            let galley = layout(fonts, pixels_per_point, job.into());
+            let mut glyphs: Vec<_> = galley.rows[0]
+                .glyphs
+                .iter()
+                .map(|g| (g.chr, g.pos.x))
+                .collect();
+            glyphs.sort_by(|a, b| a.1.total_cmp(&b.1));
+            glyphs
+        };
+        let visual_text = |fonts: &mut FontsImpl, text: &str| -> String {
+            x_positions(fonts, text)
+                .iter()
+                .map(|(chr, _)| chr)
+                .collect()
+        };
+
+        assert_eq!(visual_text(&mut fonts, "abc 123"), "abc 123");
+
+        // Numbers inside right-to-left text are still left-to-right:
+        assert_eq!(visual_text(&mut fonts, "אבג 123"), "123 גבא");
+
+        // The glyphs are still in logical order in the row:
+        let job = LayoutJob::single_section("אבג".into(), TextFormat::default());
+        let galley = layout(&mut fonts, pixels_per_point, job.into());
+        assert_eq!(galley.rows[0].text(), "אבג");
+        let glyphs = &galley.rows[0].glyphs;
+        assert!(glyphs[2].pos.x < glyphs[1].pos.x);
+        assert!(glyphs[1].pos.x < glyphs[0].pos.x);
+    }
 }
@@ -283,6 +283,9 @@ pub struct TextFormat {
     /// Text color
     pub color: Color32,
 
+    /// Background color, painted behind the glyphs.
+    ///
+    /// The background covers the full height of the row, and follows the text when it wraps.
     pub background: Color32,
 
     /// Amount to expand background fill by.
@@ -292,8 +295,15 @@ pub struct TextFormat {
 
     pub italics: bool,
 
+    /// The color and thickness of the underline, if any.
     pub underline: Stroke,
 
+    /// How to draw the [`Self::underline`].
+    ///
+    /// Default: [`UnderlineStyle::Solid`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub underline_style: UnderlineStyle,
+
     pub strikethrough: Stroke,
 
     /// If you use a small font and [`Align::TOP`] you
@@ -306,6 +316,15 @@ pub struct TextFormat {
     /// around a common center-line, which is nice when mixining emojis
     /// and normal text in e.g. a button.
     pub valign: Align,
+
+    /// Move the glyphs up (positive) or down (negative) by this many points,
+    /// e.g. for superscript and subscript.
+    ///
+    /// The row is made taller to fit the moved glyphs.
+    ///
+    /// Default: 0.0.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub baseline_shift: f32,
 }
 
 impl Default for TextFormat {
@@ -320,8 +339,10 @@ impl Default for TextFormat {
             expand_bg: 1.0,
             italics: false,
             underline: Stroke::NONE,
+            underline_style: UnderlineStyle::Solid,
             strikethrough: Stroke::NONE,
             valign: Align::BOTTOM,
+            baseline_shift: 0.0,
         }
     }
 }
@@ -338,8 +359,10 @@ impl std::hash::Hash for TextFormat {
             expand_bg,
             italics,
             underline,
+            underline_style,
             strikethrough,
             valign,
+            baseline_shift,
         } = self;
         font_id.hash(state);
         emath::OrderedFloat(*extra_letter_spacing).hash(state);
@@ -351,8 +374,10 @@ impl std::hash::Hash for TextFormat {
         emath::OrderedFloat(*expand_bg).hash(state);
         italics.hash(state);
         underline.hash(state);
+        underline_style.hash(state);
         strikethrough.hash(state);
         valign.hash(state);
+        emath::OrderedFloat(*baseline_shift).hash(state);
     }
 }
 
@@ -367,6 +392,21 @@ impl TextFormat {
     }
 }
 
+/// How to draw [`TextFormat::underline`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum UnderlineStyle {
+    /// A straight line.
+    #[default]
+    Solid,
+
+    /// A wavy line, e.g. for marking spelling mistakes.
+    Wavy,
+
+    /// A row of dots.
+    Dotted,
+}
+
 // ----------------------------------------------------------------------------
 
 /// How to wrap and elide text.
@@ -721,6 +761,22 @@ impl Glyph {
     }
 }
 
+/// A glyph of a [`Galley`], as yielded by [`Galley::glyphs`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GalleyGlyph {
+    /// The bytes in [`Galley::text`] that this glyph represents.
+    ///
+    /// egui does no text shaping, so this is normally a single `char`.
+    /// The overflow character of an elided galley covers all the elided text.
+    pub byte_range: Range<usize>,
+
+    /// The logical rectangle of the glyph, relative to the galley.
+    pub rect: Rect,
+
+    /// Index into [`Galley::rows`].
+    pub row_index: usize,
+}
+
 // ----------------------------------------------------------------------------
 
 impl Row {
@@ -838,6 +894,55 @@ impl Galley {
         }
     }
 
+    /// All the glyphs of the galley, from the first row to the last,
+    /// together with the part of the text they represent and where they are placed.
+    ///
+    /// The implicit `\n` at the end of a row has no glyph.
+    pub fn glyphs(&self) -> impl Iterator<Item = GalleyGlyph> + '_ {
+        let text = self.text();
+        let has_overflow_glyph = self.elided && self.job.wrap.overflow_character.is_some();
+        let num_rows = self.rows.len();
+
+        self.rows
+            .iter()
+            .enumerate()
+            .scan(0, move |row_start, (row_index, placed_row)| {
+                let start = *row_start;
+                let mut chars = text[start..].char_indices();
+                *row_start = text[start..]
+                    .char_indices()
+                    .nth(placed_row.char_count_including_newline())
+                    .map_or(text.len(), |(i, _)| start + i);
+
+                let num_glyphs = placed_row.glyphs.len();
+                let is_last_row = row_index + 1 == num_rows;
+                Some(
+                    placed_row
+                        .glyphs
+                        .iter()
+                        .enumerate()
+                        .map(move |(glyph_index, glyph)| {
+                            let (begin, chr) = chars
+                                .next()
+                                .map_or((text.len(), None), |(i, chr)| (start + i, Some(chr)));
+                            let is_overflow_glyph =
+                                has_overflow_glyph && is_last_row && glyph_index + 1 == num_glyphs;
+                            let end = if is_overflow_glyph {
+                                text.len()
+                            } else {
+                                begin + chr.map_or(0, char::len_utf8)
+                            };
+                            GalleyGlyph {
+                                byte_range: begin..end,
+                                rect: glyph.logical_rect().translate(placed_row.pos.to_vec2()),
+                                row_index,
+                            }
+                        }),
+                )
+            })
+            .flatten()
+    }
+
     /// Append each galley under the previous one.
     pub fn concat(job: Arc<LayoutJob>, galleys: &[Arc<Self>], pixels_per_point: f32) -> Self {
         profiling::function_scope!();
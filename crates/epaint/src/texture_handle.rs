@@ -74,6 +74,12 @@ impl TextureHandle {
     }
 
     /// Assign a new image to a subregion of the whole texture.
+    ///
+    /// `pos` is the top-left corner of the region, in texels.
+    /// The region must lie within the current size of the texture.
+    ///
+    /// Only the given region is uploaded, so this is much cheaper than [`Self::set`]
+    /// when updating a small part of a large texture each frame.
     #[expect(clippy::needless_pass_by_ref_mut)] // Intentionally hide interiority of mutability
     pub fn set_partial(
         &mut self,
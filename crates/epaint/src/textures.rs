@@ -150,6 +150,7 @@ impl TextureMeta {
 /// How the texture texels are filtered.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(from = "TextureOptionsSerde"))]
 pub struct TextureOptions {
     /// How to filter when magnifying (when texels are larger than pixels).
     pub magnification: TextureFilter,
@@ -157,8 +158,11 @@ pub struct TextureOptions {
     /// How to filter when minifying (when texels are smaller than pixels).
     pub minification: TextureFilter,
 
-    /// How to wrap the texture when the texture coordinates are outside the [0, 1] range.
-    pub wrap_mode: TextureWrapMode,
+    /// How to wrap the texture horizontally, when the texture coordinates are outside the [0, 1] range.
+    pub wrap_mode_x: TextureWrapMode,
+
+    /// How to wrap the texture vertically, when the texture coordinates are outside the [0, 1] range.
+    pub wrap_mode_y: TextureWrapMode,
 
     /// How to filter between texture mipmaps.
     ///
@@ -176,7 +180,8 @@ impl TextureOptions {
     pub const LINEAR: Self = Self {
         magnification: TextureFilter::Linear,
         minification: TextureFilter::Linear,
-        wrap_mode: TextureWrapMode::ClampToEdge,
+        wrap_mode_x: TextureWrapMode::ClampToEdge,
+        wrap_mode_y: TextureWrapMode::ClampToEdge,
         mipmap_mode: None,
     };
 
@@ -184,7 +189,8 @@ impl TextureOptions {
     pub const NEAREST: Self = Self {
         magnification: TextureFilter::Nearest,
         minification: TextureFilter::Nearest,
-        wrap_mode: TextureWrapMode::ClampToEdge,
+        wrap_mode_x: TextureWrapMode::ClampToEdge,
+        wrap_mode_y: TextureWrapMode::ClampToEdge,
         mipmap_mode: None,
     };
 
@@ -192,7 +198,8 @@ impl TextureOptions {
     pub const LINEAR_REPEAT: Self = Self {
         magnification: TextureFilter::Linear,
         minification: TextureFilter::Linear,
-        wrap_mode: TextureWrapMode::Repeat,
+        wrap_mode_x: TextureWrapMode::Repeat,
+        wrap_mode_y: TextureWrapMode::Repeat,
         mipmap_mode: None,
     };
 
@@ -200,7 +207,8 @@ impl TextureOptions {
     pub const LINEAR_MIRRORED_REPEAT: Self = Self {
         magnification: TextureFilter::Linear,
         minification: TextureFilter::Linear,
-        wrap_mode: TextureWrapMode::MirroredRepeat,
+        wrap_mode_x: TextureWrapMode::MirroredRepeat,
+        wrap_mode_y: TextureWrapMode::MirroredRepeat,
         mipmap_mode: None,
     };
 
@@ -208,7 +216,8 @@ impl TextureOptions {
     pub const NEAREST_REPEAT: Self = Self {
         magnification: TextureFilter::Nearest,
         minification: TextureFilter::Nearest,
-        wrap_mode: TextureWrapMode::Repeat,
+        wrap_mode_x: TextureWrapMode::Repeat,
+        wrap_mode_y: TextureWrapMode::Repeat,
         mipmap_mode: None,
     };
 
@@ -216,10 +225,36 @@ impl TextureOptions {
     pub const NEAREST_MIRRORED_REPEAT: Self = Self {
         magnification: TextureFilter::Nearest,
         minification: TextureFilter::Nearest,
-        wrap_mode: TextureWrapMode::MirroredRepeat,
+        wrap_mode_x: TextureWrapMode::MirroredRepeat,
+        wrap_mode_y: TextureWrapMode::MirroredRepeat,
         mipmap_mode: None,
     };
 
+    /// Set how to wrap the texture, both horizontally and vertically.
+    pub const fn with_wrap_mode(self, wrap_mode: TextureWrapMode) -> Self {
+        Self {
+            wrap_mode_x: wrap_mode,
+            wrap_mode_y: wrap_mode,
+            ..self
+        }
+    }
+
+    /// Set how to wrap the texture horizontally.
+    pub const fn with_wrap_mode_x(self, wrap_mode_x: TextureWrapMode) -> Self {
+        Self {
+            wrap_mode_x,
+            ..self
+        }
+    }
+
+    /// Set how to wrap the texture vertically.
+    pub const fn with_wrap_mode_y(self, wrap_mode_y: TextureWrapMode) -> Self {
+        Self {
+            wrap_mode_y,
+            ..self
+        }
+    }
+
     pub const fn with_mipmap_mode(self, mipmap_mode: Option<TextureFilter>) -> Self {
         Self {
             mipmap_mode,
@@ -235,6 +270,63 @@ impl Default for TextureOptions {
     }
 }
 
+/// Deserializes [`TextureOptions`] saved both before and after `wrap_mode` was split into
+/// `wrap_mode_x` and `wrap_mode_y`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(default)]
+struct TextureOptionsSerde {
+    magnification: TextureFilter,
+    minification: TextureFilter,
+    wrap_mode: Option<TextureWrapMode>,
+    wrap_mode_x: Option<TextureWrapMode>,
+    wrap_mode_y: Option<TextureWrapMode>,
+    mipmap_mode: Option<TextureFilter>,
+}
+
+#[cfg(feature = "serde")]
+impl Default for TextureOptionsSerde {
+    fn default() -> Self {
+        let TextureOptions {
+            magnification,
+            minification,
+            wrap_mode_x: _,
+            wrap_mode_y: _,
+            mipmap_mode,
+        } = TextureOptions::default();
+        Self {
+            magnification,
+            minification,
+            wrap_mode: None,
+            wrap_mode_x: None,
+            wrap_mode_y: None,
+            mipmap_mode,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<TextureOptionsSerde> for TextureOptions {
+    fn from(options: TextureOptionsSerde) -> Self {
+        let TextureOptionsSerde {
+            magnification,
+            minification,
+            wrap_mode,
+            wrap_mode_x,
+            wrap_mode_y,
+            mipmap_mode,
+        } = options;
+        let wrap_mode = wrap_mode.unwrap_or_default();
+        Self {
+            magnification,
+            minification,
+            wrap_mode_x: wrap_mode_x.unwrap_or(wrap_mode),
+            wrap_mode_y: wrap_mode_y.unwrap_or(wrap_mode),
+            mipmap_mode,
+        }
+    }
+}
+
 /// How the texture texels are filtered.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -259,7 +351,7 @@ pub enum TextureWrapMode {
     #[default]
     ClampToEdge,
 
-    /// Tiles the texture across the surface, repeating it horizontally and vertically.
+    /// Tiles the texture across the surface, repeating it.
     Repeat,
 
     /// Mirrors the texture with each repetition, creating symmetrical tiling.
@@ -330,3 +422,42 @@ impl std::fmt::Debug for TexturesDelta {
         debug_struct.finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{Color32, ColorImage, TextureHandle, mutex::RwLock};
+
+    #[test]
+    fn test_set_partial_enqueues_sub_region() {
+        let tex_mngr = Arc::new(RwLock::new(TextureManager::default()));
+        let options = TextureOptions::default();
+        let id = tex_mngr.write().alloc(
+            "atlas".to_owned(),
+            ColorImage::filled([64, 64], Color32::BLACK).into(),
+            options,
+        );
+        let mut handle = TextureHandle::new(tex_mngr.clone(), id);
+
+        handle.set_partial([8, 16], ColorImage::filled([4, 2], Color32::RED), options);
+        assert_eq!(
+            handle.size(),
+            [64, 64],
+            "Partial updates keep the texture size"
+        );
+
+        let delta = tex_mngr.write().take_delta();
+        assert_eq!(
+            delta.set.len(),
+            2,
+            "The full upload is kept before the partial one"
+        );
+        let (partial_id, partial) = &delta.set[1];
+        assert_eq!(*partial_id, id);
+        assert_eq!(partial.pos, Some([8, 16]));
+        assert_eq!(partial.image.size(), [4, 2]);
+        assert!(!partial.is_whole());
+    }
+}